@@ -9,6 +9,7 @@ pub enum GeneralAction {
     Close,
     SwitchToTorrents,
     SwitchToSearch,
+    SwitchToSession,
     Left,
     Right,
     Down,
@@ -24,6 +25,10 @@ pub enum GeneralAction {
     XdgOpen,
     MoveToColumnLeft,
     MoveToColumnRight,
+    AppendSortColumnLeft,
+    AppendSortColumnRight,
+    SwitchProfile,
+    ToggleAltSpeed,
 }
 
 impl UserAction for GeneralAction {
@@ -34,6 +39,7 @@ impl UserAction for GeneralAction {
             GeneralAction::Close => "close a popup, a task",
             GeneralAction::SwitchToTorrents => "switch to torrents tab",
             GeneralAction::SwitchToSearch => "switch to search tab",
+            GeneralAction::SwitchToSession => "switch to session tab",
             GeneralAction::Left => "switch to tab left",
             GeneralAction::Right => "switch to tab right",
             GeneralAction::Down => "move down",
@@ -49,6 +55,10 @@ impl UserAction for GeneralAction {
             GeneralAction::XdgOpen => "open with xdg-open",
             GeneralAction::MoveToColumnRight => "move to right column (sorting)",
             GeneralAction::MoveToColumnLeft => "move to left column (sorting)",
+            GeneralAction::AppendSortColumnLeft => "sort by left column too (sorting)",
+            GeneralAction::AppendSortColumnRight => "sort by right column too (sorting)",
+            GeneralAction::SwitchProfile => "switch connection profile",
+            GeneralAction::ToggleAltSpeed => "toggle alternative speed limits (turtle mode)",
         }
     }
 
@@ -70,6 +80,12 @@ impl UserAction for GeneralAction {
             (Self::MoveToColumnRight, Self::MoveToColumnLeft) => {
                 Some("move to column right / left")
             }
+            (Self::AppendSortColumnLeft, Self::AppendSortColumnRight) => {
+                Some("sort by column left / right too")
+            }
+            (Self::AppendSortColumnRight, Self::AppendSortColumnLeft) => {
+                Some("sort by column right / left too")
+            }
             (Self::ScrollPageDown, Self::ScrollPageUp) => Some("scroll page down / up"),
             (Self::ScrollPageUp, Self::ScrollPageDown) => Some("scroll page up / down"),
             (Self::GoToBeginning, Self::GoToEnd) => Some("go to beginning / end"),
@@ -88,6 +104,7 @@ impl From<GeneralAction> for Action {
             GeneralAction::Close => Action::Close,
             GeneralAction::SwitchToTorrents => Action::ChangeTab(1),
             GeneralAction::SwitchToSearch => Action::ChangeTab(2),
+            GeneralAction::SwitchToSession => Action::ChangeTab(3),
             GeneralAction::Left => Action::Left,
             GeneralAction::Right => Action::Right,
             GeneralAction::Down => Action::Down,
@@ -103,6 +120,10 @@ impl From<GeneralAction> for Action {
             GeneralAction::XdgOpen => Action::XdgOpen,
             GeneralAction::MoveToColumnLeft => Action::MoveToColumnLeft,
             GeneralAction::MoveToColumnRight => Action::MoveToColumnRight,
+            GeneralAction::AppendSortColumnLeft => Action::AppendSortColumnLeft,
+            GeneralAction::AppendSortColumnRight => Action::AppendSortColumnRight,
+            GeneralAction::SwitchProfile => Action::ShowProfiles,
+            GeneralAction::ToggleAltSpeed => Action::ToggleAltSpeed,
         }
     }
 }
@@ -1,4 +1,6 @@
 pub mod general;
 pub mod search_tab;
+pub mod session_tab;
 pub mod torrents_tab;
+pub mod torrents_tab_columns;
 pub mod torrents_tab_file_viewer;
@@ -0,0 +1,27 @@
+use intuitils::user_action::UserAction;
+use rm_shared::action::Action;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SessionAction {
+    ApplyChanges,
+    DiscardChanges,
+}
+
+impl UserAction for SessionAction {
+    fn desc(&self) -> &'static str {
+        match self {
+            SessionAction::ApplyChanges => "review and apply changes",
+            SessionAction::DiscardChanges => "discard changes",
+        }
+    }
+}
+
+impl From<SessionAction> for Action {
+    fn from(value: SessionAction) -> Self {
+        match value {
+            SessionAction::ApplyChanges => Action::ApplySessionChanges,
+            SessionAction::DiscardChanges => Action::DiscardSessionChanges,
+        }
+    }
+}
@@ -5,11 +5,32 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TorrentsAction {
     AddMagnet,
+    CreateTorrent,
     MoveTorrent,
     Rename,
     Pause,
+    StartNow,
+    Verify,
+    Reannounce,
+    QueueMoveTop,
+    QueueMoveUp,
+    QueueMoveDown,
+    QueueMoveBottom,
     Delete,
     ShowFiles,
+    ShowPeers,
+    ShowTrackers,
+    ShowOptions,
+    ToggleSidebar,
+    CycleView,
+    ShowViews,
+    ShowColumns,
+    CycleGroupBy,
+    VisualSelect,
+    SelectAll,
+    InvertSelection,
+    ClearSelection,
+    SelectSameCategory,
     ShowStats,
     ChangeCategory,
 }
@@ -18,10 +39,31 @@ impl UserAction for TorrentsAction {
     fn desc(&self) -> &'static str {
         match self {
             TorrentsAction::AddMagnet => "add a magnet",
+            TorrentsAction::CreateTorrent => "create a .torrent file",
             TorrentsAction::MoveTorrent => "move torrent download directory",
             TorrentsAction::Pause => "pause/unpause",
+            TorrentsAction::StartNow => "start now, bypassing the queue",
+            TorrentsAction::Verify => "verify local data",
+            TorrentsAction::Reannounce => "reannounce to trackers",
+            TorrentsAction::QueueMoveTop => "move to the top of the queue",
+            TorrentsAction::QueueMoveUp => "move up in the queue",
+            TorrentsAction::QueueMoveDown => "move down in the queue",
+            TorrentsAction::QueueMoveBottom => "move to the bottom of the queue",
             TorrentsAction::Delete => "delete",
             TorrentsAction::ShowFiles => "show files",
+            TorrentsAction::ShowPeers => "show peers",
+            TorrentsAction::ShowTrackers => "show trackers",
+            TorrentsAction::ShowOptions => "edit torrent options",
+            TorrentsAction::ToggleSidebar => "toggle filter sidebar",
+            TorrentsAction::CycleView => "switch to the next view",
+            TorrentsAction::ShowViews => "pick or save a view",
+            TorrentsAction::ShowColumns => "edit columns",
+            TorrentsAction::CycleGroupBy => "group by category/directory/tracker/status",
+            TorrentsAction::VisualSelect => "select a range of torrents",
+            TorrentsAction::SelectAll => "select all shown torrents",
+            TorrentsAction::InvertSelection => "invert selection of shown torrents",
+            TorrentsAction::ClearSelection => "clear selection",
+            TorrentsAction::SelectSameCategory => "select all shown in the same category",
             TorrentsAction::ShowStats => "show statistics",
             TorrentsAction::ChangeCategory => "change category",
             TorrentsAction::Rename => "rename torrent path",
@@ -33,10 +75,31 @@ impl From<TorrentsAction> for Action {
     fn from(value: TorrentsAction) -> Self {
         match value {
             TorrentsAction::AddMagnet => Action::AddMagnet,
+            TorrentsAction::CreateTorrent => Action::CreateTorrent,
             TorrentsAction::MoveTorrent => Action::MoveTorrent,
             TorrentsAction::Pause => Action::Pause,
+            TorrentsAction::StartNow => Action::StartNow,
+            TorrentsAction::Verify => Action::Verify,
+            TorrentsAction::Reannounce => Action::Reannounce,
+            TorrentsAction::QueueMoveTop => Action::QueueMoveTop,
+            TorrentsAction::QueueMoveUp => Action::QueueMoveUp,
+            TorrentsAction::QueueMoveDown => Action::QueueMoveDown,
+            TorrentsAction::QueueMoveBottom => Action::QueueMoveBottom,
             TorrentsAction::Delete => Action::Delete,
             TorrentsAction::ShowFiles => Action::ShowFiles,
+            TorrentsAction::ShowPeers => Action::ShowPeers,
+            TorrentsAction::ShowTrackers => Action::ShowTrackers,
+            TorrentsAction::ShowOptions => Action::ShowOptions,
+            TorrentsAction::ToggleSidebar => Action::ToggleSidebar,
+            TorrentsAction::CycleView => Action::CycleView,
+            TorrentsAction::ShowViews => Action::ShowViews,
+            TorrentsAction::ShowColumns => Action::ShowColumns,
+            TorrentsAction::CycleGroupBy => Action::CycleGroupBy,
+            TorrentsAction::VisualSelect => Action::VisualSelect,
+            TorrentsAction::SelectAll => Action::SelectAll,
+            TorrentsAction::InvertSelection => Action::InvertSelection,
+            TorrentsAction::ClearSelection => Action::ClearSelection,
+            TorrentsAction::SelectSameCategory => Action::SelectSameCategory,
             TorrentsAction::ShowStats => Action::ShowStats,
             TorrentsAction::ChangeCategory => Action::ChangeCategory,
             TorrentsAction::Rename => Action::Rename,
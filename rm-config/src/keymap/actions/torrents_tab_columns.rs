@@ -0,0 +1,36 @@
+use intuitils::user_action::UserAction;
+use rm_shared::action::Action;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TorrentsColumnsAction {
+    MoveColumnUp,
+    MoveColumnDown,
+    WidenColumn,
+    NarrowColumn,
+    ResetColumnWidth,
+}
+
+impl UserAction for TorrentsColumnsAction {
+    fn desc(&self) -> &'static str {
+        match self {
+            TorrentsColumnsAction::MoveColumnUp => "move column up",
+            TorrentsColumnsAction::MoveColumnDown => "move column down",
+            TorrentsColumnsAction::WidenColumn => "widen column",
+            TorrentsColumnsAction::NarrowColumn => "narrow column",
+            TorrentsColumnsAction::ResetColumnWidth => "reset column width",
+        }
+    }
+}
+
+impl From<TorrentsColumnsAction> for Action {
+    fn from(value: TorrentsColumnsAction) -> Self {
+        match value {
+            TorrentsColumnsAction::MoveColumnUp => Action::MoveColumnUp,
+            TorrentsColumnsAction::MoveColumnDown => Action::MoveColumnDown,
+            TorrentsColumnsAction::WidenColumn => Action::WidenColumn,
+            TorrentsColumnsAction::NarrowColumn => Action::NarrowColumn,
+            TorrentsColumnsAction::ResetColumnWidth => Action::ResetColumnWidth,
+        }
+    }
+}
@@ -2,7 +2,9 @@ pub mod actions;
 
 use std::collections::HashMap;
 
-use actions::torrents_tab_file_viewer::TorrentsFileViewerAction;
+use actions::{
+    torrents_tab_columns::TorrentsColumnsAction, torrents_tab_file_viewer::TorrentsFileViewerAction,
+};
 use crossterm::event::KeyModifiers;
 use intuitils::config::{
     keybindings::{KeyModifier, Keybinding, KeybindsHolder},
@@ -13,7 +15,8 @@ use serde::Deserialize;
 use rm_shared::action::Action;
 
 pub use self::actions::{
-    general::GeneralAction, search_tab::SearchAction, torrents_tab::TorrentsAction,
+    general::GeneralAction, search_tab::SearchAction, session_tab::SessionAction,
+    torrents_tab::TorrentsAction,
 };
 
 #[derive(Deserialize, Clone)]
@@ -22,7 +25,11 @@ pub struct KeymapConfig {
     pub torrents_tab: KeybindsHolder<TorrentsAction, Action>,
     #[serde(default = "default_torrents_tab_file_viewer")]
     pub torrents_tab_file_viewer: KeybindsHolder<TorrentsFileViewerAction, Action>,
+    #[serde(default = "default_torrents_tab_columns")]
+    pub torrents_tab_columns: KeybindsHolder<TorrentsColumnsAction, Action>,
     pub search_tab: KeybindsHolder<SearchAction, Action>,
+    #[serde(default = "default_session_tab")]
+    pub session_tab: KeybindsHolder<SessionAction, Action>,
 }
 
 fn default_torrents_tab_file_viewer() -> KeybindsHolder<TorrentsFileViewerAction, Action> {
@@ -44,6 +51,51 @@ fn default_torrents_tab_file_viewer() -> KeybindsHolder<TorrentsFileViewerAction
     }
 }
 
+fn default_torrents_tab_columns() -> KeybindsHolder<TorrentsColumnsAction, Action> {
+    let mut keybindings = vec![];
+    let mut map = HashMap::new();
+
+    for (key, action) in [
+        ('K', TorrentsColumnsAction::MoveColumnUp),
+        ('J', TorrentsColumnsAction::MoveColumnDown),
+        ('+', TorrentsColumnsAction::WidenColumn),
+        ('-', TorrentsColumnsAction::NarrowColumn),
+        ('r', TorrentsColumnsAction::ResetColumnWidth),
+    ] {
+        let keycode = crossterm::event::KeyCode::Char(key);
+        map.insert((keycode, KeyModifiers::NONE), action.into());
+        keybindings.push(Keybinding {
+            on: keycode,
+            modifier: KeyModifier::None,
+            action,
+            show_in_help: true,
+        });
+    }
+
+    KeybindsHolder { keybindings, map }
+}
+
+fn default_session_tab() -> KeybindsHolder<SessionAction, Action> {
+    let mut keybindings = vec![];
+    let mut map = HashMap::new();
+
+    for (key, action) in [
+        ('a', SessionAction::ApplyChanges),
+        ('u', SessionAction::DiscardChanges),
+    ] {
+        let keycode = crossterm::event::KeyCode::Char(key);
+        map.insert((keycode, KeyModifiers::NONE), action.into());
+        keybindings.push(Keybinding {
+            on: keycode,
+            modifier: KeyModifier::None,
+            action,
+            show_in_help: true,
+        });
+    }
+
+    KeybindsHolder { keybindings, map }
+}
+
 impl IntuiConfig for KeymapConfig {
     fn app_name() -> &'static str {
         "rustmission"
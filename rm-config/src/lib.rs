@@ -2,6 +2,7 @@ pub mod categories;
 pub mod keymap;
 pub mod logging;
 pub mod main_config;
+pub mod rss;
 
 use std::{path::PathBuf, sync::LazyLock};
 
@@ -24,6 +25,7 @@ pub struct Config {
     pub torrents_tab: main_config::TorrentsTab,
     pub search_tab: main_config::SearchTab,
     pub icons: main_config::Icons,
+    pub watch: main_config::Watch,
     pub keybindings: KeymapConfig,
     pub categories: CategoriesConfig,
     pub directories: Directories,
@@ -53,6 +55,7 @@ impl Config {
             torrents_tab: main_config.torrents_tab,
             search_tab: main_config.search_tab,
             icons: main_config.icons,
+            watch: main_config.watch,
             keybindings,
             categories,
             directories,
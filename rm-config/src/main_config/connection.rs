@@ -1,6 +1,8 @@
 use serde::Deserialize;
 use url::Url;
 
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+
 #[derive(Deserialize)]
 pub struct Connection {
     pub username: Option<String>,
@@ -12,6 +14,44 @@ pub struct Connection {
     pub stats_refresh: u64,
     #[serde(default = "default_refresh")]
     pub free_space_refresh: u64,
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Profile {
+    pub name: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub url: Url,
+}
+
+impl Connection {
+    /// All the profiles that can be connected to, with the one declared
+    /// directly under `[connection]` always being the first.
+    pub fn profiles(&self) -> Vec<Profile> {
+        let main_profile = Profile {
+            name: DEFAULT_PROFILE_NAME.to_string(),
+            username: self.username.clone(),
+            password: self.password.clone(),
+            url: self.url.clone(),
+        };
+
+        let mut profiles = vec![main_profile];
+        profiles.extend(self.profiles.iter().cloned());
+        profiles
+    }
+
+    pub fn profile(&self, name: &str) -> Option<Profile> {
+        self.profiles()
+            .into_iter()
+            .find(|profile| profile.name == name)
+    }
+
+    pub fn has_many_profiles(&self) -> bool {
+        !self.profiles.is_empty()
+    }
 }
 
 fn default_refresh() -> u64 {
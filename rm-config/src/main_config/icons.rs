@@ -34,6 +34,8 @@ pub struct Icons {
     pub verifying: String,
     #[serde(default = "default_loading")]
     pub loading: String,
+    #[serde(default = "default_queued")]
+    pub queued: String,
     #[serde(default = "default_pause")]
     pub pause: String,
     #[serde(default = "default_idle")]
@@ -50,6 +52,8 @@ pub struct Icons {
     pub sort_ascending: String,
     #[serde(default = "default_sort_descending")]
     pub sort_descending: String,
+    #[serde(default = "default_turtle")]
+    pub turtle: String,
 }
 
 impl Default for Icons {
@@ -71,6 +75,7 @@ impl Default for Icons {
             searching: default_searching(),
             verifying: default_verifying(),
             loading: default_loading(),
+            queued: default_queued(),
             pause: default_pause(),
             idle: default_idle(),
             magnifying_glass: default_magnifying_glass(),
@@ -79,6 +84,7 @@ impl Default for Icons {
             provider_category_anime: default_provider_category_anime(),
             sort_ascending: default_sort_ascending(),
             sort_descending: default_sort_descending(),
+            turtle: default_turtle(),
         }
     }
 }
@@ -146,6 +152,10 @@ fn default_loading() -> String {
     "󱥸".into()
 }
 
+fn default_queued() -> String {
+    "\u{f051f}".into()
+}
+
 fn default_pause() -> String {
     "󰏤".into()
 }
@@ -177,3 +187,7 @@ fn default_sort_ascending() -> String {
 fn default_sort_descending() -> String {
     "󰒽".into()
 }
+
+fn default_turtle() -> String {
+    "\u{f0d3b}".into()
+}
@@ -3,13 +3,15 @@ mod general;
 mod icons;
 mod search_tab;
 mod torrents_tab;
+mod watch;
 
-pub use connection::Connection;
+pub use connection::{Connection, Profile, DEFAULT_PROFILE_NAME};
 pub use general::General;
 pub use icons::Icons;
 use intuitils::config::IntuiConfig;
 pub use search_tab::SearchTab;
-pub use torrents_tab::TorrentsTab;
+pub use torrents_tab::{GroupBy, TorrentsTab, View};
+pub use watch::{Watch, WatchDir};
 
 use serde::Deserialize;
 
@@ -24,6 +26,8 @@ pub struct MainConfig {
     pub search_tab: SearchTab,
     #[serde(default)]
     pub icons: Icons,
+    #[serde(default)]
+    pub watch: Watch,
 }
 
 impl IntuiConfig for MainConfig {
@@ -1,24 +1,217 @@
+use std::{collections::HashMap, path::Path};
+
+use color_eyre::{eyre::eyre, Result};
 use rm_shared::header::Header;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
+use toml_edit::{value, Array, ArrayOfTables, DocumentMut, Item, Table, TableLike};
 
 #[derive(Deserialize)]
 pub struct TorrentsTab {
     #[serde(default = "default_headers")]
     pub headers: Vec<Header>,
-    #[serde(default = "default_sort")]
-    pub default_sort: Header,
+    /// Widths overriding the default ones, in characters.
+    #[serde(default)]
+    pub column_widths: HashMap<Header, u16>,
+    /// Headers to sort by, each one breaking ties of the previous.
+    #[serde(default = "default_sort", deserialize_with = "one_or_many_headers")]
+    pub default_sort: Vec<Header>,
     #[serde(default = "default_true")]
     pub default_sort_reverse: bool,
     #[serde(default = "default_true")]
     pub category_icon_insert_into_name: bool,
+    #[serde(default)]
+    pub group_by: GroupBy,
+    #[serde(default)]
+    pub views: Vec<View>,
+}
+
+#[derive(Deserialize, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupBy {
+    #[default]
+    None,
+    Category,
+    Directory,
+    Tracker,
+    Status,
+}
+
+impl GroupBy {
+    pub fn next(self) -> Self {
+        match self {
+            GroupBy::None => GroupBy::Category,
+            GroupBy::Category => GroupBy::Directory,
+            GroupBy::Directory => GroupBy::Tracker,
+            GroupBy::Tracker => GroupBy::Status,
+            GroupBy::Status => GroupBy::None,
+        }
+    }
+}
+
+impl TorrentsTab {
+    /// Writes the headers and column widths into the config at `path`, leaving
+    /// the rest of the file (comments included) untouched. Headers go to the
+    /// view named `view` if it's given.
+    pub fn write_columns(
+        path: &Path,
+        view: Option<&str>,
+        headers: &[Header],
+        widths: &HashMap<Header, u16>,
+    ) -> Result<()> {
+        let mut document: DocumentMut = std::fs::read_to_string(path)?.parse()?;
+
+        let torrents_tab = document
+            .entry("torrents_tab")
+            .or_insert_with(|| Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| eyre!("torrents_tab is not a table"))?;
+
+        let headers = headers
+            .iter()
+            .map(|header| header_key(*header))
+            .collect::<Result<Array>>()?;
+        match view {
+            Some(name) => {
+                let view = torrents_tab
+                    .get_mut("views")
+                    .and_then(Item::as_array_of_tables_mut)
+                    .and_then(|views| {
+                        views
+                            .iter_mut()
+                            .find(|view| view.get("name").and_then(Item::as_str) == Some(name))
+                    })
+                    .ok_or_else(|| eyre!("view \"{name}\" not found in the config"))?;
+                replace_value(view, "headers", headers);
+            }
+            None => replace_value(torrents_tab, "headers", headers),
+        }
+
+        if widths.is_empty() {
+            torrents_tab.remove("column_widths");
+        } else {
+            let widths_table = torrents_tab
+                .entry("column_widths")
+                .or_insert_with(|| Item::Table(Table::new()))
+                .as_table_like_mut()
+                .ok_or_else(|| eyre!("torrents_tab.column_widths is not a table"))?;
+            for header in Header::ALL {
+                let key = header_key(header)?;
+                match widths.get(&header) {
+                    Some(width) => replace_value(widths_table, &key, i64::from(*width)),
+                    None => {
+                        widths_table.remove(&key);
+                    }
+                }
+            }
+        }
+
+        std::fs::write(path, document.to_string())?;
+        Ok(())
+    }
+}
+
+/// Sets `key` to `new_value`, keeping the comments around the old one.
+fn replace_value(table: &mut dyn TableLike, key: &str, new_value: impl Into<toml_edit::Value>) {
+    let mut new_value = new_value.into();
+    match table.get_mut(key) {
+        Some(item) => {
+            if let Some(old_value) = item.as_value() {
+                *new_value.decor_mut() = old_value.decor().clone();
+            }
+            *item = Item::Value(new_value);
+        }
+        None => {
+            table.insert(key, Item::Value(new_value));
+        }
+    }
+}
+
+/// A named combination of a filter, a sort and a set of headers.
+#[derive(Deserialize, Clone)]
+pub struct View {
+    pub name: String,
+    #[serde(default)]
+    pub filter: String,
+    #[serde(default, deserialize_with = "one_or_many_headers")]
+    pub sort: Vec<Header>,
+    #[serde(default)]
+    pub sort_reverse: bool,
+    pub headers: Option<Vec<Header>>,
+}
+
+impl View {
+    /// Appends the view to `[[torrents_tab.views]]` of the config at `path`,
+    /// leaving the rest of the file (comments included) untouched.
+    pub fn append_to_config(&self, path: &Path) -> Result<()> {
+        let mut document: DocumentMut = std::fs::read_to_string(path)?.parse()?;
+
+        let torrents_tab = document
+            .entry("torrents_tab")
+            .or_insert_with(|| Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| eyre!("torrents_tab is not a table"))?;
+        let views = torrents_tab
+            .entry("views")
+            .or_insert_with(|| Item::ArrayOfTables(ArrayOfTables::new()))
+            .as_array_of_tables_mut()
+            .ok_or_else(|| eyre!("torrents_tab.views is not an array of tables"))?;
+
+        let mut table = Table::new();
+        table.insert("name", value(&self.name));
+        table.insert("filter", value(&self.filter));
+        if !self.sort.is_empty() {
+            let sort = self
+                .sort
+                .iter()
+                .map(|header| header_key(*header))
+                .collect::<Result<Array>>()?;
+            table.insert("sort", value(sort));
+        }
+        table.insert("sort_reverse", value(self.sort_reverse));
+        if let Some(headers) = &self.headers {
+            let headers = headers
+                .iter()
+                .map(|header| header_key(*header))
+                .collect::<Result<Array>>()?;
+            table.insert("headers", value(headers));
+        }
+        views.push(table);
+
+        std::fs::write(path, document.to_string())?;
+        Ok(())
+    }
+}
+
+fn header_key(header: Header) -> Result<String> {
+    match toml::Value::try_from(header)? {
+        toml::Value::String(key) => Ok(key),
+        _ => Err(eyre!("header didn't serialize into a string")),
+    }
+}
+
+/// Sorting used to take a single header, which is still accepted.
+fn one_or_many_headers<'de, D>(deserializer: D) -> std::result::Result<Vec<Header>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(Header),
+        Many(Vec<Header>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(header) => vec![header],
+        OneOrMany::Many(headers) => headers,
+    })
 }
 
 fn default_true() -> bool {
     true
 }
 
-fn default_sort() -> Header {
-    Header::AddedDate
+fn default_sort() -> Vec<Header> {
+    vec![Header::AddedDate]
 }
 
 fn default_headers() -> Vec<Header> {
@@ -36,9 +229,12 @@ impl Default for TorrentsTab {
     fn default() -> Self {
         Self {
             headers: default_headers(),
+            column_widths: HashMap::new(),
             default_sort: default_sort(),
             default_sort_reverse: default_true(),
             category_icon_insert_into_name: default_true(),
+            group_by: GroupBy::default(),
+            views: vec![],
         }
     }
 }
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Watch {
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+    #[serde(default)]
+    pub dirs: Vec<WatchDir>,
+}
+
+impl Default for Watch {
+    fn default() -> Self {
+        Self {
+            interval: default_interval(),
+            dirs: vec![],
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct WatchDir {
+    pub path: PathBuf,
+    pub category: Option<String>,
+    pub download_dir: Option<String>,
+}
+
+fn default_interval() -> u64 {
+    5
+}
@@ -0,0 +1,47 @@
+use intuitils::config::IntuiConfig;
+use serde::Deserialize;
+use url::Url;
+
+#[derive(Deserialize)]
+pub struct RssConfig {
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+    #[serde(default)]
+    pub feeds: Vec<Feed>,
+}
+
+impl IntuiConfig for RssConfig {
+    fn app_name() -> &'static str {
+        "rustmission"
+    }
+
+    fn filename() -> &'static str {
+        "rss.toml"
+    }
+
+    fn default_config() -> &'static str {
+        include_str!("../defaults/rss.toml")
+    }
+
+    fn should_exit_if_not_found() -> bool {
+        false
+    }
+
+    fn message_if_not_found() -> Option<String> {
+        Some(format!("Add your feeds to {:?}", Self::path()))
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Feed {
+    pub url: Url,
+    pub include: Option<String>,
+    pub exclude: Option<String>,
+    pub category: Option<String>,
+    pub download_dir: Option<String>,
+    pub interval: Option<u64>,
+}
+
+fn default_interval() -> u64 {
+    900
+}
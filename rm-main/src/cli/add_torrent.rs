@@ -1,46 +1,146 @@
-use std::{fs::File, io::Read};
+use std::io::BufRead;
 
 use base64::Engine;
-use color_eyre::Result;
-use transmission_rpc::types::TorrentAddArgs;
+use clap::ValueEnum;
+use color_eyre::{
+    eyre::{bail, eyre},
+    Result,
+};
+use rm_config::{main_config::Profile, CONFIG};
+use transmission_rpc::{
+    types::{Priority, TorrentAddArgs, TorrentAddedOrDuplicate},
+    TransClient,
+};
 
 use crate::transmission;
 
-pub(super) async fn add_torrent(torrent: String) -> Result<()> {
-    let mut transclient = transmission::utils::new_client();
-    let args = {
-        if torrent.starts_with("magnet:")
-            || torrent.starts_with("http:")
-            || torrent.starts_with("https:")
-        {
-            TorrentAddArgs {
-                filename: Some(torrent),
-                ..Default::default()
-            }
-        } else if torrent.starts_with("www") {
-            TorrentAddArgs {
-                filename: Some(format!("https://{torrent}")),
-                ..Default::default()
+#[derive(Clone, Copy, ValueEnum)]
+pub enum AddPriority {
+    Low,
+    Normal,
+    High,
+}
+
+impl From<AddPriority> for Priority {
+    fn from(value: AddPriority) -> Self {
+        match value {
+            AddPriority::Low => Priority::Low,
+            AddPriority::Normal => Priority::Normal,
+            AddPriority::High => Priority::High,
+        }
+    }
+}
+
+pub struct AddTorrentArgs {
+    pub dir: Option<String>,
+    pub category: Option<String>,
+    pub paused: bool,
+    pub priority: Option<AddPriority>,
+}
+
+pub(super) async fn add_torrents(
+    profile: &Profile,
+    inputs: Vec<String>,
+    args: AddTorrentArgs,
+) -> Result<()> {
+    let mut torrents = vec![];
+    for input in inputs {
+        if input == "-" {
+            for line in std::io::stdin().lock().lines() {
+                let line = line?;
+                let line = line.trim();
+                if !line.is_empty() {
+                    torrents.push(line.to_string());
+                }
             }
         } else {
-            let mut torrent_file = File::open(torrent)?;
-            let mut buf = vec![];
-            torrent_file.read_to_end(&mut buf).unwrap();
-            let metainfo = base64::engine::general_purpose::STANDARD.encode(buf);
-            TorrentAddArgs {
-                metainfo: Some(metainfo),
-                ..Default::default()
-            }
+            torrents.push(input);
         }
-    };
+    }
 
-    if let Err(e) = transclient.torrent_add(args).await {
-        eprintln!("error while adding a torrent: {e}");
-        if e.to_string().contains("expected value at line") {
-            eprintln!("Check whether your arguments are valid.");
+    let mut transclient = transmission::utils::new_client(profile);
+    let mut failed = 0;
+
+    for torrent in &torrents {
+        match add_torrent(&mut transclient, torrent, &args).await {
+            Ok(message) => {
+                tracing::info!("{message}: {torrent}");
+                println!("{message}: {torrent}");
+            }
+            Err(e) => {
+                tracing::error!("Cannot add torrent: {torrent}, {e}");
+                eprintln!("Failed to add {torrent}: {e}");
+                if e.to_string().contains("expected value at line") {
+                    eprintln!("Check whether your arguments are valid.");
+                }
+                failed += 1;
+            }
         }
+    }
+
+    if failed > 0 {
+        bail!(
+            "{failed} out of {} torrents failed to be added",
+            torrents.len()
+        );
+    }
 
-        std::process::exit(1);
-    };
     Ok(())
 }
+
+pub(super) async fn add_torrent(
+    transclient: &mut TransClient,
+    torrent: &str,
+    args: &AddTorrentArgs,
+) -> Result<&'static str> {
+    let mut add_args = if torrent.starts_with("magnet:")
+        || torrent.starts_with("http:")
+        || torrent.starts_with("https:")
+    {
+        TorrentAddArgs {
+            filename: Some(torrent.to_string()),
+            ..Default::default()
+        }
+    } else if torrent.starts_with("www") {
+        TorrentAddArgs {
+            filename: Some(format!("https://{torrent}")),
+            ..Default::default()
+        }
+    } else {
+        let buf = std::fs::read(torrent)?;
+        let metainfo = base64::engine::general_purpose::STANDARD.encode(buf);
+        TorrentAddArgs {
+            metainfo: Some(metainfo),
+            ..Default::default()
+        }
+    };
+
+    // The same way as in the TUI, the category's default directory is used
+    // unless a directory was explicitly given.
+    let category_dir = args
+        .category
+        .as_ref()
+        .and_then(|category| CONFIG.categories.map.get(category))
+        .and_then(|category| category.default_dir.clone());
+
+    add_args.download_dir = args.dir.clone().or(category_dir);
+    add_args.labels = args.category.clone().map(|category| vec![category]);
+    add_args.paused = args.paused.then_some(true);
+    add_args.bandwidth_priority = args.priority.map(Priority::from);
+
+    // Kept as is, so that `watch` can tell connection errors apart.
+    let response = transclient
+        .torrent_add(add_args)
+        .await
+        .map_err(|e| eyre!(e))?;
+
+    if !response.is_ok() {
+        bail!("{}", response.result);
+    }
+
+    match response.arguments {
+        TorrentAddedOrDuplicate::TorrentAdded(_) => Ok("Added"),
+        TorrentAddedOrDuplicate::TorrentDuplicate(_) => Ok("Already added"),
+        TorrentAddedOrDuplicate::Error => bail!("unexpected response from Transmission"),
+    }
+}
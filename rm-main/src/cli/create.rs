@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use base64::Engine;
+use clap::Args;
+use color_eyre::{eyre::eyre, Result};
+use transmission_rpc::types::TorrentAddArgs;
+
+use crate::{
+    metainfo::{create_torrent, CreateOptions},
+    transmission::{self, TorrentAction},
+};
+
+use super::torrent_action::send_torrent_action;
+
+#[derive(Args)]
+pub struct CreateArgs {
+    /// File or directory to make a torrent of
+    path: PathBuf,
+    /// Where to write the .torrent, <name>.torrent in the current directory by default
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Announce URL of a tracker, can be given multiple times
+    #[arg(long = "tracker")]
+    trackers: Vec<String>,
+    #[arg(long)]
+    comment: Option<String>,
+    /// Mark the torrent as private, so that only its trackers are used to find peers
+    #[arg(long)]
+    private: bool,
+    /// Piece size in KiB, a power of two from 16 to 16384, picked based on the total size
+    /// if not given
+    #[arg(long)]
+    piece_size: Option<u64>,
+    /// Add the torrent to Transmission afterwards, seeding from the given path
+    #[arg(long)]
+    add: bool,
+}
+
+pub(super) async fn create(profile: Option<&str>, args: CreateArgs) -> Result<()> {
+    // Looked up first, so that a typo doesn't show up only after all the hashing.
+    let profile = args
+        .add
+        .then(|| transmission::utils::find_profile(profile))
+        .transpose()?;
+    let path = std::path::absolute(&args.path)?;
+    let name = path
+        .file_name()
+        .ok_or_else(|| eyre!("{path:?} has no file name"))?
+        .to_string_lossy()
+        .into_owned();
+    let output = std::path::absolute(
+        args.output
+            .unwrap_or_else(|| PathBuf::from(format!("{name}.torrent"))),
+    )?;
+
+    let options = CreateOptions {
+        trackers: args.trackers,
+        comment: args.comment,
+        private: args.private,
+        piece_length: args
+            .piece_size
+            .map(|kib| {
+                kib.checked_mul(1024)
+                    .ok_or_else(|| eyre!("piece size of {kib} KiB is too big"))
+            })
+            .transpose()?,
+    };
+
+    println!("Hashing {path:?}...");
+    let source = path.clone();
+    let torrent = tokio::task::spawn_blocking(move || create_torrent(&source, &options)).await??;
+    std::fs::write(&output, &torrent)?;
+    println!("Created {output:?}");
+
+    if let Some(profile) = profile {
+        let download_dir = path
+            .parent()
+            .map(|parent| parent.to_string_lossy().into_owned());
+        // Sent as metainfo, as the daemon may not see this machine's files.
+        let args = TorrentAddArgs {
+            metainfo: Some(base64::engine::general_purpose::STANDARD.encode(&torrent)),
+            download_dir,
+            ..Default::default()
+        };
+        let action = TorrentAction::AddMetainfo(name.clone(), Box::new(args));
+        send_torrent_action(&profile, action).await?;
+        println!("Added {name}");
+    }
+
+    Ok(())
+}
@@ -1,44 +1,17 @@
 use color_eyre::{eyre::bail, Result};
-use regex::Regex;
-use transmission_rpc::types::TorrentAddArgs;
+use rm_config::main_config::Profile;
 
 use crate::transmission;
 
-pub async fn fetch_rss(url: &str, filter: Option<&str>) -> Result<()> {
-    let mut transclient = transmission::utils::new_client();
-    let content = reqwest::get(url).await?.bytes().await?;
-    let channel = rss::Channel::read_from(&content[..])?;
-    let re: Option<Regex> = {
-        if let Some(filter_str) = filter {
-            let res = Regex::new(filter_str)?;
-            Some(res)
-        } else {
-            None
-        }
-    };
-    let items = channel.items().iter().filter_map(|item| {
-        if let (Some(title), Some(url)) = (item.title(), item.link()) {
-            if let Some(re) = &re {
-                if re.is_match(title) {
-                    return Some((title, url));
-                }
-            } else {
-                return Some((title, url));
-            }
-        }
-        None
-    });
-    for (title, url) in items {
-        tracing::info!("RSS: downloading {title} from {url}");
-        println!("downloading {title}");
-        let args = TorrentAddArgs {
-            filename: Some(url.to_string()),
-            ..Default::default()
-        };
-        if let Err(e) = transclient.torrent_add(args).await {
-            tracing::error!("RSS: cannot download {title} from {url} {e}");
-            bail!("error while adding a torrent: {e}")
-        }
+use super::rss::{poll_feed, FeedRule};
+
+pub async fn fetch_rss(profile: &Profile, url: &str, filter: Option<&str>) -> Result<()> {
+    let mut transclient = transmission::utils::new_client(profile);
+    let rule = FeedRule::with_filter(url, filter)?;
+
+    let summary = poll_feed(&mut transclient, &rule, None).await?;
+    if summary.failed > 0 {
+        bail!("{} RSS items failed to be added", summary.failed);
     }
     Ok(())
 }
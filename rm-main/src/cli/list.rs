@@ -0,0 +1,375 @@
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use color_eyre::{eyre::eyre, Result};
+use rm_config::{main_config::Profile, CONFIG};
+use rm_shared::header::Header;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use transmission_rpc::types::{ErrorType, RatioMode, Torrent, TorrentStatus, TrackerStat};
+
+use crate::{
+    transmission::{
+        self,
+        fetchers::torrent_fields,
+        utils::{checked, tracker_host},
+    },
+    tui::tabs::torrents::{query::Query, rustmission_torrent::RustmissionTorrent},
+};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ListFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum StatusFilter {
+    Stopped,
+    Queued,
+    Verifying,
+    Downloading,
+    Seeding,
+    Error,
+}
+
+impl StatusFilter {
+    fn matches(self, torrent: &Torrent) -> bool {
+        let status = torrent.status.expect("field requested");
+        match self {
+            Self::Stopped => status == TorrentStatus::Stopped,
+            Self::Queued => matches!(
+                status,
+                TorrentStatus::QueuedToVerify
+                    | TorrentStatus::QueuedToDownload
+                    | TorrentStatus::QueuedToSeed
+            ),
+            Self::Verifying => status == TorrentStatus::Verifying,
+            Self::Downloading => status == TorrentStatus::Downloading,
+            Self::Seeding => status == TorrentStatus::Seeding,
+            Self::Error => torrent.error.expect("field requested") != ErrorType::Ok,
+        }
+    }
+}
+
+pub struct ListArgs {
+    pub format: ListFormat,
+    pub fields: Vec<Header>,
+    pub filter: Option<String>,
+    pub status: Option<StatusFilter>,
+    pub category: Option<String>,
+}
+
+/// Parses a header the same way it's written in `torrents_tab.headers`.
+pub fn parse_header(name: &str) -> Result<Header, String> {
+    use serde::de::{value::StrDeserializer, IntoDeserializer};
+
+    let deserializer: StrDeserializer<'_, serde::de::value::Error> = name.into_deserializer();
+    Header::deserialize(deserializer).map_err(|_| format!("unknown field: {name}"))
+}
+
+pub(super) async fn list(profile: &Profile, args: ListArgs) -> Result<()> {
+    let query = match &args.filter {
+        Some(filter) => Query::parse(filter).map_err(|e| eyre!("invalid filter: {e}"))?,
+        None => None,
+    };
+
+    let mut client = transmission::utils::new_client(profile);
+
+    let fields = if args.fields.is_empty() {
+        CONFIG.torrents_tab.headers.clone()
+    } else {
+        args.fields
+    };
+    let fields: Vec<Header> = fields
+        .into_iter()
+        .filter(|header| *header != Header::Padding)
+        .collect();
+
+    let mut torrents = checked(
+        client
+            .torrent_get(Some(torrent_fields(&fields)), None)
+            .await,
+    )
+    .map_err(|e| eyre!("failed to fetch torrents: {e}"))?
+    .arguments
+    .torrents;
+
+    let now = Utc::now();
+    torrents.retain(|torrent| {
+        let query_matches = query
+            .as_ref()
+            .is_none_or(|query| query.matches(&RustmissionTorrent::from(torrent.clone()), now));
+        let status_matches = args.status.is_none_or(|status| status.matches(torrent));
+        let category_matches = args
+            .category
+            .as_ref()
+            .is_none_or(|category| category_of(torrent) == Some(category.as_str()));
+
+        query_matches && status_matches && category_matches
+    });
+
+    let output = match args.format {
+        ListFormat::Table => format_table(&torrents, &fields),
+        ListFormat::Json => format_json(&torrents, &fields)?,
+        ListFormat::Csv => format_csv(&torrents, &fields),
+    };
+    print!("{output}");
+
+    Ok(())
+}
+
+fn format_table(torrents: &[Torrent], fields: &[Header]) -> String {
+    let header_row: Vec<String> = fields
+        .iter()
+        .map(|header| match header.header_name() {
+            "" => field_name(*header),
+            name => name.to_string(),
+        })
+        .collect();
+
+    let rows: Vec<Vec<String>> = torrents
+        .iter()
+        .map(|torrent| {
+            let torrent = RustmissionTorrent::from(torrent.clone());
+            fields
+                .iter()
+                .map(|header| torrent.header_text(*header))
+                .collect()
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = header_row.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut output = String::new();
+    for row in std::iter::once(&header_row).chain(&rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+fn format_json(torrents: &[Torrent], fields: &[Header]) -> Result<String> {
+    let torrents: Vec<Value> = torrents
+        .iter()
+        .map(|torrent| {
+            let object: Map<String, Value> = fields
+                .iter()
+                .map(|header| (field_name(*header), raw_value(torrent, *header)))
+                .collect();
+            Value::Object(object)
+        })
+        .collect();
+
+    Ok(serde_json::to_string_pretty(&torrents)? + "\n")
+}
+
+fn format_csv(torrents: &[Torrent], fields: &[Header]) -> String {
+    let header_row: Vec<String> = fields.iter().map(|header| field_name(*header)).collect();
+    let mut output = header_row.join(",") + "\n";
+
+    for torrent in torrents {
+        let row: Vec<String> = fields
+            .iter()
+            .map(|header| match raw_value(torrent, *header) {
+                Value::String(value) => csv_escape(&value),
+                Value::Null => String::new(),
+                value => value.to_string(),
+            })
+            .collect();
+        output.push_str(&row.join(","));
+        output.push('\n');
+    }
+    output
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn field_name(header: Header) -> String {
+    serde_json::to_value(header)
+        .ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+fn category_of(torrent: &Torrent) -> Option<&str> {
+    torrent
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.first())
+        .map(String::as_str)
+}
+
+fn status_name(torrent: &Torrent) -> &'static str {
+    if torrent.error.expect("field requested") != ErrorType::Ok {
+        return "Error";
+    }
+
+    match torrent.status.expect("field requested") {
+        TorrentStatus::Stopped => "Stopped",
+        TorrentStatus::QueuedToVerify => "QueuedToVerify",
+        TorrentStatus::Verifying => "Verifying",
+        TorrentStatus::QueuedToDownload => "QueuedToDownload",
+        TorrentStatus::Downloading => "Downloading",
+        TorrentStatus::QueuedToSeed => "QueuedToSeed",
+        TorrentStatus::Seeding => "Seeding",
+    }
+}
+
+/// Highest count reported by any of the trackers, if any of them knows.
+fn peer_count(torrent: &Torrent, count: impl Fn(&TrackerStat) -> i64) -> Option<i64> {
+    torrent
+        .tracker_stats
+        .as_ref()?
+        .iter()
+        .map(count)
+        .max()
+        .filter(|count| *count >= 0)
+}
+
+fn primary_tracker_host(torrent: &Torrent) -> Option<String> {
+    torrent
+        .trackers
+        .as_ref()?
+        .iter()
+        .find_map(|tracker| tracker_host(&tracker.announce))
+}
+
+fn done_date(torrent: &Torrent) -> Option<DateTime<Utc>> {
+    torrent.done_date.filter(|date| date.timestamp() > 0)
+}
+
+fn ratio_limit(torrent: &Torrent) -> Option<f32> {
+    match torrent.seed_ratio_mode? {
+        RatioMode::Single => torrent.seed_ratio_limit,
+        RatioMode::Global | RatioMode::Unlimited => None,
+    }
+}
+
+fn category_icon(torrent: &Torrent) -> Option<String> {
+    category_of(torrent)
+        .and_then(|category| CONFIG.categories.map.get(category))
+        .map(|category| category.icon.clone())
+}
+
+/// Value as it is returned by Transmission. Used by the machine-readable formats.
+fn raw_value(torrent: &Torrent, header: Header) -> Value {
+    let date = |date: Option<DateTime<Utc>>| json!(date.map(|date| date.timestamp()));
+
+    match header {
+        Header::Id => json!(torrent.id),
+        Header::Name => json!(torrent.name),
+        Header::SizeWhenDone => json!(torrent.size_when_done),
+        Header::Progress => json!(torrent.percent_done),
+        Header::Eta => json!(torrent.eta),
+        Header::DownloadRate => json!(torrent.rate_download),
+        Header::UploadRate => json!(torrent.rate_upload),
+        Header::DownloadDir => json!(torrent.download_dir),
+        Header::Padding => Value::Null,
+        Header::UploadRatio => json!(torrent.upload_ratio),
+        Header::UploadedEver => json!(torrent.uploaded_ever),
+        Header::ActivityDate => date(torrent.activity_date),
+        Header::AddedDate => date(torrent.added_date),
+        Header::PeersConnected => json!(torrent.peers_connected),
+        Header::SmallStatus => json!(status_name(torrent)),
+        Header::Category => json!(category_of(torrent)),
+        Header::CategoryIcon => json!(category_icon(torrent)),
+        Header::QueuePosition => json!(torrent.queue_position),
+        Header::Status => json!(status_name(torrent)),
+        Header::Seeders => json!(peer_count(torrent, |stat| stat.seeder_count)),
+        Header::Leechers => json!(peer_count(torrent, |stat| stat.leecher_count)),
+        Header::HashString => json!(torrent.hash_string),
+        Header::DoneDate => date(done_date(torrent)),
+        Header::SecondsSeeding => json!(torrent.seconds_seeding),
+        Header::SecondsDownloading => json!(torrent.seconds_downloading),
+        Header::TotalSize => json!(torrent.total_size),
+        Header::DownloadedEver => json!(torrent.downloaded_ever),
+        Header::LeftUntilDone => json!(torrent.left_until_done),
+        Header::Labels => json!(torrent.labels),
+        Header::TrackerHost => json!(primary_tracker_host(torrent)),
+        Header::Private => json!(torrent.is_private),
+        Header::RatioLimit => json!(ratio_limit(torrent)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIELDS: [Header; 4] = [Header::Id, Header::Name, Header::Category, Header::Progress];
+
+    fn torrents() -> Vec<Torrent> {
+        serde_json::from_value(json!([
+            {
+                "id": 1,
+                "name": "Debian, netinst",
+                "labels": ["linux"],
+                "percentDone": 0.5,
+            },
+            {
+                "id": 2,
+                "name": "The \"Best\" Movie",
+                "labels": [],
+                "percentDone": 1.0,
+            },
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn csv_escaping() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a, b"), "\"a, b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_escape("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            format_csv(&torrents(), &FIELDS),
+            concat!(
+                "Id,Name,Category,Progress\n",
+                "1,\"Debian, netinst\",linux,0.5\n",
+                "2,\"The \"\"Best\"\" Movie\",,1.0\n",
+            )
+        );
+    }
+
+    #[test]
+    fn json() {
+        let output = format_json(&torrents(), &FIELDS).unwrap();
+        let value: Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(
+            value,
+            json!([
+                {"Id": 1, "Name": "Debian, netinst", "Category": "linux", "Progress": 0.5},
+                {"Id": 2, "Name": "The \"Best\" Movie", "Category": null, "Progress": 1.0},
+            ])
+        );
+    }
+
+    #[test]
+    fn header_names() {
+        assert_eq!(parse_header("SizeWhenDone"), Ok(Header::SizeWhenDone));
+        assert!(parse_header("Size").is_err());
+        assert_eq!(field_name(Header::SizeWhenDone), "SizeWhenDone");
+    }
+}
@@ -1,41 +1,235 @@
 mod add_torrent;
+mod create;
 mod fetch_rss;
+mod list;
+mod rss;
+mod torrent_action;
+mod turtle;
+mod watch;
+
+use std::path::PathBuf;
 
 use clap::{Parser, Subcommand};
 use color_eyre::Result;
 
-use add_torrent::add_torrent;
+use add_torrent::{add_torrents, AddPriority, AddTorrentArgs};
+use create::{create, CreateArgs};
 use fetch_rss::fetch_rss;
 use intuitils::config::IntuiConfig;
+use list::{list, parse_header, ListArgs, ListFormat, StatusFilter};
+use rm_shared::header::Header;
+use rss::{handle_rss_command, RssCommand};
+use torrent_action::{torrent_action, TorrentSelector};
+use turtle::{turtle, TurtleMode};
+use watch::watch;
+
+use crate::transmission::{utils::find_profile, TorrentAction};
 
 #[derive(Parser)]
 #[command(version, about)]
 pub struct Args {
     #[command(subcommand)]
     pub command: Option<Commands>,
+    /// Name of the connection profile (from config.toml) to use
+    #[arg(short, long, global = true)]
+    pub profile: Option<String>,
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
-    AddTorrent { torrent: String },
-    FetchRss { url: String, filter: Option<String> },
+    AddTorrent {
+        /// Magnets, URLs or paths to .torrent files. Use "-" to read them from stdin
+        #[arg(required = true)]
+        torrents: Vec<String>,
+        /// Directory to download to
+        #[arg(long)]
+        dir: Option<String>,
+        /// Category from categories.toml, its default directory is used unless --dir is given
+        #[arg(long)]
+        category: Option<String>,
+        /// Don't start downloading right away
+        #[arg(long)]
+        paused: bool,
+        #[arg(long, value_enum)]
+        priority: Option<AddPriority>,
+    },
+    /// Create a .torrent file from a local file or directory
+    Create(CreateArgs),
+    FetchRss {
+        url: String,
+        filter: Option<String>,
+    },
+    /// Add torrents from feeds defined in rss.toml
+    Rss {
+        #[command(subcommand)]
+        command: RssCommand,
+    },
+    /// Add .torrent and .magnet files dropped into directories
+    Watch {
+        /// Directories to watch, [watch] section from config.toml is used if none given
+        dirs: Vec<PathBuf>,
+        /// Category for torrents from given directories
+        #[arg(long)]
+        category: Option<String>,
+        /// Download directory for torrents from given directories
+        #[arg(long)]
+        dir: Option<String>,
+    },
+    /// Print torrents, optionally filtered
+    List {
+        #[arg(long, value_enum, default_value_t = ListFormat::Table)]
+        format: ListFormat,
+        /// Comma-separated, same names as in torrents_tab.headers
+        #[arg(long, value_delimiter = ',', value_parser = parse_header)]
+        fields: Vec<Header>,
+        /// Query in the same language as the torrents tab search, e.g. 'status:seeding size>10G'
+        #[arg(long)]
+        filter: Option<String>,
+        #[arg(long, value_enum)]
+        status: Option<StatusFilter>,
+        #[arg(long)]
+        category: Option<String>,
+    },
+    Start(TorrentSelector),
+    Stop(TorrentSelector),
+    Remove {
+        /// Delete downloaded data too
+        #[arg(long)]
+        with_files: bool,
+        #[command(flatten)]
+        selector: TorrentSelector,
+    },
+    Move {
+        /// Directory to move the data to
+        #[arg(long)]
+        to: String,
+        #[command(flatten)]
+        selector: TorrentSelector,
+    },
+    SetCategory {
+        /// Category to set, empty to clear it
+        #[arg(long)]
+        to: String,
+        #[command(flatten)]
+        selector: TorrentSelector,
+    },
+    Verify(TorrentSelector),
+    Reannounce(TorrentSelector),
+    /// Turn alternative speed limits on or off
+    Turtle {
+        #[arg(value_enum)]
+        mode: TurtleMode,
+    },
     PrintDefaultConfig {},
     PrintDefaultKeymap {},
     PrintDefaultCategories {},
 }
 
-pub async fn handle_command(command: Commands) -> Result<()> {
+pub async fn handle_command(command: Commands, profile: Option<String>) -> Result<()> {
     match command {
-        Commands::AddTorrent { torrent } => {
-            match add_torrent(torrent.clone()).await {
-                Ok(_) => tracing::info!("Torrent added: {torrent}"),
-                Err(e) => {
-                    tracing::error!("Cannot add torrent: {torrent}, {e}");
-                    return Err(e);
-                }
+        Commands::AddTorrent {
+            torrents,
+            dir,
+            category,
+            paused,
+            priority,
+        } => {
+            let profile = find_profile(profile.as_deref())?;
+            let args = AddTorrentArgs {
+                dir,
+                category,
+                paused,
+                priority,
+            };
+            add_torrents(&profile, torrents, args).await?
+        }
+        Commands::Create(args) => create(profile.as_deref(), args).await?,
+        Commands::FetchRss { url, filter } => {
+            let profile = find_profile(profile.as_deref())?;
+            fetch_rss(&profile, &url, filter.as_deref()).await?
+        }
+        Commands::Rss { command } => {
+            let profile = find_profile(profile.as_deref())?;
+            handle_rss_command(&profile, command).await?
+        }
+        Commands::Watch {
+            dirs,
+            category,
+            dir,
+        } => {
+            let profile = find_profile(profile.as_deref())?;
+            watch(&profile, dirs, category, dir).await?
+        }
+        Commands::List {
+            format,
+            fields,
+            filter,
+            status,
+            category,
+        } => {
+            let profile = find_profile(profile.as_deref())?;
+            let args = ListArgs {
+                format,
+                fields,
+                filter,
+                status,
+                category,
+            };
+            list(&profile, args).await?
+        }
+        Commands::Start(selector) => {
+            let profile = find_profile(profile.as_deref())?;
+            torrent_action(&profile, selector, "start", TorrentAction::Start).await?
+        }
+        Commands::Stop(selector) => {
+            let profile = find_profile(profile.as_deref())?;
+            torrent_action(&profile, selector, "stop", TorrentAction::Stop).await?
+        }
+        Commands::Remove {
+            with_files,
+            selector,
+        } => {
+            let profile = find_profile(profile.as_deref())?;
+            if with_files {
+                torrent_action(
+                    &profile,
+                    selector,
+                    "remove with files",
+                    TorrentAction::DelWithFiles,
+                )
+                .await?
+            } else {
+                torrent_action(&profile, selector, "remove", TorrentAction::DelWithoutFiles).await?
             }
-        },
-        Commands::FetchRss { url, filter } => fetch_rss(&url, filter.as_deref()).await?,
+        }
+        Commands::Move { to, selector } => {
+            let profile = find_profile(profile.as_deref())?;
+            let verb = format!("move to {to}");
+            torrent_action(&profile, selector, &verb, |ids| {
+                TorrentAction::Move(ids, to)
+            })
+            .await?
+        }
+        Commands::SetCategory { to, selector } => {
+            let profile = find_profile(profile.as_deref())?;
+            let verb = format!("set category to \"{to}\"");
+            torrent_action(&profile, selector, &verb, |ids| {
+                TorrentAction::ChangeCategory(ids, to)
+            })
+            .await?
+        }
+        Commands::Verify(selector) => {
+            let profile = find_profile(profile.as_deref())?;
+            torrent_action(&profile, selector, "verify", TorrentAction::Verify).await?
+        }
+        Commands::Reannounce(selector) => {
+            let profile = find_profile(profile.as_deref())?;
+            torrent_action(&profile, selector, "reannounce", TorrentAction::Reannounce).await?
+        }
+        Commands::Turtle { mode } => {
+            let profile = find_profile(profile.as_deref())?;
+            turtle(&profile, mode).await?
+        }
         Commands::PrintDefaultConfig {} => {
             tracing::info!("Printing config");
             println!("{}", rm_config::main_config::MainConfig::default_config())
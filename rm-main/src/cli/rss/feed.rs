@@ -0,0 +1,282 @@
+use color_eyre::{eyre::eyre, Result};
+use regex::Regex;
+use rm_config::{rss::Feed, CONFIG};
+use tokio::sync::Mutex;
+use transmission_rpc::{types::TorrentAddArgs, TransClient};
+
+use crate::transmission::utils::checked;
+
+use super::seen::SeenStore;
+
+/// A feed from `rss.toml` with its patterns already compiled.
+pub struct FeedRule {
+    pub url: String,
+    include: Option<Regex>,
+    exclude: Option<Regex>,
+    category: Option<String>,
+    download_dir: Option<String>,
+}
+
+impl FeedRule {
+    pub fn new(feed: &Feed) -> Result<Self> {
+        let compile = |pattern: &Option<String>| {
+            pattern
+                .as_deref()
+                .map(Regex::new)
+                .transpose()
+                .map_err(|e| eyre!("invalid pattern for feed {}: {e}", feed.url))
+        };
+
+        Ok(Self {
+            url: feed.url.to_string(),
+            include: compile(&feed.include)?,
+            exclude: compile(&feed.exclude)?,
+            category: feed.category.clone(),
+            download_dir: feed.download_dir.clone(),
+        })
+    }
+
+    pub fn with_filter(url: &str, include: Option<&str>) -> Result<Self> {
+        Ok(Self {
+            url: url.to_string(),
+            include: include.map(Regex::new).transpose()?,
+            exclude: None,
+            category: None,
+            download_dir: None,
+        })
+    }
+
+    fn matches(&self, title: &str) -> bool {
+        self.include.as_ref().is_none_or(|re| re.is_match(title))
+            && !self.exclude.as_ref().is_some_and(|re| re.is_match(title))
+    }
+
+    fn download_dir(&self) -> Option<String> {
+        self.download_dir.clone().or_else(|| {
+            self.category
+                .as_ref()
+                .and_then(|category| CONFIG.categories.map.get(category))
+                .and_then(|category| category.default_dir.clone())
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct PollSummary {
+    pub added: usize,
+    pub failed: usize,
+}
+
+/// An item of a feed that points to a torrent.
+#[derive(Debug, PartialEq)]
+pub struct FeedItem {
+    pub title: String,
+    pub torrent_url: String,
+    /// Falls back to the torrent URL for feeds without GUIDs.
+    pub guid: String,
+}
+
+/// Items of an RSS document. Items without a title or a torrent URL are skipped.
+pub fn parse_items(content: &[u8]) -> Result<Vec<FeedItem>> {
+    let channel = rss::Channel::read_from(content)?;
+
+    let items = channel
+        .items()
+        .iter()
+        .filter_map(|item| {
+            let (title, torrent_url) = (item.title()?, torrent_url(item)?);
+            let guid = item.guid().map(|guid| guid.value()).unwrap_or(torrent_url);
+            Some(FeedItem {
+                title: title.to_string(),
+                torrent_url: torrent_url.to_string(),
+                guid: guid.to_string(),
+            })
+        })
+        .collect();
+
+    Ok(items)
+}
+
+/// Items matching the rule that aren't in `seen` yet.
+pub fn new_items(rule: &FeedRule, items: Vec<FeedItem>, seen: Option<&SeenStore>) -> Vec<FeedItem> {
+    items
+        .into_iter()
+        .filter(|item| rule.matches(&item.title))
+        .filter(|item| !seen.is_some_and(|seen| seen.is_seen(&rule.url, &item.guid)))
+        .collect()
+}
+
+/// Adds every matching item of the feed that's not in `seen` yet.
+/// Failing to add an item doesn't stop the others from being added.
+///
+/// `seen` is only locked to check and mark items, not while talking to the
+/// network, so that other feeds polled at the same time don't wait on this one.
+pub async fn poll_feed(
+    transclient: &mut TransClient,
+    rule: &FeedRule,
+    seen: Option<&Mutex<SeenStore>>,
+) -> Result<PollSummary> {
+    let content = reqwest::get(&rule.url).await?.bytes().await?;
+    let items = parse_items(&content)?;
+
+    let items = match seen {
+        Some(seen) => new_items(rule, items, Some(&*seen.lock().await)),
+        None => new_items(rule, items, None),
+    };
+
+    let mut summary = PollSummary::default();
+
+    for item in items {
+        let FeedItem {
+            title,
+            torrent_url,
+            guid,
+        } = item;
+
+        tracing::info!("RSS: downloading {title} from {torrent_url}");
+        let args = TorrentAddArgs {
+            filename: Some(torrent_url.clone()),
+            download_dir: rule.download_dir(),
+            labels: rule.category.clone().map(|category| vec![category]),
+            ..Default::default()
+        };
+
+        match checked(transclient.torrent_add(args).await) {
+            Ok(_) => {
+                println!("Added {title}");
+                summary.added += 1;
+                if let Some(seen) = seen {
+                    seen.lock().await.mark_seen(&rule.url, guid);
+                }
+            }
+            Err(e) => {
+                tracing::error!("RSS: cannot download {title} from {torrent_url} {e}");
+                eprintln!("Failed to add {title}: {e}");
+                summary.failed += 1;
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+fn torrent_url(item: &rss::Item) -> Option<&str> {
+    let enclosure = item.enclosure();
+    match enclosure {
+        Some(enclosure) if enclosure.mime_type() == "application/x-bittorrent" => {
+            Some(enclosure.url())
+        }
+        _ => item.link().or(enclosure.map(|enclosure| enclosure.url())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+  <channel>
+    <title>Releases</title>
+    <link>https://example.com</link>
+    <description>Releases</description>
+    <item>
+      <title>Show - 01 [1080p]</title>
+      <link>https://example.com/view/1</link>
+      <guid>release-1</guid>
+      <enclosure url="https://example.com/1.torrent" type="application/x-bittorrent" length="1"/>
+    </item>
+    <item>
+      <title>Show - 01 [720p]</title>
+      <link>magnet:?xt=urn:btih:2</link>
+    </item>
+    <item>
+      <title>Show - 01-12 [1080p] (Batch)</title>
+      <link>https://example.com/3.torrent</link>
+      <guid>release-3</guid>
+    </item>
+    <item>
+      <link>https://example.com/4.torrent</link>
+    </item>
+  </channel>
+</rss>"#;
+
+    fn rule(include: Option<&str>, exclude: Option<&str>) -> FeedRule {
+        FeedRule {
+            url: "https://example.com/rss".to_string(),
+            include: include.map(|re| Regex::new(re).unwrap()),
+            exclude: exclude.map(|re| Regex::new(re).unwrap()),
+            category: None,
+            download_dir: None,
+        }
+    }
+
+    fn titles(items: &[FeedItem]) -> Vec<&str> {
+        items.iter().map(|item| item.title.as_str()).collect()
+    }
+
+    #[test]
+    fn parses_items() {
+        let items = parse_items(FEED.as_bytes()).unwrap();
+
+        assert_eq!(
+            items,
+            vec![
+                FeedItem {
+                    title: "Show - 01 [1080p]".to_string(),
+                    torrent_url: "https://example.com/1.torrent".to_string(),
+                    guid: "release-1".to_string(),
+                },
+                FeedItem {
+                    title: "Show - 01 [720p]".to_string(),
+                    torrent_url: "magnet:?xt=urn:btih:2".to_string(),
+                    guid: "magnet:?xt=urn:btih:2".to_string(),
+                },
+                FeedItem {
+                    title: "Show - 01-12 [1080p] (Batch)".to_string(),
+                    torrent_url: "https://example.com/3.torrent".to_string(),
+                    guid: "release-3".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_feed() {
+        assert!(parse_items(b"<html>not a feed</html>").is_err());
+    }
+
+    #[test]
+    fn filters_by_include_and_exclude() {
+        let items = || parse_items(FEED.as_bytes()).unwrap();
+
+        assert_eq!(new_items(&rule(None, None), items(), None).len(), 3);
+        assert_eq!(
+            titles(&new_items(&rule(Some("1080p"), None), items(), None)),
+            ["Show - 01 [1080p]", "Show - 01-12 [1080p] (Batch)"]
+        );
+        assert_eq!(
+            titles(&new_items(
+                &rule(Some("1080p"), Some("(?i)batch")),
+                items(),
+                None
+            )),
+            ["Show - 01 [1080p]"]
+        );
+    }
+
+    #[test]
+    fn skips_seen_items() {
+        let rule = rule(None, None);
+        let mut seen = SeenStore::for_tests();
+        seen.mark_seen(&rule.url, "release-1".to_string());
+        seen.mark_seen("https://example.com/other", "release-3".to_string());
+
+        let items = new_items(&rule, parse_items(FEED.as_bytes()).unwrap(), Some(&seen));
+
+        assert_eq!(
+            titles(&items),
+            ["Show - 01 [720p]", "Show - 01-12 [1080p] (Batch)"]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+mod feed;
+mod seen;
+
+use std::{sync::Arc, time::Duration};
+
+use clap::Subcommand;
+use color_eyre::{eyre::bail, Result};
+use intuitils::config::IntuiConfig;
+use rm_config::{main_config::Profile, rss::RssConfig};
+use tokio::sync::Mutex;
+
+use crate::transmission;
+
+pub use feed::{poll_feed, FeedRule};
+use seen::SeenStore;
+
+/// Shortest interval the daemon checks a feed on, so that a misconfigured one
+/// doesn't hammer the feed.
+const MIN_INTERVAL: u64 = 60;
+
+#[derive(Subcommand)]
+pub enum RssCommand {
+    /// Check all feeds from rss.toml once
+    Run,
+    /// Keep checking feeds from rss.toml on their intervals
+    Daemon,
+}
+
+pub(super) async fn handle_rss_command(profile: &Profile, command: RssCommand) -> Result<()> {
+    let config = RssConfig::init()?;
+    if config.feeds.is_empty() {
+        bail!("no feeds defined in {:?}", RssConfig::path());
+    }
+
+    let rules = config
+        .feeds
+        .iter()
+        .map(FeedRule::new)
+        .collect::<Result<Vec<_>>>()?;
+
+    let seen = SeenStore::load()?;
+
+    match command {
+        RssCommand::Run => run_once(profile, rules, seen).await,
+        RssCommand::Daemon => {
+            let intervals = config
+                .feeds
+                .iter()
+                .map(|feed| feed.interval.unwrap_or(config.interval).max(MIN_INTERVAL))
+                .collect();
+            run_daemon(profile, rules, intervals, seen).await
+        }
+    }
+}
+
+async fn run_once(profile: &Profile, rules: Vec<FeedRule>, seen: SeenStore) -> Result<()> {
+    let mut transclient = transmission::utils::new_client(profile);
+    let seen = Mutex::new(seen);
+    let mut failed = 0;
+
+    for rule in &rules {
+        match poll_feed(&mut transclient, rule, Some(&seen)).await {
+            Ok(summary) => failed += summary.failed,
+            Err(e) => {
+                tracing::error!("RSS: cannot fetch {}: {e}", rule.url);
+                eprintln!("Failed to fetch {}: {e}", rule.url);
+                failed += 1;
+            }
+        }
+        seen.lock().await.save()?;
+    }
+
+    if failed > 0 {
+        bail!("{failed} RSS items or feeds failed");
+    }
+
+    Ok(())
+}
+
+async fn run_daemon(
+    profile: &Profile,
+    rules: Vec<FeedRule>,
+    intervals: Vec<u64>,
+    seen: SeenStore,
+) -> Result<()> {
+    let seen = Arc::new(Mutex::new(seen));
+    let mut tasks = vec![];
+
+    for (rule, interval) in rules.into_iter().zip(intervals) {
+        let mut transclient = transmission::utils::new_client(profile);
+        let seen = Arc::clone(&seen);
+
+        tasks.push(tokio::spawn(async move {
+            loop {
+                match poll_feed(&mut transclient, &rule, Some(&seen)).await {
+                    Ok(summary) if summary.added > 0 || summary.failed > 0 => {
+                        tracing::info!(
+                            "RSS: {}: {} added, {} failed",
+                            rule.url,
+                            summary.added,
+                            summary.failed
+                        );
+                    }
+                    Ok(_) => (),
+                    Err(e) => {
+                        tracing::error!("RSS: cannot fetch {}: {e}", rule.url);
+                        eprintln!("Failed to fetch {}: {e}", rule.url);
+                    }
+                }
+                if let Err(e) = seen.lock().await.save() {
+                    tracing::error!("RSS: cannot save seen items: {e}");
+                    eprintln!("Failed to save seen items: {e}");
+                }
+
+                tokio::time::sleep(Duration::from_secs(interval)).await;
+            }
+        }));
+    }
+
+    println!("Watching {} feeds", tasks.len());
+    futures::future::join_all(tasks).await;
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    path::PathBuf,
+};
+
+use color_eyre::{eyre::eyre, Result};
+
+// Old entries are dropped so the store doesn't grow forever. Feeds don't keep
+// this many items around, so they won't be seen again anyway.
+const MAX_SEEN_PER_FEED: usize = 2000;
+
+/// GUIDs of RSS items that were already added, persisted in the XDG state dir.
+pub struct SeenStore {
+    path: PathBuf,
+    feeds: HashMap<String, VecDeque<String>>,
+}
+
+impl SeenStore {
+    pub fn load() -> Result<Self> {
+        let path = xdg::BaseDirectories::with_prefix("rustmission")?
+            .place_state_file("rss_seen.json")
+            .map_err(|e| eyre!("cannot create state directory: {e}"))?;
+
+        let feeds = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| eyre!("corrupted RSS state file {path:?}: {e}"))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self { path, feeds })
+    }
+
+    pub fn is_seen(&self, feed: &str, guid: &str) -> bool {
+        self.feeds
+            .get(feed)
+            .is_some_and(|seen| seen.iter().any(|seen_guid| seen_guid == guid))
+    }
+
+    pub fn mark_seen(&mut self, feed: &str, guid: String) {
+        let seen = self.feeds.entry(feed.to_string()).or_default();
+        seen.push_back(guid);
+        while seen.len() > MAX_SEEN_PER_FEED {
+            seen.pop_front();
+        }
+    }
+
+    #[cfg(test)]
+    pub fn for_tests() -> Self {
+        Self {
+            path: PathBuf::new(),
+            feeds: HashMap::new(),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, serde_json::to_string(&self.feeds)?)?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_items_per_feed() {
+        let mut seen = SeenStore::for_tests();
+        seen.mark_seen("a", "1".to_string());
+
+        assert!(seen.is_seen("a", "1"));
+        assert!(!seen.is_seen("a", "2"));
+        assert!(!seen.is_seen("b", "1"));
+    }
+
+    #[test]
+    fn forgets_oldest_items() {
+        let mut seen = SeenStore::for_tests();
+        for guid in 0..=MAX_SEEN_PER_FEED {
+            seen.mark_seen("a", guid.to_string());
+        }
+
+        assert!(!seen.is_seen("a", "0"));
+        assert!(seen.is_seen("a", "1"));
+        assert!(seen.is_seen("a", &MAX_SEEN_PER_FEED.to_string()));
+    }
+}
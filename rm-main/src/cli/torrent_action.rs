@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use clap::Args;
+use color_eyre::{
+    eyre::{bail, eyre},
+    Result,
+};
+use regex::Regex;
+use rm_config::main_config::Profile;
+use rm_shared::action::UpdateAction;
+use tokio::sync::{mpsc::unbounded_channel, Mutex};
+use transmission_rpc::types::{Id, Torrent, TorrentGetField};
+
+use crate::transmission::{self, session::SessionClient, utils::checked, TorrentAction};
+
+#[derive(Args)]
+pub struct TorrentSelector {
+    /// IDs or hashes of torrents
+    torrents: Vec<String>,
+    /// Regex matched against torrent names
+    #[arg(long)]
+    regex: Option<String>,
+    /// Category (label) of torrents
+    #[arg(long)]
+    category: Option<String>,
+    /// Only print torrents that would be affected
+    #[arg(long)]
+    dry_run: bool,
+}
+
+impl TorrentSelector {
+    fn is_empty(&self) -> bool {
+        self.torrents.is_empty() && self.regex.is_none() && self.category.is_none()
+    }
+
+    fn matches_id_or_hash(&self, torrent: &Torrent) -> bool {
+        if self.torrents.is_empty() {
+            return true;
+        }
+
+        self.torrents
+            .iter()
+            .any(|wanted| matches_id_or_hash(torrent, wanted))
+    }
+
+    fn matches_category(&self, torrent: &Torrent) -> bool {
+        let Some(category) = &self.category else {
+            return true;
+        };
+
+        torrent
+            .labels
+            .as_ref()
+            .and_then(|labels| labels.first())
+            .is_some_and(|label| label == category)
+    }
+}
+
+fn matches_id_or_hash(torrent: &Torrent, wanted: &str) -> bool {
+    match wanted.parse::<i64>() {
+        Ok(id) => torrent.id == Some(id),
+        Err(_) => torrent
+            .hash_string
+            .as_ref()
+            .is_some_and(|hash| hash.eq_ignore_ascii_case(wanted)),
+    }
+}
+
+/// Resolves the selector to torrents and sends `make_action` with their IDs.
+pub(super) async fn torrent_action(
+    profile: &Profile,
+    selector: TorrentSelector,
+    verb: &str,
+    make_action: impl FnOnce(Vec<Id>) -> TorrentAction,
+) -> Result<()> {
+    if selector.is_empty() {
+        bail!("no torrents selected: pass IDs, hashes, --regex or --category");
+    }
+
+    let regex = selector.regex.as_deref().map(Regex::new).transpose()?;
+
+    let mut client = transmission::utils::new_client(profile);
+    let fields = vec![
+        TorrentGetField::Id,
+        TorrentGetField::Name,
+        TorrentGetField::HashString,
+        TorrentGetField::Labels,
+    ];
+    let torrents = checked(client.torrent_get(Some(fields), None).await)
+        .map_err(|e| eyre!("failed to fetch torrents: {e}"))?
+        .arguments
+        .torrents;
+
+    // Nothing is done if some of the torrents aren't there, as a typo could
+    // otherwise go unnoticed among the ones that are.
+    let missing: Vec<&str> = selector
+        .torrents
+        .iter()
+        .filter(|wanted| !torrents.iter().any(|t| matches_id_or_hash(t, wanted)))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        bail!("no torrents with these IDs or hashes: {}", missing.join(", "));
+    }
+
+    let selected: Vec<&Torrent> = torrents
+        .iter()
+        .filter(|torrent| {
+            let name_matches = regex
+                .as_ref()
+                .is_none_or(|re| re.is_match(torrent.name.as_deref().unwrap_or_default()));
+            name_matches
+                && selector.matches_id_or_hash(torrent)
+                && selector.matches_category(torrent)
+        })
+        .collect();
+
+    if selected.is_empty() {
+        bail!("no torrents matched");
+    }
+
+    if selector.dry_run {
+        println!("Would {verb}:");
+        print_torrents(&selected);
+        return Ok(());
+    }
+
+    let ids = selected
+        .iter()
+        .map(|torrent| Id::Id(torrent.id.expect("field requested")))
+        .collect();
+
+    send_torrent_action(profile, make_action(ids)).await?;
+
+    println!("Requested to {verb}:");
+    print_torrents(&selected);
+    Ok(())
+}
+
+/// Runs a single action through the very same `action_handler` the TUI uses.
+pub(super) async fn send_torrent_action(profile: &Profile, action: TorrentAction) -> Result<()> {
+    let (trans_tx, trans_rx) = unbounded_channel();
+    let (update_tx, mut update_rx) = unbounded_channel();
+    let handler = tokio::spawn(transmission::action_handler(
+        transmission::utils::new_client(profile),
+        SessionClient::new(profile),
+        Arc::new(Mutex::new(trans_rx)),
+        update_tx,
+    ));
+
+    trans_tx.send(action)?;
+    drop(trans_tx);
+    handler.await?;
+
+    while let Ok(update) = update_rx.try_recv() {
+        if let UpdateAction::Error(err) = update {
+            bail!("{}: {}", err.description, err.source);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_torrents(torrents: &[&Torrent]) {
+    for torrent in torrents {
+        println!(
+            "{:>5}  {}",
+            torrent.id.unwrap_or_default(),
+            torrent.name.as_deref().unwrap_or_default()
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use clap::ValueEnum;
+use color_eyre::{eyre::eyre, Result};
+use rm_config::main_config::Profile;
+
+use crate::transmission::{
+    session::{alt_speed_enabled, alt_speed_limits, set_alt_speed, SessionClient},
+    utils::new_client,
+};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum TurtleMode {
+    On,
+    Off,
+    Toggle,
+}
+
+pub async fn turtle(profile: &Profile, mode: TurtleMode) -> Result<()> {
+    let enabled = match mode {
+        TurtleMode::On => Some(true),
+        TurtleMode::Off => Some(false),
+        TurtleMode::Toggle => None,
+    };
+
+    let settings = set_alt_speed(
+        &mut new_client(profile),
+        &mut SessionClient::new(profile),
+        enabled,
+    )
+    .await
+    .map_err(|e| eyre!("failed to set alternative speed limits: {e}"))?;
+
+    if alt_speed_enabled(&settings) {
+        let (down, up) = alt_speed_limits(&settings);
+        println!("Turtle mode on (down {down} KB/s, up {up} KB/s)");
+    } else {
+        println!("Turtle mode off");
+    }
+
+    Ok(())
+}
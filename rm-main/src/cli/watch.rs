@@ -0,0 +1,261 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use color_eyre::{
+    eyre::{bail, eyre},
+    Report, Result,
+};
+use rm_config::{
+    main_config::{Profile, WatchDir},
+    CONFIG,
+};
+use transmission_rpc::TransClient;
+
+use crate::transmission::{self, connection};
+
+use super::add_torrent::{add_torrent, AddTorrentArgs};
+
+const DONE_DIR: &str = "done";
+const FAILED_DIR: &str = "failed";
+
+// Files modified more recently than that may still be being written to.
+const SETTLE_TIME: Duration = Duration::from_secs(2);
+
+pub(super) async fn watch(
+    profile: &Profile,
+    dirs: Vec<PathBuf>,
+    category: Option<String>,
+    download_dir: Option<String>,
+) -> Result<()> {
+    let dirs = if dirs.is_empty() {
+        CONFIG.watch.dirs.clone()
+    } else {
+        dirs.into_iter()
+            .map(|path| WatchDir {
+                path,
+                category: category.clone(),
+                download_dir: download_dir.clone(),
+            })
+            .collect()
+    };
+
+    if dirs.is_empty() {
+        bail!("no directories to watch: pass them as arguments or define them under [watch] in config.toml");
+    }
+
+    for dir in &dirs {
+        if !dir.path.is_dir() {
+            bail!("{:?} is not a directory", dir.path);
+        }
+        fs::create_dir_all(dir.path.join(DONE_DIR))?;
+        fs::create_dir_all(dir.path.join(FAILED_DIR))?;
+        println!("Watching {:?}", dir.path);
+    }
+
+    let mut transclient = transmission::utils::new_client(profile);
+
+    loop {
+        for dir in &dirs {
+            if let Err(e) = scan_dir(&mut transclient, dir).await {
+                tracing::error!("Watch: cannot scan {:?}: {e}", dir.path);
+                eprintln!("Failed to scan {:?}: {e}", dir.path);
+            }
+        }
+
+        tokio::time::sleep(Duration::from_secs(CONFIG.watch.interval)).await;
+    }
+}
+
+async fn scan_dir(transclient: &mut TransClient, dir: &WatchDir) -> Result<()> {
+    let args = AddTorrentArgs {
+        dir: dir.download_dir.clone(),
+        category: dir.category.clone(),
+        paused: false,
+        priority: None,
+    };
+
+    for entry in fs::read_dir(&dir.path)? {
+        let path = entry?.path();
+        if !is_ready(&path) {
+            continue;
+        }
+
+        let added = match watched_kind(&path) {
+            Some(WatchedKind::Torrent) => {
+                add_torrent(transclient, &path.to_string_lossy(), &args).await
+            }
+            Some(WatchedKind::Magnet) => match fs::read_to_string(&path) {
+                // Anything else would be taken for a path to a local file.
+                Ok(magnet) if magnet.trim().starts_with("magnet:") => {
+                    add_torrent(transclient, magnet.trim(), &args).await
+                }
+                Ok(_) => Err(eyre!("not a magnet link")),
+                Err(e) => Err(e.into()),
+            },
+            None => continue,
+        };
+
+        let target_dir = match added {
+            Ok(message) => {
+                tracing::info!("Watch: {message}: {path:?}");
+                println!("{message}: {path:?}");
+                DONE_DIR
+            }
+            // The file is left in place to be retried on the next scan.
+            Err(e) if is_connection_error(&e) => {
+                tracing::warn!("Watch: cannot reach the daemon to add {path:?}: {e}");
+                eprintln!("Failed to reach the daemon to add {path:?}: {e}");
+                continue;
+            }
+            Err(e) => {
+                tracing::error!("Watch: cannot add {path:?}: {e}");
+                eprintln!("Failed to add {path:?}: {e}");
+                FAILED_DIR
+            }
+        };
+
+        move_into(&path, &dir.path.join(target_dir))?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+enum WatchedKind {
+    Torrent,
+    Magnet,
+}
+
+fn watched_kind(path: &Path) -> Option<WatchedKind> {
+    let extension = path.extension()?.to_str()?.to_lowercase();
+    match extension.as_str() {
+        "torrent" => Some(WatchedKind::Torrent),
+        "magnet" => Some(WatchedKind::Magnet),
+        _ => None,
+    }
+}
+
+fn is_connection_error(err: &Report) -> bool {
+    err.downcast_ref::<Box<dyn Error + Send + Sync>>()
+        .is_some_and(|err| connection::is_transient(err.as_ref()))
+}
+
+fn is_ready(path: &Path) -> bool {
+    let Ok(metadata) = fs::metadata(path) else {
+        return false;
+    };
+
+    metadata.is_file()
+        && metadata
+            .modified()
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age >= SETTLE_TIME)
+}
+
+fn move_into(path: &Path, dir: &Path) -> Result<()> {
+    let file_name = path.file_name().expect("it's a file");
+    let mut target = dir.join(file_name);
+
+    // Don't overwrite a file with the same name handled earlier.
+    let mut attempt = 1;
+    while target.exists() {
+        target = dir.join(format!("{attempt}-{}", file_name.to_string_lossy()));
+        attempt += 1;
+    }
+
+    fs::rename(path, target)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::*;
+    use crate::test_utils::TestDir;
+
+    const MAGNET: &str = "magnet:?xt=urn:btih:0123456789abcdef0123456789abcdef01234567";
+
+    fn settled(path: &Path) {
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() - SETTLE_TIME * 2)
+            .unwrap();
+    }
+
+    fn watch_dir(dir: &TestDir) -> WatchDir {
+        fs::create_dir_all(dir.path().join(DONE_DIR)).unwrap();
+        fs::create_dir_all(dir.path().join(FAILED_DIR)).unwrap();
+        WatchDir {
+            path: dir.path().to_path_buf(),
+            category: None,
+            download_dir: None,
+        }
+    }
+
+    #[test]
+    fn kinds() {
+        let kind = |path: &str| watched_kind(Path::new(path));
+        assert_eq!(kind("a.torrent"), Some(WatchedKind::Torrent));
+        assert_eq!(kind("A.TORRENT"), Some(WatchedKind::Torrent));
+        assert_eq!(kind("b.magnet"), Some(WatchedKind::Magnet));
+        assert_eq!(kind("c.txt"), None);
+        assert_eq!(kind("torrent"), None);
+    }
+
+    #[test]
+    fn settling() {
+        let dir = TestDir::new("watch-settling");
+        let path = dir.write("a.torrent", b"");
+        assert!(!is_ready(&path));
+        settled(&path);
+        assert!(is_ready(&path));
+        assert!(!is_ready(dir.path()));
+    }
+
+    #[tokio::test]
+    async fn magnet_without_link_fails() {
+        let dir = TestDir::new("watch-invalid-magnet");
+        let path = dir.write("a.magnet", b"https://example.org/a.torrent");
+        settled(&path);
+        // Never reached, the file is rejected before that.
+        let mut client = TransClient::new("http://127.0.0.1:1".parse().unwrap());
+
+        scan_dir(&mut client, &watch_dir(&dir)).await.unwrap();
+
+        assert!(!path.exists());
+        assert!(dir.path().join(FAILED_DIR).join("a.magnet").exists());
+    }
+
+    #[tokio::test]
+    async fn unreachable_daemon_is_retried() {
+        let dir = TestDir::new("watch-unreachable");
+        let magnet = dir.write("a.magnet", MAGNET.as_bytes());
+        let other = dir.write("notes.txt", b"");
+        settled(&magnet);
+        settled(&other);
+        let mut client = TransClient::new("http://127.0.0.1:1".parse().unwrap());
+
+        scan_dir(&mut client, &watch_dir(&dir)).await.unwrap();
+
+        // Left in place for the next scan.
+        assert!(magnet.exists());
+        assert!(other.exists());
+        assert_eq!(
+            fs::read_dir(dir.path().join(FAILED_DIR)).unwrap().count(),
+            0
+        );
+    }
+
+    #[test]
+    fn connection_errors() {
+        assert!(!is_connection_error(&eyre!("not a magnet link")));
+    }
+}
@@ -1,4 +1,7 @@
 mod cli;
+mod metainfo;
+#[cfg(test)]
+mod test_utils;
 pub mod transmission;
 mod tui;
 
@@ -25,7 +28,7 @@ async fn main() -> Result<()> {
             .with_ansi(false)
             .init();
     }
-  
+
     color_eyre::config::HookBuilder::default()
         .issue_url(concat!(env!("CARGO_PKG_REPOSITORY"), "/issues/new"))
         .add_issue_metadata("version", env!("CARGO_PKG_VERSION"))
@@ -38,17 +41,18 @@ async fn main() -> Result<()> {
     let args = cli::Args::parse();
 
     if let Some(command) = args.command {
-        cli::handle_command(command).await?;
+        cli::handle_command(command, args.profile).await?;
     } else {
         tracing::info!("Starting rustmission");
-        run_tui().await?;
+        run_tui(args.profile).await?;
     }
 
     Ok(())
 }
 
-async fn run_tui() -> Result<()> {
-    let app = App::new().await?;
+async fn run_tui(profile: Option<String>) -> Result<()> {
+    let profile = transmission::utils::find_profile(profile.as_deref())?;
+    let app = App::new(profile).await?;
     if let Err(e) = app.run().await {
         let _ = disable_raw_mode();
         let _ = execute!(stdout(), LeaveAlternateScreen, Show, DisableMouseCapture);
@@ -0,0 +1,347 @@
+use std::{
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::{
+    eyre::{bail, eyre},
+    Result,
+};
+use serde::Serialize;
+use serde_bytes::ByteBuf;
+use sha1::{Digest, Sha1};
+
+const MIN_PIECE_LENGTH: u64 = 16 * 1024;
+const MAX_PIECE_LENGTH: u64 = 16 * 1024 * 1024;
+// Automatic piece length aims for about that many pieces.
+const TARGET_PIECES: u64 = 1500;
+
+#[derive(Default)]
+pub struct CreateOptions {
+    pub trackers: Vec<String>,
+    pub comment: Option<String>,
+    pub private: bool,
+    /// Piece length in bytes, picked based on the total size if not given.
+    pub piece_length: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct RawTorrent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    announce: Option<String>,
+    #[serde(rename = "announce-list", skip_serializing_if = "Option::is_none")]
+    announce_list: Option<Vec<Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(rename = "created by")]
+    created_by: String,
+    #[serde(rename = "creation date")]
+    creation_date: i64,
+    info: RawInfo,
+}
+
+#[derive(Serialize)]
+struct RawInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<RawFile>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    length: Option<u64>,
+    name: String,
+    #[serde(rename = "piece length")]
+    piece_length: u64,
+    pieces: ByteBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<u8>,
+}
+
+#[derive(Serialize)]
+struct RawFile {
+    length: u64,
+    path: Vec<String>,
+}
+
+/// Hashes the file or directory at `path` and returns a bencoded v1 torrent.
+/// It reads every byte of the data, so it blocks for a while with big ones.
+pub fn create_torrent(path: &Path, options: &CreateOptions) -> Result<Vec<u8>> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| eyre!("{path:?} has no file name"))?
+        .to_string_lossy()
+        .into_owned();
+
+    let files = if path.is_dir() {
+        let mut files = vec![];
+        collect_files(path, &mut files)?;
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    if files.is_empty() {
+        bail!("{path:?} has no files in it");
+    }
+
+    let mut lengths = vec![];
+    for file in &files {
+        lengths.push(file.metadata()?.len());
+    }
+    let total_length: u64 = lengths.iter().sum();
+
+    let piece_length = match options.piece_length {
+        Some(piece_length) => {
+            if !piece_length.is_power_of_two()
+                || !(MIN_PIECE_LENGTH..=MAX_PIECE_LENGTH).contains(&piece_length)
+            {
+                bail!("piece size has to be a power of two between 16 KiB and 16 MiB");
+            }
+            piece_length
+        }
+        None => (total_length / TARGET_PIECES)
+            .next_power_of_two()
+            .clamp(MIN_PIECE_LENGTH, MAX_PIECE_LENGTH),
+    };
+
+    let pieces = hash_pieces(&files, piece_length)?;
+
+    let info = if path.is_dir() {
+        let raw_files = files
+            .iter()
+            .zip(lengths)
+            .map(|(file, length)| RawFile {
+                length,
+                path: file
+                    .strip_prefix(path)
+                    .expect("collected from path")
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                    .collect(),
+            })
+            .collect();
+
+        RawInfo {
+            files: Some(raw_files),
+            length: None,
+            name,
+            piece_length,
+            pieces: ByteBuf::from(pieces),
+            private: options.private.then_some(1),
+        }
+    } else {
+        RawInfo {
+            files: None,
+            length: Some(total_length),
+            name,
+            piece_length,
+            pieces: ByteBuf::from(pieces),
+            private: options.private.then_some(1),
+        }
+    };
+
+    let torrent = RawTorrent {
+        announce: options.trackers.first().cloned(),
+        announce_list: (options.trackers.len() > 1).then(|| {
+            options
+                .trackers
+                .iter()
+                .map(|tracker| vec![tracker.clone()])
+                .collect()
+        }),
+        comment: options.comment.clone(),
+        created_by: format!("rustmission {}", env!("CARGO_PKG_VERSION")),
+        creation_date: chrono::Utc::now().timestamp(),
+        info,
+    };
+
+    Ok(serde_bencode::to_bytes(&torrent)?)
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        // Symlinked directories could point back up the tree and never end.
+        if entry.file_type()?.is_symlink() && path.is_dir() {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Pieces span file boundaries, as if all the files were one long file.
+fn hash_pieces(files: &[PathBuf], piece_length: u64) -> Result<Vec<u8>> {
+    let mut pieces = vec![];
+    let mut piece = Vec::with_capacity(piece_length as usize);
+
+    for path in files {
+        let mut file = File::open(path)?;
+        loop {
+            let wanted = piece_length as usize - piece.len();
+            let read = (&mut file).take(wanted as u64).read_to_end(&mut piece)?;
+            if piece.len() == piece_length as usize {
+                pieces.extend_from_slice(&Sha1::digest(&piece));
+                piece.clear();
+            }
+            if read < wanted {
+                break;
+            }
+        }
+    }
+
+    if !piece.is_empty() {
+        pieces.extend_from_slice(&Sha1::digest(&piece));
+    }
+
+    Ok(pieces)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{metainfo::Metainfo, test_utils::TestDir};
+
+    #[derive(Deserialize)]
+    struct PieceInfo {
+        info: PieceInfoInner,
+    }
+
+    #[derive(Deserialize)]
+    struct PieceInfoInner {
+        #[serde(rename = "piece length")]
+        piece_length: u64,
+        pieces: ByteBuf,
+    }
+
+    fn data(len: usize, seed: u8) -> Vec<u8> {
+        (0..len)
+            .map(|i| (i as u8).wrapping_mul(31) ^ seed)
+            .collect()
+    }
+
+    fn expected_pieces(data: &[u8], piece_length: usize) -> Vec<u8> {
+        data.chunks(piece_length)
+            .flat_map(|chunk| Sha1::digest(chunk).to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn pieces_span_file_boundaries() {
+        let dir = TestDir::new("boundaries");
+        let first = data(20 * 1024, 1);
+        let second = data(30 * 1024, 2);
+        let files = vec![dir.write("a", &first), dir.write("b", &second)];
+
+        let pieces = hash_pieces(&files, MIN_PIECE_LENGTH).unwrap();
+
+        let all = [first, second].concat();
+        // 50 KiB in 16 KiB pieces, the last one being shorter.
+        assert_eq!(pieces.len(), 4 * 20);
+        assert_eq!(pieces, expected_pieces(&all, MIN_PIECE_LENGTH as usize));
+    }
+
+    #[test]
+    fn single_file() {
+        let dir = TestDir::new("single");
+        let contents = data(40 * 1024, 3);
+        let path = dir.write("movie.mkv", &contents);
+
+        let torrent = create_torrent(&path, &CreateOptions::default()).unwrap();
+
+        let metainfo = Metainfo::from_bytes(&torrent).unwrap();
+        assert_eq!(metainfo.name, "movie.mkv");
+        assert_eq!(metainfo.files.len(), 1);
+        assert_eq!(metainfo.files[0].path, vec!["movie.mkv"]);
+        assert_eq!(metainfo.files[0].length, 40 * 1024);
+
+        let info = serde_bencode::from_bytes::<PieceInfo>(&torrent)
+            .unwrap()
+            .info;
+        assert_eq!(info.piece_length, MIN_PIECE_LENGTH);
+        assert_eq!(
+            info.pieces.into_vec(),
+            expected_pieces(&contents, MIN_PIECE_LENGTH as usize)
+        );
+    }
+
+    #[test]
+    fn multiple_files() {
+        let dir = TestDir::new("multiple");
+        let first = data(10 * 1024, 4);
+        let second = data(10 * 1024, 5);
+        dir.write("album/b/track.flac", &second);
+        dir.write("album/a.flac", &first);
+
+        let options = CreateOptions {
+            piece_length: Some(32 * 1024),
+            ..Default::default()
+        };
+        let torrent = create_torrent(&dir.path().join("album"), &options).unwrap();
+
+        let metainfo = Metainfo::from_bytes(&torrent).unwrap();
+        assert_eq!(metainfo.name, "album");
+        let paths: Vec<_> = metainfo
+            .files
+            .iter()
+            .map(|file| file.path.clone())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![vec!["album", "a.flac"], vec!["album", "b", "track.flac"]]
+        );
+
+        let info = serde_bencode::from_bytes::<PieceInfo>(&torrent)
+            .unwrap()
+            .info;
+        assert_eq!(info.piece_length, 32 * 1024);
+        assert_eq!(
+            info.pieces.into_vec(),
+            expected_pieces(&[first, second].concat(), 32 * 1024)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_directories_are_skipped() {
+        let dir = TestDir::new("symlinks");
+        dir.write("album/a.flac", &data(1024, 6));
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("album/loop")).unwrap();
+
+        let torrent = create_torrent(&dir.path().join("album"), &CreateOptions::default()).unwrap();
+
+        let metainfo = Metainfo::from_bytes(&torrent).unwrap();
+        assert_eq!(metainfo.files.len(), 1);
+        assert_eq!(metainfo.files[0].path, vec!["album", "a.flac"]);
+    }
+
+    #[test]
+    fn invalid_piece_length() {
+        let dir = TestDir::new("piece-length");
+        let path = dir.write("file", &data(1024, 7));
+
+        for piece_length in [0, 8 * 1024, 24 * 1024, 32 * 1024 * 1024] {
+            let options = CreateOptions {
+                piece_length: Some(piece_length),
+                ..Default::default()
+            };
+            let err = create_torrent(&path, &options).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "piece size has to be a power of two between 16 KiB and 16 MiB"
+            );
+        }
+    }
+
+    #[test]
+    fn empty_directory() {
+        let dir = TestDir::new("empty");
+        assert!(create_torrent(dir.path(), &CreateOptions::default()).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+mod create;
+
+pub use create::{create_torrent, CreateOptions};
+
+use serde::Deserialize;
+
+/// Contents of a `.torrent` file, as much of it as is needed to show what's inside.
+pub struct Metainfo {
+    pub name: String,
+    /// Files in the same order Transmission indexes them in.
+    pub files: Vec<MetainfoFile>,
+}
+
+pub struct MetainfoFile {
+    /// Path of the file, starting with the torrent's name.
+    pub path: Vec<String>,
+    pub length: i64,
+}
+
+#[derive(Deserialize)]
+struct RawMetainfo {
+    info: RawInfo,
+}
+
+#[derive(Deserialize)]
+struct RawInfo {
+    name: String,
+    length: Option<i64>,
+    files: Option<Vec<RawFile>>,
+}
+
+#[derive(Deserialize)]
+struct RawFile {
+    length: i64,
+    path: Vec<String>,
+}
+
+impl Metainfo {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, serde_bencode::Error> {
+        let info = serde_bencode::from_bytes::<RawMetainfo>(bytes)?.info;
+
+        let files = match (info.files, info.length) {
+            (Some(files), _) => files
+                .into_iter()
+                .map(|file| MetainfoFile {
+                    path: std::iter::once(info.name.clone())
+                        .chain(file.path)
+                        .collect(),
+                    length: file.length,
+                })
+                .collect(),
+            (None, Some(length)) => vec![MetainfoFile {
+                path: vec![info.name.clone()],
+                length,
+            }],
+            // v2-only torrents describe their files in a "file tree" instead.
+            (None, None) => return Err(serde_bencode::Error::MissingField("files".into())),
+        };
+
+        Ok(Self {
+            name: info.name,
+            files,
+        })
+    }
+
+    pub fn total_length(&self) -> i64 {
+        self.files.iter().map(|file| file.length).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SINGLE_FILE: &[u8] = concat!(
+        "d8:announce14:http://tracker",
+        "4:infod6:lengthi1024e4:name8:file.iso12:piece lengthi16384e6:pieces0:e",
+        "e"
+    )
+    .as_bytes();
+
+    const MULTI_FILE: &[u8] = concat!(
+        "d4:infod",
+        "5:filesl",
+        "d6:lengthi10e4:pathl5:a.txtee",
+        "d6:lengthi20e4:pathl3:sub5:b.txtee",
+        "e",
+        "4:name3:dir12:piece lengthi16384e6:pieces0:e",
+        "e"
+    )
+    .as_bytes();
+
+    #[test]
+    fn single_file() {
+        let metainfo = Metainfo::from_bytes(SINGLE_FILE).unwrap();
+
+        assert_eq!(metainfo.name, "file.iso");
+        assert_eq!(metainfo.files.len(), 1);
+        assert_eq!(metainfo.files[0].path, vec!["file.iso"]);
+        assert_eq!(metainfo.files[0].length, 1024);
+        assert_eq!(metainfo.total_length(), 1024);
+    }
+
+    #[test]
+    fn multi_file() {
+        let metainfo = Metainfo::from_bytes(MULTI_FILE).unwrap();
+
+        assert_eq!(metainfo.name, "dir");
+        // The index of a file is the id Transmission knows it by.
+        let files: Vec<_> = metainfo
+            .files
+            .iter()
+            .map(|file| (file.path.clone(), file.length))
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                (vec!["dir".to_string(), "a.txt".to_string()], 10),
+                (
+                    vec!["dir".to_string(), "sub".to_string(), "b.txt".to_string()],
+                    20
+                ),
+            ]
+        );
+        assert_eq!(metainfo.total_length(), 30);
+    }
+
+    #[test]
+    fn invalid() {
+        // v2-only, with a "file tree" instead of "files" or "length".
+        let v2_only = b"d4:infod9:file treede4:name3:dir12:piece lengthi16384eee";
+        assert!(Metainfo::from_bytes(v2_only).is_err());
+        assert!(Metainfo::from_bytes(b"not bencode").is_err());
+    }
+}
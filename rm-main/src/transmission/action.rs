@@ -4,23 +4,39 @@ use intuitils::error_message::ErrorMessage;
 use tokio::sync::mpsc::UnboundedReceiver;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot::Sender;
+use tokio::sync::Mutex;
 use transmission_rpc::types::{
     FreeSpace, Id, SessionGet, SessionStats, Torrent, TorrentAction as RPCAction, TorrentAddArgs,
-    TorrentGetField, TorrentSetArgs,
+    TorrentGetField, TorrentSetArgs, TrackerList,
 };
 use transmission_rpc::TransClient;
 
 use rm_shared::action::UpdateAction;
 
+use super::{
+    session::{self, QueueMove, SessionClient, SessionSettings},
+    utils::checked,
+};
+
 const FAILED_TO_COMMUNICATE: &str = "Failed to communicate with Transmission";
 
 pub enum TorrentAction {
     // Add a torrent with this Magnet/URL, Directory, Label (Category)
     Add(String, Option<String>, Option<String>),
+    // Add a torrent from a local .torrent file with this Name, Arguments (with its metainfo)
+    AddMetainfo(String, Box<TorrentAddArgs>),
     // Stop Torrents with these given IDs
     Stop(Vec<Id>),
     // Start Torrents with these given IDs
     Start(Vec<Id>),
+    // Start Torrents with these given IDs right away, regardless of the queue
+    StartNow(Vec<Id>),
+    // Move Torrents with these given IDs in the queue
+    QueueMove(QueueMove, Vec<Id>),
+    // Verify local data of Torrents with these given IDs
+    Verify(Vec<Id>),
+    // Ask trackers for more peers for Torrents with these given IDs
+    Reannounce(Vec<Id>),
     // Torrent ID, Directory to move to
     Move(Vec<Id>, String),
     // Torrent ID, Current name, Name to change to
@@ -31,10 +47,22 @@ pub enum TorrentAction {
     DelWithoutFiles(Vec<Id>),
     // Delete Torrents with these given IDs (with files)
     DelWithFiles(Vec<Id>),
+    // Add a tracker with this announce URL (in a new tier) to Torrents with these given IDs
+    AddTracker(Vec<Id>, String),
+    // Remove a tracker with this announce URL from Torrents with these given IDs
+    RemoveTracker(Vec<Id>, String),
+    // Torrent IDs, Announce URL to replace, Announce URL to replace it with
+    ReplaceTracker(Vec<Id>, String, String),
     // Set various properties to Torrents with these given IDs
     SetArgs(Box<TorrentSetArgs>, Option<Vec<Id>>),
     // Get info about current Transmission session
     GetSessionGet(Sender<Result<SessionGet, Box<ErrorMessage>>>),
+    // Get all the settings of current Transmission session
+    GetSessionSettings(Sender<Result<SessionSettings, Box<ErrorMessage>>>),
+    // Change these settings of current Transmission session
+    SetSessionSettings(SessionSettings, Sender<Result<(), Box<ErrorMessage>>>),
+    // Turn alternative speed limits (turtle mode) on or off, toggle them if not given
+    SetAltSpeed(Option<bool>),
     // Get info about current Transmission session statistics
     GetSessionStats(Sender<Result<Arc<SessionStats>, Box<ErrorMessage>>>),
     // Get info about available space on the disk
@@ -46,13 +74,23 @@ pub enum TorrentAction {
     ),
     // Get info about specific torrents with these given IDs
     GetTorrentsById(Vec<Id>, Sender<Result<Vec<Torrent>, Box<ErrorMessage>>>),
+    // Get info about specific torrents, only with these given Fields
+    GetTorrentFieldsById(
+        Vec<Id>,
+        Vec<TorrentGetField>,
+        Sender<Result<Vec<Torrent>, Box<ErrorMessage>>>,
+    ),
 }
 
+// The receiver stays locked for as long as the handler is alive, so that after
+// switching to another profile the new handler can pick it up.
 pub async fn action_handler(
     mut client: TransClient,
-    mut trans_rx: UnboundedReceiver<TorrentAction>,
+    mut session_client: SessionClient,
+    trans_rx: Arc<Mutex<UnboundedReceiver<TorrentAction>>>,
     update_tx: UnboundedSender<UpdateAction>,
 ) {
+    let mut trans_rx = trans_rx.lock().await;
     while let Some(action) = trans_rx.recv().await {
         match action {
             TorrentAction::Add(ref url, directory, label) => {
@@ -72,7 +110,7 @@ pub async fn action_handler(
                     labels: label,
                     ..Default::default()
                 };
-                match client.torrent_add(args).await {
+                match checked(client.torrent_add(args).await) {
                     Ok(_) => {
                         update_tx.send(UpdateAction::StatusTaskSuccess).unwrap();
                     }
@@ -87,8 +125,24 @@ pub async fn action_handler(
                     }
                 }
             }
+            TorrentAction::AddMetainfo(name, args) => {
+                match checked(client.torrent_add(*args).await) {
+                    Ok(_) => {
+                        update_tx.send(UpdateAction::StatusTaskSuccess).unwrap();
+                    }
+                    Err(err) => {
+                        let msg = format!("Failed to add torrent \"{name}\"");
+                        tracing::error!("{msg}");
+                        let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                        update_tx
+                            .send(UpdateAction::Error(Box::new(err_message)))
+                            .unwrap();
+                        update_tx.send(UpdateAction::StatusTaskFailure).unwrap();
+                    }
+                }
+            }
             TorrentAction::Stop(ids) => {
-                match client.torrent_action(RPCAction::Stop, ids.clone()).await {
+                match checked(client.torrent_action(RPCAction::Stop, ids.clone()).await) {
                     Ok(_) => tracing::info!("Stopping torrent, IDs={:?}", ids),
                     Err(err) => {
                         let msg = format!("Failed to stop torrents with these IDs: {:?}", ids);
@@ -101,7 +155,7 @@ pub async fn action_handler(
                 }
             }
             TorrentAction::Start(ids) => {
-                match client.torrent_action(RPCAction::Start, ids.clone()).await {
+                match checked(client.torrent_action(RPCAction::Start, ids.clone()).await) {
                     Ok(_) => tracing::info!("Starting torrent, IDs={:?}", ids),
                     Err(err) => {
                         let msg = format!("Failed to start torrents with these IDs: {:?}", ids);
@@ -113,8 +167,82 @@ pub async fn action_handler(
                     }
                 }
             }
+            TorrentAction::StartNow(ids) => {
+                match checked(
+                    client
+                        .torrent_action(RPCAction::StartNow, ids.clone())
+                        .await,
+                ) {
+                    Ok(_) => {
+                        tracing::info!("Starting torrent now, IDs={:?}", ids);
+                        update_tx.send(UpdateAction::StatusTaskSuccess).unwrap();
+                    }
+                    Err(err) => {
+                        let msg = format!("Failed to start torrents with these IDs: {:?}", ids);
+                        tracing::error!("{msg}");
+                        let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                        update_tx
+                            .send(UpdateAction::Error(Box::new(err_message)))
+                            .unwrap();
+                        update_tx.send(UpdateAction::StatusTaskFailure).unwrap();
+                    }
+                }
+            }
+            TorrentAction::QueueMove(queue_move, ids) => {
+                match session_client.queue_move(queue_move, ids.clone()).await {
+                    Ok(()) => tracing::info!("Moving torrent in queue {queue_move:?}, IDs={ids:?}"),
+                    Err(err) => {
+                        let msg =
+                            format!("Failed to move torrents with these IDs in queue: {ids:?}");
+                        tracing::error!("{msg}");
+                        let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                        update_tx
+                            .send(UpdateAction::Error(Box::new(err_message)))
+                            .unwrap();
+                    }
+                }
+            }
+            TorrentAction::Verify(ids) => {
+                match checked(client.torrent_action(RPCAction::Verify, ids.clone()).await) {
+                    Ok(_) => {
+                        tracing::info!("Verifying torrent, IDs={:?}", ids);
+                        update_tx.send(UpdateAction::StatusTaskSuccess).unwrap();
+                    }
+                    Err(err) => {
+                        let msg = format!("Failed to verify torrents with these IDs: {:?}", ids);
+                        tracing::error!("{msg}");
+                        let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                        update_tx
+                            .send(UpdateAction::Error(Box::new(err_message)))
+                            .unwrap();
+                        update_tx.send(UpdateAction::StatusTaskFailure).unwrap();
+                    }
+                }
+            }
+            TorrentAction::Reannounce(ids) => {
+                match checked(
+                    client
+                        .torrent_action(RPCAction::Reannounce, ids.clone())
+                        .await,
+                ) {
+                    Ok(_) => {
+                        tracing::info!("Reannouncing torrent, IDs={:?}", ids);
+                        update_tx.send(UpdateAction::StatusTaskSuccess).unwrap();
+                    }
+                    Err(err) => {
+                        let msg =
+                            format!("Failed to reannounce torrents with these IDs: {:?}", ids);
+                        tracing::error!("{msg}");
+                        let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                        update_tx
+                            .send(UpdateAction::Error(Box::new(err_message)))
+                            .unwrap();
+                        update_tx.send(UpdateAction::StatusTaskFailure).unwrap();
+                    }
+                }
+            }
             TorrentAction::DelWithFiles(ids) => {
-                match client.torrent_remove(ids.clone(), true).await {
+                match checked(client.torrent_remove(ids.clone(), true).await) {
                     Ok(_) => update_tx.send(UpdateAction::StatusTaskSuccess).unwrap(),
                     Err(err) => {
                         let msg = format!("Failed to remove torrents with these IDs: {:?}", ids);
@@ -128,7 +256,7 @@ pub async fn action_handler(
                 }
             }
             TorrentAction::DelWithoutFiles(ids) => {
-                match client.torrent_remove(ids.clone(), false).await {
+                match checked(client.torrent_remove(ids.clone(), false).await) {
                     Ok(_) => update_tx.send(UpdateAction::StatusTaskSuccess).unwrap(),
                     Err(err) => {
                         let msg = format!("Failed to remove torrents with these IDs: {:?}", ids);
@@ -142,7 +270,7 @@ pub async fn action_handler(
                 }
             }
             TorrentAction::SetArgs(args, ids) => {
-                match client.torrent_set(*args, ids.clone()).await {
+                match checked(client.torrent_set(*args, ids.clone()).await) {
                     Ok(_) => (),
                     Err(err) => {
                         let msg = format!(
@@ -157,7 +285,68 @@ pub async fn action_handler(
                     }
                 }
             }
-            TorrentAction::GetSessionGet(sender) => match client.session_get().await {
+            TorrentAction::AddTracker(ids, url) => {
+                let result = edit_trackers(&mut client, ids, |tiers| {
+                    if tiers.iter().flatten().any(|tracker| *tracker == url) {
+                        return false;
+                    }
+                    tiers.push(vec![url.clone()]);
+                    true
+                })
+                .await;
+
+                if let Err(err) = result {
+                    let msg = format!("Failed to add tracker {url}");
+                    tracing::error!("{msg}");
+                    let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                    update_tx
+                        .send(UpdateAction::Error(Box::new(err_message)))
+                        .unwrap();
+                }
+            }
+            TorrentAction::RemoveTracker(ids, url) => {
+                let result = edit_trackers(&mut client, ids, |tiers| {
+                    let trackers_no = tiers.iter().flatten().count();
+                    for tier in tiers.iter_mut() {
+                        tier.retain(|tracker| *tracker != url);
+                    }
+                    tiers.retain(|tier| !tier.is_empty());
+                    trackers_no != tiers.iter().flatten().count()
+                })
+                .await;
+
+                if let Err(err) = result {
+                    let msg = format!("Failed to remove tracker {url}");
+                    tracing::error!("{msg}");
+                    let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                    update_tx
+                        .send(UpdateAction::Error(Box::new(err_message)))
+                        .unwrap();
+                }
+            }
+            TorrentAction::ReplaceTracker(ids, old_url, new_url) => {
+                let result = edit_trackers(&mut client, ids, |tiers| {
+                    let mut replaced = false;
+                    for tracker in tiers.iter_mut().flatten() {
+                        if *tracker == old_url {
+                            tracker.clone_from(&new_url);
+                            replaced = true;
+                        }
+                    }
+                    replaced
+                })
+                .await;
+
+                if let Err(err) = result {
+                    let msg = format!("Failed to replace tracker {old_url} with {new_url}");
+                    tracing::error!("{msg}");
+                    let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                    update_tx
+                        .send(UpdateAction::Error(Box::new(err_message)))
+                        .unwrap();
+                }
+            }
+            TorrentAction::GetSessionGet(sender) => match checked(client.session_get().await) {
                 Ok(session_get) => {
                     sender.send(Ok(session_get.arguments)).unwrap();
                 }
@@ -168,11 +357,55 @@ pub async fn action_handler(
                     sender.send(Err(Box::new(err_message))).unwrap();
                 }
             },
+            TorrentAction::GetSessionSettings(sender) => match session_client.get().await {
+                Ok(settings) => {
+                    let _ = sender.send(Ok(settings));
+                }
+                Err(err) => {
+                    let msg = "Failed to get session settings";
+                    tracing::error!("{msg}: {err}");
+                    let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                    let _ = sender.send(Err(Box::new(err_message)));
+                }
+            },
+            TorrentAction::SetSessionSettings(changes, sender) => {
+                let result = match session::session_set_args(&changes) {
+                    Ok(args) => checked(client.session_set(args).await).map(|_| ()),
+                    Err(err) => Err(err),
+                };
+                match result {
+                    Ok(()) => {
+                        let _ = sender.send(Ok(()));
+                    }
+                    Err(err) => {
+                        let msg = "Failed to change session settings";
+                        tracing::error!("{msg}: {err}");
+                        let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                        let _ = sender.send(Err(Box::new(err_message)));
+                    }
+                }
+            }
+            TorrentAction::SetAltSpeed(enabled) => {
+                match session::set_alt_speed(&mut client, &mut session_client, enabled).await {
+                    Ok(settings) => update_tx
+                        .send(UpdateAction::SessionSettings(Arc::new(settings)))
+                        .unwrap(),
+                    Err(err) => {
+                        let msg = "Failed to toggle alternative speed limits";
+                        tracing::error!("{msg}: {err}");
+                        let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                        update_tx
+                            .send(UpdateAction::Error(Box::new(err_message)))
+                            .unwrap();
+                    }
+                }
+            }
             TorrentAction::Move(ids, new_directory) => {
-                if let Err(err) = client
-                    .torrent_set_location(ids, new_directory.clone(), Some(true))
-                    .await
-                {
+                if let Err(err) = checked(
+                    client
+                        .torrent_set_location(ids, new_directory.clone(), Some(true))
+                        .await,
+                ) {
                     let msg = format!("Failed to move torrent to new directory:\n{new_directory}");
                     tracing::error!("{msg}");
                     let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
@@ -181,7 +414,7 @@ pub async fn action_handler(
                         .unwrap();
                 }
             }
-            TorrentAction::GetSessionStats(sender) => match client.session_stats().await {
+            TorrentAction::GetSessionStats(sender) => match checked(client.session_stats().await) {
                 Ok(stats) => sender.send(Ok(Arc::new(stats.arguments))).unwrap(),
                 Err(err) => {
                     let msg = "Failed to get session stats";
@@ -190,17 +423,19 @@ pub async fn action_handler(
                     sender.send(Err(Box::new(err_message))).unwrap();
                 }
             },
-            TorrentAction::GetFreeSpace(path, sender) => match client.free_space(path).await {
-                Ok(free_space) => sender.send(Ok(free_space.arguments)).unwrap(),
-                Err(err) => {
-                    let msg = "Failed to get free space info";
-                    tracing::error!("{msg}");
-                    let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
-                    sender.send(Err(Box::new(err_message))).unwrap();
+            TorrentAction::GetFreeSpace(path, sender) => {
+                match checked(client.free_space(path).await) {
+                    Ok(free_space) => sender.send(Ok(free_space.arguments)).unwrap(),
+                    Err(err) => {
+                        let msg = "Failed to get free space info";
+                        tracing::error!("{msg}");
+                        let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                        sender.send(Err(Box::new(err_message))).unwrap();
+                    }
                 }
-            },
+            }
             TorrentAction::GetTorrents(fields, sender) => {
-                match client.torrent_get(Some(fields), None).await {
+                match checked(client.torrent_get(Some(fields), None).await) {
                     Ok(torrents) => sender.send(Ok(torrents.arguments.torrents)).unwrap(),
                     Err(err) => {
                         let msg = "Failed to fetch torrent data";
@@ -211,7 +446,7 @@ pub async fn action_handler(
                 }
             }
             TorrentAction::GetTorrentsById(ids, sender) => {
-                match client.torrent_get(None, Some(ids.clone())).await {
+                match checked(client.torrent_get(None, Some(ids.clone())).await) {
                     Ok(torrents) => {
                         // TODO: log using tracing in case of an error.
                         let _ = sender.send(Ok(torrents.arguments.torrents));
@@ -224,6 +459,19 @@ pub async fn action_handler(
                     }
                 }
             }
+            TorrentAction::GetTorrentFieldsById(ids, fields, sender) => {
+                match checked(client.torrent_get(Some(fields), Some(ids.clone())).await) {
+                    Ok(torrents) => {
+                        let _ = sender.send(Ok(torrents.arguments.torrents));
+                    }
+                    Err(err) => {
+                        let msg = format!("Failed to fetch torrents with these IDs: {:?}", ids);
+                        tracing::error!("{msg}");
+                        let err_message = ErrorMessage::new(FAILED_TO_COMMUNICATE, msg, err);
+                        let _ = sender.send(Err(Box::new(err_message)));
+                    }
+                }
+            }
             TorrentAction::ChangeCategory(ids, category) => {
                 let labels = if category.is_empty() {
                     vec![]
@@ -231,7 +479,7 @@ pub async fn action_handler(
                     vec![category]
                 };
                 let args = TorrentSetArgs::default().labels(labels);
-                match client.torrent_set(args, Some(ids)).await {
+                match checked(client.torrent_set(args, Some(ids)).await) {
                     Ok(_) => update_tx.send(UpdateAction::StatusTaskSuccess).unwrap(),
                     Err(err) => {
                         let msg = "Failed to set category";
@@ -245,10 +493,11 @@ pub async fn action_handler(
                 }
             }
             TorrentAction::Rename(id, current_name, new_name) => {
-                match client
-                    .torrent_rename_path(vec![id], current_name, new_name)
-                    .await
-                {
+                match checked(
+                    client
+                        .torrent_rename_path(vec![id], current_name, new_name)
+                        .await,
+                ) {
                     Ok(_) => update_tx.send(UpdateAction::StatusTaskSuccess).unwrap(),
                     Err(err) => {
                         let msg = "Failed to rename a torrent";
@@ -264,3 +513,43 @@ pub async fn action_handler(
         }
     }
 }
+
+// `trackerList` is the only way to edit trackers that doesn't need their IDs,
+// so every torrent gets its own list rewritten. `edit` returns whether it changed anything.
+async fn edit_trackers(
+    client: &mut TransClient,
+    ids: Vec<Id>,
+    edit: impl Fn(&mut Vec<Vec<String>>) -> bool,
+) -> transmission_rpc::types::Result<()> {
+    let torrents = checked(
+        client
+            .torrent_get(
+                Some(vec![TorrentGetField::Id, TorrentGetField::TrackerList]),
+                Some(ids),
+            )
+            .await,
+    )?
+    .arguments
+    .torrents;
+
+    for torrent in torrents {
+        let mut tiers: Vec<Vec<String>> = torrent
+            .tracker_list
+            .unwrap_or_default()
+            .split("\n\n")
+            .map(|tier| tier.lines().map(str::to_string).collect::<Vec<_>>())
+            .filter(|tier| !tier.is_empty())
+            .collect();
+
+        if !edit(&mut tiers) {
+            continue;
+        }
+
+        let tracker_list = TrackerList(tiers.join(&String::new()));
+        let args = TorrentSetArgs::new().tracker_list(tracker_list);
+        let id = Id::Id(torrent.id.expect("field requested"));
+        checked(client.torrent_set(args, Some(vec![id])).await)?;
+    }
+
+    Ok(())
+}
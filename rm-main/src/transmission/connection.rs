@@ -0,0 +1,103 @@
+use std::{error::Error, sync::LazyLock, time::Duration};
+
+use rm_shared::{action::UpdateAction, connection_status::ConnectionStatus};
+use tokio::sync::watch;
+
+use crate::tui::ctx::CTX;
+
+use super::fetchers;
+
+const MIN_BACKOFF: u64 = 1;
+const MAX_BACKOFF: u64 = 60;
+
+static STATUS: LazyLock<watch::Sender<ConnectionStatus>> =
+    LazyLock::new(|| watch::Sender::new(ConnectionStatus::Reconnecting));
+
+fn set_status(status: ConnectionStatus) {
+    if STATUS.send_if_modified(|current| std::mem::replace(current, status) != status) {
+        CTX.send_update_action(UpdateAction::ConnectionStatus(status));
+    }
+}
+
+pub fn is_connected() -> bool {
+    STATUS.borrow().is_connected()
+}
+
+/// Waits until the daemon is reachable. Fetchers call it before polling
+/// so that they don't hammer a daemon that's down.
+pub async fn wait_until_connected() {
+    let _ = STATUS
+        .subscribe()
+        .wait_for(ConnectionStatus::is_connected)
+        .await;
+}
+
+/// Forgets about the current connection, e.g. after switching to another daemon.
+pub fn reset() {
+    set_status(ConnectionStatus::Reconnecting);
+}
+
+/// Lets the supervisor know that a request failed. It's ignored unless we
+/// were connected up until now.
+pub fn report_failure() {
+    if is_connected() {
+        set_status(ConnectionStatus::Reconnecting);
+    }
+}
+
+/// Whether retrying could help, e.g. because the daemon is down. A wrong URL,
+/// bad credentials or something else than Transmission at the other end need
+/// the user to step in. Transmission answers a 401 with an HTML page, so that
+/// one shows up as a decoding error.
+pub fn is_transient(err: &(dyn Error + Send + Sync + 'static)) -> bool {
+    err.downcast_ref::<reqwest::Error>().is_some_and(|err| {
+        err.is_connect() || err.is_timeout() || err.is_request() || err.is_body()
+    })
+}
+
+/// Keeps track of the connection state. It fetches `SESSION_GET` every time
+/// the connection gets (re)established and backs off exponentially while the
+/// daemon is unreachable.
+pub async fn supervisor() {
+    let mut backoff = MIN_BACKOFF;
+    // Non-transient errors are shown once per outage, not on every retry.
+    let mut error_reported = false;
+
+    loop {
+        set_status(ConnectionStatus::Reconnecting);
+
+        match fetchers::session_get().await {
+            Ok(()) => {
+                backoff = MIN_BACKOFF;
+                error_reported = false;
+                set_status(ConnectionStatus::Connected);
+
+                let _ = STATUS
+                    .subscribe()
+                    .wait_for(|status| !status.is_connected())
+                    .await;
+            }
+            Err(mut err_message) => {
+                tracing::warn!(
+                    "Daemon unreachable, retrying in {backoff}s: {}",
+                    err_message.source
+                );
+
+                if !error_reported && !is_transient(&*err_message.source) {
+                    error_reported = true;
+                    err_message.description = String::from(
+                        "Failed to connect to the daemon. Check the URL and credentials of the profile.",
+                    );
+                    CTX.send_update_action(UpdateAction::ConnectionError(err_message));
+                }
+
+                for retry_in in (1..=backoff).rev() {
+                    set_status(ConnectionStatus::Offline { retry_in });
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+        }
+    }
+}
@@ -1,102 +1,184 @@
 use std::{sync::Arc, time::Duration};
 
+use intuitils::error_message::ErrorMessage;
 use rm_config::CONFIG;
 use tokio::sync::oneshot;
 use transmission_rpc::types::TorrentGetField;
 
-use rm_shared::action::UpdateAction;
+use rm_shared::{action::UpdateAction, header::Header};
 
-use crate::tui::ctx::CTX;
+use crate::tui::{
+    ctx::CTX,
+    tabs::torrents::{session_download_dir, SESSION_GET, TABLE_HEADERS},
+};
 
-use super::TorrentAction;
+use super::{connection, TorrentAction};
+
+pub async fn session_get() -> Result<(), Box<ErrorMessage>> {
+    let (sess_tx, sess_rx) = oneshot::channel();
+    CTX.send_torrent_action(TorrentAction::GetSessionGet(sess_tx));
+    let Ok(session_get) = sess_rx.await else {
+        // The profile got switched in the meantime.
+        return Ok(());
+    };
+    let session_get = session_get?;
+    *SESSION_GET.write().unwrap() = Some(session_get);
+    Ok(())
+}
 
 pub async fn stats() {
     loop {
+        connection::wait_until_connected().await;
+
         let (stats_tx, stats_rx) = oneshot::channel();
         CTX.send_torrent_action(TorrentAction::GetSessionStats(stats_tx));
 
-        match stats_rx.await.unwrap() {
-            Ok(stats) => {
+        match stats_rx.await {
+            Ok(Ok(stats)) => {
                 CTX.send_update_action(UpdateAction::SessionStats(stats));
             }
-            Err(err_message) => {
-                CTX.send_update_action(UpdateAction::Error(err_message));
-            }
+            Ok(Err(_)) => connection::report_failure(),
+            // The profile got switched in the meantime.
+            Err(_) => (),
         };
 
         tokio::time::sleep(Duration::from_secs(CONFIG.connection.stats_refresh)).await;
     }
 }
 
-pub async fn free_space() {
-    let download_dir = loop {
-        let (sess_tx, sess_rx) = oneshot::channel();
-        CTX.send_torrent_action(TorrentAction::GetSessionGet(sess_tx));
-        match sess_rx.await.unwrap() {
-            Ok(sess) => {
-                break sess.download_dir.leak();
-            }
-            Err(err_message) => {
-                CTX.send_update_action(UpdateAction::Error(err_message));
-                tokio::time::sleep(Duration::from_secs(10)).await;
+pub async fn session_settings() {
+    loop {
+        connection::wait_until_connected().await;
+
+        let (settings_tx, settings_rx) = oneshot::channel();
+        CTX.send_torrent_action(TorrentAction::GetSessionSettings(settings_tx));
+
+        match settings_rx.await {
+            Ok(Ok(settings)) => {
+                CTX.send_update_action(UpdateAction::SessionSettings(Arc::new(settings)));
             }
+            Ok(Err(_)) => connection::report_failure(),
+            Err(_) => (),
         };
-    };
 
+        tokio::time::sleep(Duration::from_secs(CONFIG.connection.stats_refresh)).await;
+    }
+}
+
+pub async fn free_space() {
     loop {
+        connection::wait_until_connected().await;
+
+        let Some(download_dir) = session_download_dir() else {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            continue;
+        };
+
         let (space_tx, space_rx) = oneshot::channel();
-        CTX.send_torrent_action(TorrentAction::GetFreeSpace(
-            download_dir.to_string(),
-            space_tx,
-        ));
+        CTX.send_torrent_action(TorrentAction::GetFreeSpace(download_dir, space_tx));
 
-        match space_rx.await.unwrap() {
-            Ok(free_space) => {
+        match space_rx.await {
+            Ok(Ok(free_space)) => {
                 CTX.send_update_action(UpdateAction::FreeSpace(Arc::new(free_space)));
             }
-            Err(err_message) => {
-                CTX.send_update_action(UpdateAction::Error(err_message));
-            }
+            Ok(Err(_)) => connection::report_failure(),
+            Err(_) => (),
         }
 
         tokio::time::sleep(Duration::from_secs(CONFIG.connection.free_space_refresh)).await;
     }
 }
 
+/// Fields needed to display torrents with `headers`, both in the TUI and in the CLI.
+pub fn torrent_fields(headers: &[Header]) -> Vec<TorrentGetField> {
+    // Statuses, filtering, facets and grouping rely on these regardless of headers.
+    let mut fields = vec![
+        TorrentGetField::Id,
+        TorrentGetField::Name,
+        TorrentGetField::IsStalled,
+        TorrentGetField::PercentDone,
+        TorrentGetField::UploadRatio,
+        TorrentGetField::SizeWhenDone,
+        TorrentGetField::RateUpload,
+        TorrentGetField::RateDownload,
+        TorrentGetField::Status,
+        TorrentGetField::DownloadDir,
+        TorrentGetField::UploadedEver,
+        TorrentGetField::DownloadedEver,
+        TorrentGetField::ActivityDate,
+        TorrentGetField::AddedDate,
+        TorrentGetField::Error,
+        TorrentGetField::ErrorString,
+        TorrentGetField::Labels,
+        TorrentGetField::RecheckProgress,
+        TorrentGetField::Trackers,
+    ];
+
+    for header in headers {
+        for field in header_fields(*header) {
+            if !fields.contains(field) {
+                fields.push(*field);
+            }
+        }
+    }
+
+    fields
+}
+
+/// Fields a header needs on top of the ones that are always requested.
+fn header_fields(header: Header) -> &'static [TorrentGetField] {
+    match header {
+        Header::Eta => &[TorrentGetField::Eta],
+        Header::PeersConnected => &[TorrentGetField::PeersConnected],
+        Header::QueuePosition => &[TorrentGetField::QueuePosition],
+        Header::Seeders | Header::Leechers => &[TorrentGetField::TrackerStats],
+        Header::HashString => &[TorrentGetField::HashString],
+        Header::DoneDate => &[TorrentGetField::DoneDate],
+        Header::SecondsSeeding => &[TorrentGetField::SecondsSeeding],
+        Header::SecondsDownloading => &[TorrentGetField::SecondsDownloading],
+        Header::TotalSize => &[TorrentGetField::TotalSize],
+        Header::LeftUntilDone => &[TorrentGetField::LeftUntilDone],
+        Header::Private => &[TorrentGetField::IsPrivate],
+        Header::RatioLimit => &[
+            TorrentGetField::SeedRatioMode,
+            TorrentGetField::SeedRatioLimit,
+        ],
+        Header::Id
+        | Header::Name
+        | Header::SizeWhenDone
+        | Header::Progress
+        | Header::DownloadRate
+        | Header::UploadRate
+        | Header::DownloadDir
+        | Header::Padding
+        | Header::UploadRatio
+        | Header::UploadedEver
+        | Header::ActivityDate
+        | Header::AddedDate
+        | Header::SmallStatus
+        | Header::Category
+        | Header::CategoryIcon
+        | Header::Status
+        | Header::DownloadedEver
+        | Header::Labels
+        | Header::TrackerHost => &[],
+    }
+}
+
 pub async fn torrents() {
     loop {
-        let fields = vec![
-            TorrentGetField::Id,
-            TorrentGetField::Name,
-            TorrentGetField::IsFinished,
-            TorrentGetField::IsStalled,
-            TorrentGetField::PercentDone,
-            TorrentGetField::UploadRatio,
-            TorrentGetField::SizeWhenDone,
-            TorrentGetField::Eta,
-            TorrentGetField::RateUpload,
-            TorrentGetField::RateDownload,
-            TorrentGetField::Status,
-            TorrentGetField::DownloadDir,
-            TorrentGetField::UploadedEver,
-            TorrentGetField::ActivityDate,
-            TorrentGetField::AddedDate,
-            TorrentGetField::PeersConnected,
-            TorrentGetField::Error,
-            TorrentGetField::ErrorString,
-            TorrentGetField::Labels,
-            TorrentGetField::FileStats,
-        ];
+        connection::wait_until_connected().await;
+
+        let fields = torrent_fields(&TABLE_HEADERS.read().unwrap());
         let (torrents_tx, torrents_rx) = oneshot::channel();
         CTX.send_torrent_action(TorrentAction::GetTorrents(fields, torrents_tx));
 
-        match torrents_rx.await.unwrap() {
-            Ok(torrents) => {
+        match torrents_rx.await {
+            Ok(Ok(torrents)) => {
                 CTX.send_update_action(UpdateAction::UpdateTorrents(torrents));
             }
-            Err(err_message) => {
-                CTX.send_update_action(UpdateAction::Error(err_message));
-            }
+            Ok(Err(_)) => connection::report_failure(),
+            Err(_) => (),
         };
 
         tokio::time::sleep(Duration::from_secs(CONFIG.connection.torrents_refresh)).await;
@@ -1,5 +1,7 @@
 mod action;
+pub mod connection;
 pub mod fetchers;
+pub mod session;
 pub mod utils;
 
 pub use action::{action_handler, TorrentAction};
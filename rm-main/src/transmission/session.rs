@@ -0,0 +1,202 @@
+use reqwest::{header::HeaderValue, StatusCode, Url};
+use rm_config::main_config::Profile;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use transmission_rpc::{
+    types::{Id, Result, SessionSetArgs},
+    TransClient,
+};
+
+use super::utils::checked;
+
+const SESSION_ID_HEADER: &str = "X-Transmission-Session-Id";
+const ALT_SPEED_ENABLED: &str = "alt-speed-enabled";
+
+/// Daemon settings as returned by `session-get`, keyed by their RPC names.
+pub type SessionSettings = Map<String, Value>;
+
+/// transmission-rpc's `SessionGet` carries only a handful of fields, so the
+/// settings are read with a plain `session-get` call. Same goes for the
+/// `queue-move-*` methods, which it doesn't have at all. Settings are written
+/// with `TransClient::session_set`, see [`session_set_args`].
+pub struct SessionClient {
+    http: reqwest::Client,
+    url: Url,
+    username: Option<String>,
+    password: Option<String>,
+    session_id: Option<HeaderValue>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum QueueMove {
+    Top,
+    Up,
+    Down,
+    Bottom,
+}
+
+impl QueueMove {
+    fn method(self) -> &'static str {
+        match self {
+            QueueMove::Top => "queue-move-top",
+            QueueMove::Up => "queue-move-up",
+            QueueMove::Down => "queue-move-down",
+            QueueMove::Bottom => "queue-move-bottom",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: String,
+    #[serde(default)]
+    arguments: Map<String, Value>,
+}
+
+impl SessionClient {
+    pub fn new(profile: &Profile) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url: profile.url.clone(),
+            username: profile.username.clone(),
+            password: profile.password.clone(),
+            session_id: None,
+        }
+    }
+
+    pub async fn get(&mut self) -> Result<SessionSettings> {
+        self.call(json!({ "method": "session-get" })).await
+    }
+
+    pub async fn queue_move(&mut self, queue_move: QueueMove, ids: Vec<Id>) -> Result<()> {
+        self.call(json!({ "method": queue_move.method(), "arguments": { "ids": ids } }))
+            .await
+            .map(|_| ())
+    }
+
+    async fn call(&mut self, request: Value) -> Result<Map<String, Value>> {
+        let body = serde_json::to_vec(&request)?;
+
+        // The first request is refused with 409, which hands out the session id
+        // that has to be sent along with every request after that.
+        for _ in 0..2 {
+            let mut http_request = self.http.post(self.url.clone()).body(body.clone());
+            if let Some(username) = &self.username {
+                http_request = http_request.basic_auth(username, self.password.as_ref());
+            }
+            if let Some(session_id) = &self.session_id {
+                http_request = http_request.header(SESSION_ID_HEADER, session_id);
+            }
+
+            let response = http_request.send().await?;
+            if response.status() == StatusCode::CONFLICT {
+                self.session_id = response.headers().get(SESSION_ID_HEADER).cloned();
+                continue;
+            }
+
+            let response: RpcResponse =
+                serde_json::from_slice(&response.error_for_status()?.bytes().await?)?;
+            if response.result != "success" {
+                return Err(response.result.into());
+            }
+            return Ok(response.arguments);
+        }
+
+        Err("the daemon keeps refusing the session id".into())
+    }
+}
+
+/// Turns the alternative speed limits on or off, or flips them if `enabled`
+/// isn't given. Returns the settings as they are afterwards.
+pub async fn set_alt_speed(
+    client: &mut TransClient,
+    session_client: &mut SessionClient,
+    enabled: Option<bool>,
+) -> Result<SessionSettings> {
+    let enabled = match enabled {
+        Some(enabled) => enabled,
+        None => !alt_speed_enabled(&session_client.get().await?),
+    };
+
+    let args = SessionSetArgs {
+        alt_speed_enabled: Some(enabled),
+        ..Default::default()
+    };
+    checked(client.session_set(args).await)?;
+    session_client.get().await
+}
+
+/// Turns settings keyed by their RPC names into `session-set` arguments.
+pub fn session_set_args(changes: &SessionSettings) -> Result<SessionSetArgs> {
+    let mut args = SessionSetArgs::default();
+
+    for (key, value) in changes {
+        let invalid = || format!("invalid value for {key}: {value}");
+        let bool = || value.as_bool().ok_or_else(invalid);
+        let int = || {
+            value
+                .as_i64()
+                .and_then(|value| i32::try_from(value).ok())
+                .ok_or_else(invalid)
+        };
+        let string = || value.as_str().map(str::to_string).ok_or_else(invalid);
+
+        match key.as_str() {
+            "alt-speed-down" => args.alt_speed_down = Some(int()?),
+            ALT_SPEED_ENABLED => args.alt_speed_enabled = Some(bool()?),
+            "alt-speed-time-begin" => args.alt_speed_time_begin = Some(int()?),
+            "alt-speed-time-day" => args.alt_speed_time_day = Some(int()?),
+            "alt-speed-time-enabled" => args.alt_speed_time_enabled = Some(bool()?),
+            "alt-speed-time-end" => args.alt_speed_time_end = Some(int()?),
+            "alt-speed-up" => args.alt_speed_up = Some(int()?),
+            "dht-enabled" => args.dht_enabled = Some(bool()?),
+            "download-queue-enabled" => args.download_queue_enabled = Some(bool()?),
+            "download-queue-size" => args.download_queue_size = Some(int()?),
+            "encryption" => args.encryption = Some(string()?),
+            "idle-seeding-limit" => args.idle_seeding_limit = Some(int()?),
+            "idle-seeding-limit-enabled" => args.idle_seeding_limit_enabled = Some(bool()?),
+            "incomplete-dir" => args.incomplete_dir = Some(string()?),
+            "incomplete-dir-enabled" => args.incomplete_dir_enabled = Some(bool()?),
+            "lpd-enabled" => args.lpd_enabled = Some(bool()?),
+            "peer-limit-global" => args.peer_limit_global = Some(int()?),
+            "peer-limit-per-torrent" => args.peer_limit_per_torrent = Some(int()?),
+            "peer-port" => args.peer_port = Some(int()?),
+            "peer-port-random-on-start" => args.peer_port_random_on_start = Some(bool()?),
+            "pex-enabled" => args.pex_enabled = Some(bool()?),
+            "port-forwarding-enabled" => args.port_forwarding_enabled = Some(bool()?),
+            "queue-stalled-enabled" => args.queue_stalled_enabled = Some(bool()?),
+            "queue-stalled-minutes" => args.queue_stalled_minutes = Some(int()?),
+            "seed-queue-enabled" => args.seed_queue_enabled = Some(bool()?),
+            "seed-queue-size" => args.seed_queue_size = Some(int()?),
+            "seedRatioLimit" => {
+                args.seed_ratio_limit = Some(value.as_f64().ok_or_else(invalid)? as f32)
+            }
+            "seedRatioLimited" => args.seed_ratio_limited = Some(bool()?),
+            "speed-limit-down" => args.speed_limit_down = Some(int()?),
+            "speed-limit-down-enabled" => args.speed_limit_down_enabled = Some(bool()?),
+            "speed-limit-up" => args.speed_limit_up = Some(int()?),
+            "speed-limit-up-enabled" => args.speed_limit_up_enabled = Some(bool()?),
+            _ => return Err(format!("unsupported setting: {key}").into()),
+        }
+    }
+
+    Ok(args)
+}
+
+pub fn alt_speed_enabled(settings: &SessionSettings) -> bool {
+    settings
+        .get(ALT_SPEED_ENABLED)
+        .and_then(Value::as_bool)
+        .unwrap_or_default()
+}
+
+/// Alternative download and upload limits in KB/s.
+pub fn alt_speed_limits(settings: &SessionSettings) -> (i64, i64) {
+    let limit = |key| {
+        settings
+            .get(key)
+            .and_then(Value::as_i64)
+            .unwrap_or_default()
+    };
+    (limit("alt-speed-down"), limit("alt-speed-up"))
+}
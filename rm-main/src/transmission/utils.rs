@@ -1,21 +1,51 @@
-use rm_config::CONFIG;
-use transmission_rpc::{types::BasicAuth, TransClient};
+use color_eyre::{eyre::eyre, Result};
+use reqwest::Url;
+use rm_config::{main_config::Profile, CONFIG};
+use transmission_rpc::{
+    types::{BasicAuth, RpcResponse, RpcResponseArgument},
+    TransClient,
+};
 
-pub fn new_client() -> TransClient {
-    let user = CONFIG
-        .connection
-        .username
-        .as_ref()
-        .unwrap_or(&"".to_string())
-        .clone();
-    let password = CONFIG
-        .connection
-        .password
-        .as_ref()
-        .unwrap_or(&"".to_string())
-        .clone();
+pub fn new_client(profile: &Profile) -> TransClient {
+    let user = profile.username.clone().unwrap_or_default();
+    let password = profile.password.clone().unwrap_or_default();
 
     let auth = BasicAuth { user, password };
 
-    TransClient::with_auth(CONFIG.connection.url.clone(), auth)
+    TransClient::with_auth(profile.url.clone(), auth)
+}
+
+/// Transmission answers requests it couldn't carry out with a `result` other
+/// than "success", which is turned into an error with that `result` as its message.
+pub fn checked<T: RpcResponseArgument>(
+    response: transmission_rpc::types::Result<RpcResponse<T>>,
+) -> transmission_rpc::types::Result<RpcResponse<T>> {
+    match response {
+        Ok(response) if !response.is_ok() => Err(response.result.into()),
+        response => response,
+    }
+}
+
+/// Finds a profile with the given name. If there's no name given, fallbacks to
+/// `default_profile` from the config or to the main profile.
+pub fn find_profile(name: Option<&str>) -> Result<Profile> {
+    let name = name
+        .or(CONFIG.connection.default_profile.as_deref())
+        .unwrap_or(rm_config::main_config::DEFAULT_PROFILE_NAME);
+
+    CONFIG.connection.profile(name).ok_or_else(|| {
+        let available = CONFIG
+            .connection
+            .profiles()
+            .into_iter()
+            .map(|profile| profile.name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        eyre!("No connection profile named \"{name}\". Available profiles: {available}")
+    })
+}
+
+/// Host part of a tracker's announce URL.
+pub fn tracker_host(announce: &str) -> Option<String> {
+    Url::parse(announce).ok()?.host_str().map(str::to_string)
 }
@@ -1,6 +1,8 @@
 use std::{
     io::stdout,
     panic::{set_hook, take_hook},
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use crate::{
@@ -9,19 +11,20 @@ use crate::{
 };
 
 use intuitils::Terminal;
+use rm_config::{main_config::Profile, CONFIG};
 use rm_shared::action::{Action, UpdateAction};
 
-use color_eyre::{
-    eyre::{self},
-    Result, Section,
-};
+use color_eyre::Result;
 use crossterm::{
     cursor::Show,
-    event::DisableMouseCapture,
+    event::{DisableMouseCapture, MouseButton, MouseEvent, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, LeaveAlternateScreen},
 };
-use tokio::sync::{mpsc::UnboundedReceiver, oneshot};
+use tokio::{
+    sync::{mpsc::UnboundedReceiver, Mutex},
+    task::JoinHandle,
+};
 
 use super::{
     ctx::{CTX, CTX_RAW},
@@ -33,14 +36,18 @@ pub struct App {
     should_quit: bool,
     action_rx: UnboundedReceiver<Action>,
     update_rx: UnboundedReceiver<UpdateAction>,
+    torrent_rx: Arc<Mutex<UnboundedReceiver<TorrentAction>>>,
+    transmission_tasks: Vec<JoinHandle<()>>,
     main_window: MainWindow,
     mode: Mode,
+    last_click: Option<(Instant, MouseEvent)>,
 }
 
-impl App {
-    pub async fn new() -> Result<Self> {
-        let client = transmission::utils::new_client();
+/// Two clicks in the same spot within this time make a double click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
 
+impl App {
+    pub async fn new(profile: Profile) -> Result<Self> {
         let (action_rx, update_rx, torrent_rx) = CTX_RAW
             .1
             .lock()
@@ -48,32 +55,58 @@ impl App {
             .take()
             .expect("it wasn't taken before");
 
-        tokio::spawn(transmission::action_handler(
-            client,
-            torrent_rx,
-            CTX.update_tx.clone(),
-        ));
-
-        tokio::spawn(async move {
-            let (sess_tx, sess_rx) = oneshot::channel();
-
-            CTX.send_torrent_action(TorrentAction::GetSessionGet(sess_tx));
-            match sess_rx.await.unwrap() {
-                Ok(sess_get) => SESSION_GET.set(sess_get).unwrap(),
-                Err(e) => CTX.send_update_action(UpdateAction::UnrecoverableError(Box::new(
-                    eyre::eyre!(e.source).wrap_err("error connecting to transmission daemon")
-                        .suggestion("Check if the transmission daemon IP address is correct and ensure you have an internet connection."),
-                ))),
-            }
-        });
-
-        Ok(Self {
+        let mut app = Self {
             should_quit: false,
-            main_window: MainWindow::new(),
+            main_window: MainWindow::new(profile.name.clone()),
             action_rx,
             update_rx,
+            torrent_rx: Arc::new(Mutex::new(torrent_rx)),
+            transmission_tasks: vec![],
             mode: Mode::Normal,
-        })
+            last_click: None,
+        };
+
+        app.spawn_transmission_tasks(&profile);
+
+        Ok(app)
+    }
+
+    fn spawn_transmission_tasks(&mut self, profile: &Profile) {
+        let client = transmission::utils::new_client(profile);
+        let session_client = transmission::session::SessionClient::new(profile);
+
+        self.transmission_tasks.extend([
+            tokio::spawn(transmission::action_handler(
+                client,
+                session_client,
+                Arc::clone(&self.torrent_rx),
+                CTX.update_tx.clone(),
+            )),
+            tokio::spawn(transmission::connection::supervisor()),
+            tokio::spawn(transmission::fetchers::stats()),
+            tokio::spawn(transmission::fetchers::session_settings()),
+            tokio::spawn(transmission::fetchers::torrents()),
+            tokio::spawn(transmission::fetchers::free_space()),
+        ]);
+    }
+
+    async fn switch_profile(&mut self, profile: &Profile) {
+        // action_handler is always the first one, so it's stopped only after
+        // everything that could still be waiting for its response.
+        for task in self.transmission_tasks.drain(..).rev() {
+            task.abort();
+            let _ = task.await;
+        }
+
+        // Whatever is still queued was meant for the previous daemon.
+        {
+            let mut torrent_rx = self.torrent_rx.lock().await;
+            while torrent_rx.try_recv().is_ok() {}
+        }
+        *SESSION_GET.write().unwrap() = None;
+        transmission::connection::reset();
+
+        self.spawn_transmission_tasks(profile);
     }
 
     pub async fn run(mut self) -> Result<()> {
@@ -113,7 +146,7 @@ impl App {
                 event = tui_event => {
                     let event = event.unwrap();
 
-                    use crossterm::event::{Event, MouseEventKind};
+                    use crossterm::event::Event;
                     match event {
                         Event::Key(key_event) => {
                             let app_key_event = AppKeyEvent::from(key_event);
@@ -128,6 +161,10 @@ impl App {
                         Event::Mouse(mouse_event) => match mouse_event.kind {
                             MouseEventKind::ScrollDown => self.handle_user_action(Action::ScrollDownBy(3)),
                             MouseEventKind::ScrollUp => self.handle_user_action(Action::ScrollUpBy(3)),
+                            MouseEventKind::Down(_) if self.mode == Mode::Normal => {
+                                let action = self.click_action(mouse_event);
+                                self.handle_user_action(action);
+                            }
                             _ => (),
                         },
                         Event::Resize(_, _) => self.render(terminal).unwrap(),
@@ -165,6 +202,23 @@ impl App {
         Ok(())
     }
 
+    fn click_action(&mut self, event: MouseEvent) -> Action {
+        let is_double_click = event.kind == MouseEventKind::Down(MouseButton::Left)
+            && self.last_click.is_some_and(|(time, last)| {
+                time.elapsed() < DOUBLE_CLICK_INTERVAL
+                    && last.kind == event.kind
+                    && (last.column, last.row) == (event.column, event.row)
+            });
+
+        if is_double_click {
+            self.last_click = None;
+            Action::DoubleClick(event)
+        } else {
+            self.last_click = Some((Instant::now(), event));
+            Action::Click(event)
+        }
+    }
+
     fn handle_user_action(&mut self, action: Action) {
         match &action {
             Action::HardQuit => {
@@ -186,6 +240,12 @@ impl App {
             UpdateAction::SwitchToNormalMode => {
                 self.mode = Mode::Normal;
             }
+            UpdateAction::SwitchProfile(ref name) => {
+                if let Some(profile) = CONFIG.connection.profile(name) {
+                    self.main_window.handle_update_action(action);
+                    self.switch_profile(&profile).await;
+                }
+            }
 
             _ => self.main_window.handle_update_action(action),
         };
@@ -28,8 +28,10 @@ impl AppKeyEvent {
             Window::Torrents(torrents_tab_current_window) => match torrents_tab_current_window {
                 TorrentWindow::General => &CONFIG.keybindings.torrents_tab.map,
                 TorrentWindow::FileViewer => &CONFIG.keybindings.torrents_tab_file_viewer.map,
+                TorrentWindow::Columns => &CONFIG.keybindings.torrents_tab_columns.map,
             },
             Window::Search(_) => &CONFIG.keybindings.search_tab.map,
+            Window::Session(_) => &CONFIG.keybindings.session_tab.map,
         };
 
         let keybinding = self.keybinding();
@@ -1,7 +1,12 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
+    layout::{Position, Rect},
     style::{Style, Styled, Stylize},
     text::Line,
-    widgets::{block::Title, Block, BorderType},
+    widgets::{
+        block::{self, Title},
+        Block, BorderType,
+    },
 };
 use rm_config::CONFIG;
 
@@ -13,14 +18,35 @@ pub fn popup_close_button() -> Line<'static> {
     Line::from(" [CLOSE] ".bold()).right_aligned()
 }
 
-pub fn popup_block(title: &str) -> Block {
+/// Whether `event` is a left click on a close button that's at the right of
+/// the top or the bottom border of `block_rect`.
+pub fn popup_close_button_clicked(
+    block_rect: Rect,
+    title_position: block::Position,
+    event: MouseEvent,
+) -> bool {
+    let width = popup_close_button_highlight().width() as u16;
+    let y = match title_position {
+        block::Position::Top => block_rect.top(),
+        block::Position::Bottom => block_rect.bottom().saturating_sub(1),
+    };
+    let button = Rect::new(block_rect.right().saturating_sub(width + 1), y, width, 1);
+
+    is_left_click(event) && button.contains(Position::new(event.column, event.row))
+}
+
+pub fn is_left_click(event: MouseEvent) -> bool {
+    event.kind == MouseEventKind::Down(MouseButton::Left)
+}
+
+pub fn popup_block(title: &str) -> Block<'_> {
     let title_style = Style::default().fg(CONFIG.general.accent_color);
     Block::bordered()
         .border_type(BorderType::Rounded)
         .title(Title::from(title.set_style(title_style)))
 }
 
-pub fn popup_block_with_close_highlight(title: &str) -> Block {
+pub fn popup_block_with_close_highlight(title: &str) -> Block<'_> {
     popup_block(title).title(popup_close_button_highlight())
 }
 
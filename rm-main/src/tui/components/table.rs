@@ -1,11 +1,17 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
-use ratatui::widgets::TableState;
+use ratatui::{
+    layout::{Constraint, Layout, Position, Rect},
+    widgets::TableState,
+};
+use rm_config::CONFIG;
 
 pub struct GenericTable<T: Clone> {
     pub state: RefCell<TableState>,
     pub items: Vec<T>,
     pub overwritten_len: RefCell<Option<usize>>,
+    /// Where the table was last rendered, so that clicks can be mapped to rows.
+    area: Cell<Rect>,
 }
 
 impl<T: Clone> GenericTable<T> {
@@ -14,9 +20,57 @@ impl<T: Clone> GenericTable<T> {
             state: RefCell::new(TableState::new().with_selected(Some(0))),
             items,
             overwritten_len: RefCell::new(None),
+            area: Cell::new(Rect::default()),
         }
     }
 
+    /// Has to be called with the area the table (header included) is rendered in.
+    pub fn set_area(&self, area: Rect) {
+        self.area.set(area);
+    }
+
+    fn header_area(&self) -> Option<Rect> {
+        let area = self.area.get();
+        (!CONFIG.general.headers_hide && area.height > 0).then_some(Rect { height: 1, ..area })
+    }
+
+    /// Index of the row displayed at `position`, given the height of each row.
+    pub fn row_at(&self, position: Position, row_height: impl Fn(usize) -> u16) -> Option<usize> {
+        let area = self.area.get();
+        if !area.contains(position) {
+            return None;
+        }
+
+        let mut y = area.y + u16::from(self.header_area().is_some());
+        if position.y < y {
+            return None;
+        }
+
+        let offset = self.state.borrow().offset();
+        for idx in offset..self.get_len() {
+            y = y.saturating_add(row_height(idx));
+            if position.y < y {
+                return Some(idx);
+            }
+        }
+        None
+    }
+
+    /// Index of the column whose header is displayed at `position`.
+    pub fn header_at(&self, widths: &[Constraint], position: Position) -> Option<usize> {
+        let header_area = self.header_area()?;
+        if !header_area.contains(position) {
+            return None;
+        }
+
+        // Same layout that ratatui's Table uses for its columns.
+        Layout::horizontal(widths)
+            .spacing(1)
+            .split(header_area)
+            .iter()
+            .position(|column| column.contains(position))
+    }
+
     pub fn get_len(&self) -> usize {
         self.overwritten_len
             .borrow()
@@ -105,11 +159,11 @@ impl<T: Clone> GenericTable<T> {
     }
 
     pub fn select_last(&mut self) {
-        if self.items.is_empty() {
+        if self.get_len() == 0 {
             return;
         }
 
         let mut state = self.state.borrow_mut();
-        state.select(Some(self.items.len() - 1));
+        state.select(Some(self.get_len() - 1));
     }
 }
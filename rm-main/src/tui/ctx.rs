@@ -7,16 +7,13 @@ use crate::transmission::TorrentAction;
 
 pub static CTX: LazyLock<Ctx> = LazyLock::new(|| CTX_RAW.0.clone());
 
-pub(super) static CTX_RAW: LazyLock<(
-    Ctx,
-    Mutex<
-        Option<(
-            UnboundedReceiver<Action>,
-            UnboundedReceiver<UpdateAction>,
-            UnboundedReceiver<TorrentAction>,
-        )>,
-    >,
-)> = LazyLock::new(|| {
+type CtxReceivers = (
+    UnboundedReceiver<Action>,
+    UnboundedReceiver<UpdateAction>,
+    UnboundedReceiver<TorrentAction>,
+);
+
+pub(super) static CTX_RAW: LazyLock<(Ctx, Mutex<Option<CtxReceivers>>)> = LazyLock::new(|| {
     let (ctx, act_rx, upd_rx, tor_rx) = Ctx::new();
     (ctx, Mutex::new(Some((act_rx, upd_rx, tor_rx))))
 });
@@ -4,14 +4,19 @@ use crossterm::event::KeyCode;
 use intuitils::popup::popup_rects;
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    widgets::{
+        block::Position, Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation,
+        ScrollbarState,
+    },
 };
 
 use rm_config::CONFIG;
 use rm_shared::action::Action;
 
 use crate::tui::{
-    components::{popup_block_with_close_highlight, Component, ComponentAction},
+    components::{
+        popup_block_with_close_highlight, popup_close_button_clicked, Component, ComponentAction,
+    },
     ctx::CTX,
 };
 
@@ -30,9 +35,12 @@ pub struct HelpPopup {
     global_keys: Vec<(String, &'static str)>,
     torrent_keys: Vec<(String, &'static str)>,
     torrent_file_viewer_keys: Vec<(String, &'static str)>,
+    torrent_columns_keys: Vec<(String, &'static str)>,
     search_keys: Vec<(String, &'static str)>,
+    session_keys: Vec<(String, &'static str)>,
     max_key_len: usize,
     max_line_len: usize,
+    block_rect: Rect,
 }
 
 struct Scroll {
@@ -77,10 +85,18 @@ impl HelpPopup {
             .keybindings
             .torrents_tab_file_viewer
             .get_help_repr_with_override(override_keycode);
+        let torrent_columns_keys = CONFIG
+            .keybindings
+            .torrents_tab_columns
+            .get_help_repr_with_override(override_keycode);
         let search_keys = CONFIG
             .keybindings
             .search_tab
             .get_help_repr_with_override(override_keycode);
+        let session_keys = CONFIG
+            .keybindings
+            .session_tab
+            .get_help_repr_with_override(override_keycode);
 
         let mut calc_max_lens = |keys: &[(String, &'static str)]| {
             for (keycode, desc) in keys {
@@ -99,7 +115,10 @@ impl HelpPopup {
 
         calc_max_lens(&global_keys);
         calc_max_lens(&torrent_keys);
+        calc_max_lens(&torrent_file_viewer_keys);
+        calc_max_lens(&torrent_columns_keys);
         calc_max_lens(&search_keys);
+        calc_max_lens(&session_keys);
 
         debug_assert!(max_key_len > 0);
         debug_assert!(max_line_len > 0);
@@ -108,9 +127,12 @@ impl HelpPopup {
             global_keys,
             torrent_keys,
             torrent_file_viewer_keys,
+            torrent_columns_keys,
             search_keys,
+            session_keys,
             max_key_len,
             max_line_len,
+            block_rect: Rect::default(),
         }
     }
 
@@ -160,6 +182,11 @@ impl Component for HelpPopup {
         match action {
             action if action.is_soft_quit() => ComponentAction::Quit,
             Action::Confirm | Action::ShowHelp => ComponentAction::Quit,
+            Action::Click(event)
+                if popup_close_button_clicked(self.block_rect, Position::Top, event) =>
+            {
+                ComponentAction::Quit
+            }
             Action::Up | Action::ScrollUpBy(_) => self.scroll_up(),
             Action::Down | Action::ScrollDownBy(_) => self.scroll_down(),
             Action::ScrollUpPage | Action::Home => self.scroll_to_home(),
@@ -170,6 +197,7 @@ impl Component for HelpPopup {
 
     fn render(&mut self, f: &mut Frame, rect: Rect) {
         let (popup_rect, block_rect, text_rect) = popup_rects(rect, 75, 75);
+        self.block_rect = block_rect;
 
         let block = popup_block_with_close_highlight(" Help ");
 
@@ -198,7 +226,9 @@ impl Component for HelpPopup {
         let global_keys = padded_keys(&mut self.global_keys);
         let torrent_keys = padded_keys(&mut self.torrent_keys);
         let torrent_file_viewer_keys = padded_keys(&mut self.torrent_file_viewer_keys);
+        let torrent_columns_keys = padded_keys(&mut self.torrent_columns_keys);
         let search_keys = padded_keys(&mut self.search_keys);
+        let session_keys = padded_keys(&mut self.session_keys);
 
         let mut lines = vec![];
 
@@ -240,6 +270,16 @@ impl Component for HelpPopup {
 
         insert_keys(&mut lines, torrent_file_viewer_keys);
 
+        lines.push(
+            Line::from(vec![Span::styled(
+                "Torrents Columns Editor",
+                Style::default().bold().underlined(),
+            )])
+            .centered(),
+        );
+
+        insert_keys(&mut lines, torrent_columns_keys);
+
         lines.push(
             Line::from(vec![Span::styled(
                 "Search Tab",
@@ -250,6 +290,16 @@ impl Component for HelpPopup {
 
         insert_keys(&mut lines, search_keys);
 
+        lines.push(
+            Line::from(vec![Span::styled(
+                "Session Tab",
+                Style::default().bold().underlined(),
+            )])
+            .centered(),
+        );
+
+        insert_keys(&mut lines, session_keys);
+
         let help_text = Text::from(lines);
 
         if text_rect.height <= u16::try_from(help_text.lines.len()).unwrap() {
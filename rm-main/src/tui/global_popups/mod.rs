@@ -1,10 +1,12 @@
 mod error;
 mod help;
+mod profiles;
 
 use ratatui::prelude::*;
 
 pub use error::ErrorPopup;
 pub use help::HelpPopup;
+pub use profiles::ProfilesPopup;
 
 use rm_shared::action::Action;
 
@@ -16,6 +18,7 @@ use super::{
 pub(super) struct GlobalPopupManager {
     pub error_popup: Option<ErrorPopup>,
     pub help_popup: Option<HelpPopup>,
+    pub profiles_popup: Option<ProfilesPopup>,
 }
 
 impl GlobalPopupManager {
@@ -23,11 +26,12 @@ impl GlobalPopupManager {
         Self {
             error_popup: None,
             help_popup: None,
+            profiles_popup: None,
         }
     }
 
     pub const fn needs_action(&self) -> bool {
-        self.error_popup.is_some() || self.help_popup.is_some()
+        self.error_popup.is_some() || self.help_popup.is_some() || self.profiles_popup.is_some()
     }
 
     pub fn toggle_help(&mut self) {
@@ -49,6 +53,11 @@ impl GlobalPopupManager {
                 self.help_popup = None;
                 CTX.send_action(Action::Render);
             }
+        } else if let Some(popup) = &mut self.profiles_popup {
+            if popup.handle_actions(action).is_quit() {
+                self.profiles_popup = None;
+                CTX.send_action(Action::Render);
+            }
         }
     }
 }
@@ -71,6 +80,8 @@ impl Component for GlobalPopupManager {
             popup.render(f, rect)
         } else if let Some(popup) = &mut self.help_popup {
             popup.render(f, rect);
+        } else if let Some(popup) = &mut self.profiles_popup {
+            popup.render(f, rect);
         }
     }
 }
@@ -0,0 +1,115 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Clear, List, ListState},
+};
+
+use rm_config::{main_config::Profile, CONFIG};
+use rm_shared::action::{Action, UpdateAction};
+
+use crate::tui::{
+    components::{popup_block, Component, ComponentAction},
+    ctx::CTX,
+};
+
+pub struct ProfilesPopup {
+    profiles: Vec<Profile>,
+    active_profile: String,
+    list_state: ListState,
+}
+
+impl ProfilesPopup {
+    pub fn new(active_profile: String) -> Self {
+        let profiles = CONFIG.connection.profiles();
+        let selected = profiles
+            .iter()
+            .position(|profile| profile.name == active_profile)
+            .unwrap_or(0);
+
+        Self {
+            profiles,
+            active_profile,
+            list_state: ListState::default().with_selected(Some(selected)),
+        }
+    }
+}
+
+impl Component for ProfilesPopup {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        if action.is_soft_quit() {
+            return ComponentAction::Quit;
+        }
+
+        match action {
+            Action::Up => {
+                self.list_state.select_previous();
+                CTX.send_action(Action::Render);
+                ComponentAction::Nothing
+            }
+            Action::Down => {
+                self.list_state.select_next();
+                CTX.send_action(Action::Render);
+                ComponentAction::Nothing
+            }
+            Action::Confirm => {
+                if let Some(profile) = self
+                    .list_state
+                    .selected()
+                    .and_then(|idx| self.profiles.get(idx))
+                {
+                    if profile.name != self.active_profile {
+                        CTX.send_update_action(UpdateAction::SwitchProfile(profile.name.clone()));
+                    }
+                }
+                ComponentAction::Quit
+            }
+            _ => ComponentAction::Nothing,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let longest_line = self
+            .profiles
+            .iter()
+            .map(|profile| profile.name.chars().count().max(profile.url.as_str().len()))
+            .max()
+            .unwrap_or(0);
+
+        let width = u16::try_from(longest_line + 6).unwrap_or(u16::MAX);
+        let height = u16::try_from(self.profiles.len() * 2 + 2).unwrap_or(u16::MAX);
+
+        let [block_rect] = Layout::horizontal([Constraint::Length(width)])
+            .flex(layout::Flex::Center)
+            .areas(rect);
+        let [block_rect] = Layout::vertical([Constraint::Length(height)])
+            .flex(layout::Flex::Center)
+            .areas(block_rect);
+
+        let block = popup_block(" Profiles ");
+
+        let list_rect = block_rect.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        });
+
+        let items = self.profiles.iter().map(|profile| {
+            let name = if profile.name == self.active_profile {
+                Line::from(format!(" {} ", profile.name)).underlined()
+            } else {
+                Line::from(format!(" {} ", profile.name))
+            };
+            let url = Line::from(format!(" {}", profile.url)).dark_gray();
+            Text::from(vec![name, url])
+        });
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .fg(CONFIG.general.accent_color)
+                .bg(Color::Black)
+                .bold(),
+        );
+
+        f.render_widget(Clear, block_rect);
+        f.render_widget(block, block_rect);
+        f.render_stateful_widget(list, list_rect, &mut self.list_state);
+    }
+}
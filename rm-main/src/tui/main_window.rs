@@ -1,7 +1,7 @@
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
 use intui_tabs::{Tabs, TabsState};
-use ratatui::prelude::*;
+use ratatui::{prelude::*, widgets::Paragraph};
 
 use rm_config::CONFIG;
 use rm_shared::{
@@ -9,31 +9,38 @@ use rm_shared::{
     current_window::Window,
 };
 
-use crate::tui::ctx::CTX;
+use crate::{
+    transmission::{self, TorrentAction},
+    tui::ctx::CTX,
+};
 
 use super::{
     components::{Component, ComponentAction},
-    global_popups::{ErrorPopup, GlobalPopupManager},
-    tabs::{search::SearchTab, torrents::TorrentsTab},
+    global_popups::{ErrorPopup, GlobalPopupManager, ProfilesPopup},
+    tabs::{
+        search::SearchTab,
+        session::SessionTab,
+        torrents::{TorrentsTab, ACTIVE_VIEW},
+    },
 };
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
 pub enum CurrentTab {
+    #[default]
     Torrents,
     Search,
-}
-
-impl Default for CurrentTab {
-    fn default() -> Self {
-        CurrentTab::Torrents
-    }
+    Session,
 }
 
 impl Display for CurrentTab {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CurrentTab::Torrents => write!(f, "Torrents"),
+            CurrentTab::Torrents => match ACTIVE_VIEW.read().unwrap().as_deref() {
+                Some(view) => write!(f, "Torrents ({view})"),
+                None => write!(f, "Torrents"),
+            },
             CurrentTab::Search => write!(f, "Search"),
+            CurrentTab::Session => write!(f, "Session"),
         }
     }
 }
@@ -42,24 +49,101 @@ pub struct MainWindow {
     pub tabs: intui_tabs::TabsState<CurrentTab>,
     pub torrents_tab: TorrentsTab,
     pub search_tab: SearchTab,
+    pub session_tab: SessionTab,
     global_popup_manager: GlobalPopupManager,
+    active_profile: String,
+    top_bar: Rect,
 }
 
 impl MainWindow {
-    pub fn new() -> Self {
+    pub fn new(active_profile: String) -> Self {
         Self {
-            tabs: TabsState::new(vec![CurrentTab::Torrents, CurrentTab::Search]),
+            tabs: TabsState::new(vec![
+                CurrentTab::Torrents,
+                CurrentTab::Search,
+                CurrentTab::Session,
+            ]),
             torrents_tab: TorrentsTab::new(),
             search_tab: SearchTab::new(),
+            session_tab: SessionTab::new(),
             global_popup_manager: GlobalPopupManager::new(),
+            active_profile,
+            top_bar: Rect::default(),
+        }
+    }
+
+    /// Tab whose title is displayed at `position` in the top bar.
+    fn tab_at(&self, position: Position) -> Option<CurrentTab> {
+        if !self.top_bar.contains(position) {
+            return None;
+        }
+
+        let tabs = [
+            CurrentTab::Torrents,
+            CurrentTab::Search,
+            CurrentTab::Session,
+        ];
+        let titles = tabs.map(|tab| {
+            if CONFIG.general.beginner_mode {
+                format!("{}. {tab}", tab as u8 + 1)
+            } else {
+                tab.to_string()
+            }
+        });
+
+        // intui_tabs centers the tabs in an area as wide as the titles' byte
+        // length, with each title padded with a space on both sides and
+        // separated by a one character divider. Within it, titles take up
+        // their display width.
+        let byte_len: usize = titles.iter().map(|title| title.len() + 3).sum();
+        let [tabs_area] = Layout::horizontal([Constraint::Length(byte_len as u16)])
+            .flex(layout::Flex::Center)
+            .areas(self.top_bar);
+
+        let mut x = tabs_area.x;
+        for (tab, title) in tabs.into_iter().zip(titles) {
+            let width = Line::raw(title).width() as u16 + 2;
+            if (x..x + width).contains(&position.x) {
+                return Some(tab);
+            }
+            x += width + 1;
         }
+        None
     }
 
     pub fn current_window(&self) -> Window {
         match self.tabs.current() {
             CurrentTab::Torrents => Window::Torrents(self.torrents_tab.current_window),
             CurrentTab::Search => Window::Search(self.search_tab.current_window),
+            CurrentTab::Session => Window::Session(self.session_tab.current_window),
+        }
+    }
+
+    fn current_tab_handle_actions(&mut self, action: Action) {
+        match self.tabs.current() {
+            CurrentTab::Torrents => self.torrents_tab.handle_actions(action),
+            CurrentTab::Search => self.search_tab.handle_actions(action),
+            CurrentTab::Session => self.session_tab.handle_actions(action),
+        };
+    }
+
+    fn change_tab(&mut self, tab: CurrentTab) {
+        if self.tabs.current() == tab {
+            return;
         }
+
+        let idx = match tab {
+            CurrentTab::Torrents => 1,
+            CurrentTab::Search => 2,
+            CurrentTab::Session => 3,
+        };
+        self.tabs.set(idx);
+
+        if tab == CurrentTab::Session {
+            self.session_tab.refresh();
+        }
+
+        CTX.send_action(Action::Render);
     }
 }
 
@@ -74,25 +158,28 @@ impl Component for MainWindow {
             _ if self.global_popup_manager.needs_action() => {
                 self.global_popup_manager.handle_actions(action);
             }
-            A::Left | A::ChangeTab(1) => {
-                if self.tabs.current() != CurrentTab::Torrents {
-                    self.tabs.set(1);
-                    CTX.send_action(Action::Render);
-                }
-            }
-            A::Right | A::ChangeTab(2) => {
-                if self.tabs.current() != CurrentTab::Search {
-                    self.tabs.set(2);
-                    CTX.send_action(Action::Render);
-                }
-            }
-            _ if self.tabs.current() == CurrentTab::Torrents => {
-                self.torrents_tab.handle_actions(action);
+            A::ShowProfiles => {
+                self.global_popup_manager.profiles_popup =
+                    Some(ProfilesPopup::new(self.active_profile.clone()));
+                CTX.send_action(Action::Render);
             }
-            _ if self.tabs.current() == CurrentTab::Search => {
-                self.search_tab.handle_actions(action);
-            }
-            _ => unreachable!(),
+            A::ToggleAltSpeed => CTX.send_torrent_action(TorrentAction::SetAltSpeed(None)),
+            A::Left => match self.tabs.current() {
+                CurrentTab::Torrents | CurrentTab::Search => self.change_tab(CurrentTab::Torrents),
+                CurrentTab::Session => self.change_tab(CurrentTab::Search),
+            },
+            A::Right => match self.tabs.current() {
+                CurrentTab::Torrents => self.change_tab(CurrentTab::Search),
+                CurrentTab::Search | CurrentTab::Session => self.change_tab(CurrentTab::Session),
+            },
+            A::Click(event) => match self.tab_at(Position::new(event.column, event.row)) {
+                Some(tab) => self.change_tab(tab),
+                None => self.current_tab_handle_actions(action),
+            },
+            A::ChangeTab(1) => self.change_tab(CurrentTab::Torrents),
+            A::ChangeTab(2) => self.change_tab(CurrentTab::Search),
+            A::ChangeTab(3) => self.change_tab(CurrentTab::Session),
+            _ => self.current_tab_handle_actions(action),
         };
 
         ComponentAction::Nothing
@@ -100,17 +187,48 @@ impl Component for MainWindow {
 
     fn handle_update_action(&mut self, action: UpdateAction) {
         match action {
+            // Requests failing to reach the daemon are expected while it's unreachable,
+            // it's signaled by BottomStats. Any other error is still shown.
+            UpdateAction::Error(err)
+                if !transmission::connection::is_connected()
+                    && transmission::connection::is_transient(err.source.as_ref()) => {}
             UpdateAction::Error(err) => {
                 let error_popup =
                     ErrorPopup::new(err.title, err.description, err.source.to_string());
                 self.global_popup_manager.error_popup = Some(error_popup);
             }
+            UpdateAction::SwitchProfile(ref name) => {
+                self.active_profile = name.clone();
+                self.session_tab
+                    .handle_update_action(UpdateAction::SwitchProfile(name.clone()));
+                self.torrents_tab.handle_update_action(action);
+            }
+            UpdateAction::ConnectionStatus(_) => self.torrents_tab.handle_update_action(action),
+            UpdateAction::ConnectionError(err) => {
+                let error_popup = ErrorPopup::new(
+                    err.title.clone(),
+                    err.description.clone(),
+                    err.source.to_string(),
+                );
+                self.global_popup_manager.error_popup = Some(error_popup);
+                self.torrents_tab
+                    .handle_update_action(UpdateAction::ConnectionError(err));
+            }
+            UpdateAction::SessionSettings(settings) => {
+                self.torrents_tab
+                    .handle_update_action(UpdateAction::SessionSettings(Arc::clone(&settings)));
+                self.session_tab
+                    .handle_update_action(UpdateAction::SessionSettings(settings));
+            }
             action if self.tabs.current() == CurrentTab::Torrents => {
                 self.torrents_tab.handle_update_action(action)
             }
             action if self.tabs.current() == CurrentTab::Search => {
                 self.search_tab.handle_update_action(action)
             }
+            action if self.tabs.current() == CurrentTab::Session => {
+                self.session_tab.handle_update_action(action)
+            }
             _ => unreachable!(),
         }
     }
@@ -118,20 +236,30 @@ impl Component for MainWindow {
     fn tick(&mut self) {
         self.search_tab.tick();
         self.torrents_tab.tick();
+        self.session_tab.tick();
     }
 
     fn render(&mut self, f: &mut Frame, rect: Rect) {
         let [top_bar, main_window] =
             Layout::vertical([Constraint::Length(1), Constraint::Percentage(100)]).areas(rect);
+        self.top_bar = top_bar;
 
         let tabs = Tabs::new()
             .beginner_mode(CONFIG.general.beginner_mode)
             .color(CONFIG.general.accent_color);
         f.render_stateful_widget(tabs, top_bar, &mut self.tabs);
 
+        if CONFIG.connection.has_many_profiles() {
+            let profile = Paragraph::new(format!("{} ", self.active_profile))
+                .fg(CONFIG.general.accent_color)
+                .right_aligned();
+            f.render_widget(profile, top_bar);
+        }
+
         match self.tabs.current() {
             CurrentTab::Torrents => self.torrents_tab.render(f, main_window),
             CurrentTab::Search => self.search_tab.render(f, main_window),
+            CurrentTab::Session => self.session_tab.render(f, main_window),
         }
 
         self.global_popup_manager.render(f, f.area());
@@ -1,2 +1,3 @@
 pub mod search;
+pub mod session;
 pub mod torrents;
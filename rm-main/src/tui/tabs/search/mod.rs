@@ -1,10 +1,10 @@
 mod bottom_bar;
 mod popups;
 
-use std::{borrow::Cow, sync::Arc, time::Duration};
+use std::{borrow::Cow, cmp::Reverse, sync::Arc, time::Duration};
 
 use bottom_bar::BottomBar;
-use crossterm::event::{Event, KeyCode, KeyEvent};
+use crossterm::event::{Event, KeyCode, KeyEvent, MouseEvent};
 use futures::{stream::FuturesUnordered, StreamExt};
 use magnetease::{Magnet, MagneteaseErrorKind, WhichProvider};
 use popups::{CurrentPopup, PopupManager};
@@ -121,7 +121,7 @@ impl SearchTab {
         }
     }
 
-    fn magnet_to_row(magnet: &Magnet) -> Row {
+    fn magnet_to_row(magnet: &Magnet) -> Row<'_> {
         let size = bytes_to_human_format(magnet.bytes as i64);
         Row::new([
             Cell::from(Cow::Owned(magnet.seeders.to_string())).light_green(),
@@ -218,6 +218,21 @@ impl SearchTab {
         CTX.send_action(Action::Render);
     }
 
+    fn handle_click(&mut self, event: MouseEvent, is_double_click: bool) {
+        let position = Position::new(event.column, event.row);
+        let Some(row) = self.table.row_at(position, |_| 1) else {
+            return;
+        };
+
+        self.table.state.borrow_mut().select(Some(row));
+        self.focus = SearchTabFocus::List;
+        CTX.send_action(Action::Render);
+
+        if is_double_click {
+            self.add_magnet();
+        }
+    }
+
     fn xdg_open(&mut self) {
         if let Some(magnet) = self.table.current_item() {
             let _ = open::that_detached(&magnet.url);
@@ -295,6 +310,8 @@ impl Component for SearchTab {
             A::Confirm => self.add_magnet(),
             A::XdgOpen => self.xdg_open(),
             A::ShowProvidersInfo => self.show_providers_info(),
+            A::Click(event) => self.handle_click(event, false),
+            A::DoubleClick(event) => self.handle_click(event, true),
 
             _ => (),
         };
@@ -328,7 +345,7 @@ impl Component for SearchTab {
                 self.update_providers_popup();
 
                 self.table.items.extend(response.magnets);
-                self.table.items.sort_by(|a, b| b.seeders.cmp(&a.seeders));
+                self.table.items.sort_by_key(|a| Reverse(a.seeders));
 
                 let mut state = self.table.state.borrow_mut();
                 if !self.table.items.is_empty() && state.selected().is_none() {
@@ -441,6 +458,7 @@ impl Component for SearchTab {
         };
 
         f.render_stateful_widget(table, rest, &mut self.table.state.borrow_mut());
+        self.table.set_area(rest);
 
         self.bottom_bar.render(f, bottom_line);
         self.popup_manager.render(f, f.area());
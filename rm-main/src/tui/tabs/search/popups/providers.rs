@@ -5,19 +5,25 @@ use ratatui::{
     prelude::Rect,
     style::{Style, Styled, Stylize},
     text::Line,
-    widgets::{block::Title, Block, BorderType, Clear, Row, Table},
+    widgets::{
+        block::{Position, Title},
+        Block, BorderType, Clear, Row, Table,
+    },
     Frame,
 };
 use rm_config::CONFIG;
 use rm_shared::action::Action;
 
 use crate::tui::{
-    components::{popup_close_button_highlight, Component, ComponentAction},
+    components::{
+        popup_close_button_clicked, popup_close_button_highlight, Component, ComponentAction,
+    },
     tabs::search::{ConfiguredProvider, ProviderState},
 };
 
 pub struct ProvidersPopup {
     providers: Vec<ConfiguredProvider>,
+    block_rect: Rect,
 }
 
 impl From<&ConfiguredProvider> for Row<'_> {
@@ -74,7 +80,10 @@ impl From<&ConfiguredProvider> for Row<'_> {
 
 impl ProvidersPopup {
     pub const fn new(providers: Vec<ConfiguredProvider>) -> Self {
-        Self { providers }
+        Self {
+            providers,
+            block_rect: Rect::ZERO,
+        }
     }
 
     pub fn update_providers(&mut self, providers: Vec<ConfiguredProvider>) {
@@ -87,12 +96,18 @@ impl Component for ProvidersPopup {
         match action {
             _ if action.is_soft_quit() => ComponentAction::Quit,
             Action::Confirm => ComponentAction::Quit,
+            Action::Click(event)
+                if popup_close_button_clicked(self.block_rect, Position::Bottom, event) =>
+            {
+                ComponentAction::Quit
+            }
             _ => ComponentAction::Nothing,
         }
     }
 
     fn render(&mut self, f: &mut Frame, rect: Rect) {
         let (popup_rect, block_rect, table_rect) = popup_rects(rect, 80, 50);
+        self.block_rect = block_rect;
 
         let title_style = Style::default().fg(CONFIG.general.accent_color);
         let block = Block::bordered()
@@ -0,0 +1,372 @@
+mod settings;
+
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use intuitils::popup::popup_rects;
+use ratatui::{
+    prelude::*,
+    widgets::{block::Position, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+use rm_config::{
+    keymap::{GeneralAction, SessionAction},
+    CONFIG,
+};
+use serde_json::Value;
+use tokio::sync::oneshot;
+
+use crate::{
+    transmission::{self, session::SessionSettings, TorrentAction},
+    tui::{
+        components::{
+            keybinding_style, popup_block, popup_close_button_clicked,
+            popup_close_button_highlight, Component, ComponentAction, InputManager,
+        },
+        ctx::CTX,
+    },
+};
+use rm_shared::{
+    action::{Action, UpdateAction},
+    current_window::SessionWindow,
+};
+use settings::{Setting, SETTINGS};
+
+pub struct SessionTab {
+    pub current_window: SessionWindow,
+    settings: Option<Arc<SessionSettings>>,
+    // Edited values that haven't been sent to the daemon yet.
+    changes: SessionSettings,
+    selected: usize,
+    table_state: TableState,
+    input_mgr: Option<InputManager>,
+    showing_changes: bool,
+    changes_block_rect: Rect,
+    needs_fetch: bool,
+}
+
+async fn fetch_settings() {
+    let (settings_tx, settings_rx) = oneshot::channel();
+    CTX.send_torrent_action(TorrentAction::GetSessionSettings(settings_tx));
+
+    // The sender is dropped if the profile got switched in the meantime.
+    match settings_rx.await {
+        Ok(Ok(settings)) => {
+            CTX.send_update_action(UpdateAction::SessionSettings(Arc::new(settings)))
+        }
+        Ok(Err(err_message)) => CTX.send_update_action(UpdateAction::Error(err_message)),
+        Err(_) => (),
+    }
+}
+
+async fn apply_changes(changes: SessionSettings) {
+    let (result_tx, result_rx) = oneshot::channel();
+    CTX.send_torrent_action(TorrentAction::SetSessionSettings(changes, result_tx));
+
+    match result_rx.await {
+        Ok(Ok(())) => fetch_settings().await,
+        Ok(Err(err_message)) => CTX.send_update_action(UpdateAction::Error(err_message)),
+        Err(_) => (),
+    }
+}
+
+impl SessionTab {
+    pub fn new() -> Self {
+        Self {
+            current_window: SessionWindow::General,
+            settings: None,
+            changes: SessionSettings::new(),
+            selected: 0,
+            table_state: TableState::default(),
+            input_mgr: None,
+            showing_changes: false,
+            changes_block_rect: Rect::default(),
+            needs_fetch: true,
+        }
+    }
+
+    /// Fetches the settings again as soon as the daemon is reachable.
+    pub fn refresh(&mut self) {
+        self.needs_fetch = true;
+    }
+
+    fn selected_setting(&self) -> &'static Setting {
+        &SETTINGS[self.selected]
+    }
+
+    /// What the setting is going to be, with the not yet applied change if there's one.
+    fn value(&self, setting: &Setting) -> Option<&Value> {
+        self.changes
+            .get(setting.key)
+            .or_else(|| self.settings.as_ref()?.get(setting.key))
+    }
+
+    fn change(&mut self, setting: &Setting, value: Value) {
+        let current = self
+            .settings
+            .as_ref()
+            .and_then(|settings| settings.get(setting.key));
+        if current == Some(&value) {
+            self.changes.remove(setting.key);
+        } else {
+            self.changes.insert(setting.key.to_string(), value);
+        }
+    }
+
+    fn edit_selected(&mut self) {
+        let setting = self.selected_setting();
+        let Some(value) = self.value(setting).cloned() else {
+            // Not supported by this version of the daemon.
+            return;
+        };
+
+        if setting.is_toggle() {
+            if let Some(value) = setting.toggled(&value) {
+                self.change(setting, value);
+            }
+        } else {
+            self.input_mgr = Some(InputManager::new_with_value(
+                format!("{}: ", setting.name),
+                setting.format(&value),
+            ));
+            CTX.send_update_action(UpdateAction::SwitchToInputMode);
+        }
+    }
+
+    fn handle_input(&mut self, input: KeyEvent) {
+        let setting = self.selected_setting();
+        let Some(input_mgr) = &mut self.input_mgr else {
+            return;
+        };
+
+        match input.code {
+            KeyCode::Enter => match setting.parse(&input_mgr.text()) {
+                Ok(value) => self.change(setting, value),
+                Err(expected) => {
+                    input_mgr.set_prompt(format!("{} (expected {expected}): ", setting.name));
+                    return;
+                }
+            },
+            KeyCode::Esc => (),
+            _ => {
+                input_mgr.handle_key(input);
+                return;
+            }
+        }
+
+        self.input_mgr = None;
+        CTX.send_update_action(UpdateAction::SwitchToNormalMode);
+    }
+
+    fn move_selection(&mut self, by: isize) {
+        let last = SETTINGS.len() as isize - 1;
+        self.selected = (self.selected as isize + by).clamp(0, last) as usize;
+    }
+
+    fn apply(&mut self) {
+        self.showing_changes = false;
+        let changes = self.changes.clone();
+        tokio::task::spawn(apply_changes(changes));
+    }
+
+    fn render_changes(&mut self, f: &mut Frame, rect: Rect) {
+        let (popup_rect, block_rect, text_rect) = popup_rects(rect, 60, 50);
+        self.changes_block_rect = block_rect;
+
+        let mut keys = vec![];
+        if let Some(key) = CONFIG
+            .keybindings
+            .general
+            .get_keys_for_action_joined(GeneralAction::Confirm)
+        {
+            keys.push(Span::styled(key, keybinding_style()));
+            keys.push(Span::raw(" - apply "));
+        }
+
+        let block = popup_block(" Apply changes ")
+            .title_bottom(popup_close_button_highlight())
+            .title_bottom(Line::from(keys).left_aligned());
+
+        let lines: Vec<_> = SETTINGS
+            .iter()
+            .filter_map(|setting| {
+                let new = self.changes.get(setting.key)?;
+                let old = self
+                    .settings
+                    .as_ref()
+                    .and_then(|settings| settings.get(setting.key))
+                    .map_or_else(|| "-".to_string(), |old| setting.format(old));
+                Some(Line::from(vec![
+                    Span::raw(format!("{} / {}: ", setting.section, setting.name)),
+                    Span::raw(old).red(),
+                    Span::raw(" → "),
+                    Span::raw(setting.format(new)).green(),
+                ]))
+            })
+            .collect();
+
+        f.render_widget(Clear, popup_rect);
+        f.render_widget(block, block_rect);
+        f.render_widget(Paragraph::new(lines), text_rect);
+    }
+
+    fn bottom_line(&self) -> Line<'_> {
+        if self.changes.is_empty() {
+            return Line::default();
+        }
+
+        let mut spans = vec![
+            Span::raw(format!(" {} unapplied changes ", self.changes.len()))
+                .fg(CONFIG.general.accent_color),
+        ];
+        for (action, desc) in [
+            (SessionAction::ApplyChanges, " - review and apply | "),
+            (SessionAction::DiscardChanges, " - discard "),
+        ] {
+            if let Some(key) = CONFIG
+                .keybindings
+                .session_tab
+                .get_keys_for_action_joined(action)
+            {
+                spans.push(Span::styled(key, keybinding_style()));
+                spans.push(Span::raw(desc));
+            }
+        }
+        Line::from(spans)
+    }
+}
+
+impl Component for SessionTab {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        use Action as A;
+
+        if self.input_mgr.is_some() {
+            if let A::Input(input) = action {
+                self.handle_input(input);
+                CTX.send_action(A::Render);
+            }
+            return ComponentAction::Nothing;
+        }
+
+        if self.showing_changes {
+            match action {
+                _ if action.is_soft_quit() => self.showing_changes = false,
+                A::Click(event)
+                    if popup_close_button_clicked(
+                        self.changes_block_rect,
+                        Position::Bottom,
+                        event,
+                    ) =>
+                {
+                    self.showing_changes = false
+                }
+                A::Confirm | A::ApplySessionChanges => self.apply(),
+                _ => return ComponentAction::Nothing,
+            }
+            CTX.send_action(A::Render);
+            return ComponentAction::Nothing;
+        }
+
+        match action {
+            _ if action.is_quit() => CTX.send_action(A::HardQuit),
+            A::Up => self.move_selection(-1),
+            A::Down => self.move_selection(1),
+            A::ScrollUpBy(amount) => self.move_selection(-isize::from(amount)),
+            A::ScrollDownBy(amount) => self.move_selection(isize::from(amount)),
+            A::ScrollUpPage => self.move_selection(-10),
+            A::ScrollDownPage => self.move_selection(10),
+            A::Home => self.selected = 0,
+            A::End => self.selected = SETTINGS.len() - 1,
+            A::Confirm | A::Select => self.edit_selected(),
+            A::ApplySessionChanges if !self.changes.is_empty() => self.showing_changes = true,
+            A::DiscardSessionChanges => self.changes.clear(),
+            _ => return ComponentAction::Nothing,
+        }
+
+        CTX.send_action(A::Render);
+        ComponentAction::Nothing
+    }
+
+    fn handle_update_action(&mut self, action: UpdateAction) {
+        match action {
+            UpdateAction::SessionSettings(settings) => {
+                // Whatever the daemon already agrees with isn't a change anymore.
+                self.changes
+                    .retain(|key, value| settings.get(key) != Some(value));
+                self.settings = Some(settings);
+            }
+            UpdateAction::SwitchProfile(_) => {
+                self.settings = None;
+                self.changes.clear();
+                self.showing_changes = false;
+                self.needs_fetch = true;
+            }
+            _ => (),
+        }
+    }
+
+    fn tick(&mut self) {
+        if self.needs_fetch && transmission::connection::is_connected() {
+            self.needs_fetch = false;
+            tokio::task::spawn(fetch_settings());
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let Some(settings) = &self.settings else {
+            f.render_widget(Paragraph::new("Loading...").centered(), rect);
+            return;
+        };
+
+        let [table_rect, bottom_rect] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(rect);
+
+        let accent_color = CONFIG.general.accent_color;
+        let mut rows = vec![];
+        let mut selected_row = 0;
+        let mut last_section = "";
+
+        for (i, setting) in SETTINGS.iter().enumerate() {
+            if setting.section != last_section {
+                rows.push(Row::new([Cell::from(setting.section).bold().underlined()]));
+                last_section = setting.section;
+            }
+
+            if i == self.selected {
+                selected_row = rows.len();
+            }
+
+            let current = settings.get(setting.key);
+            let value = match (current, self.changes.get(setting.key)) {
+                (_, Some(new)) => Line::from(vec![
+                    Span::raw(current.map_or_else(|| "-".to_string(), |old| setting.format(old))),
+                    Span::raw(" → "),
+                    Span::raw(setting.format(new)).fg(accent_color).bold(),
+                ]),
+                (Some(current), None) => Line::from(setting.format(current)),
+                (None, None) => Line::from("not supported by the daemon").dark_gray(),
+            };
+
+            rows.push(Row::new([
+                Cell::from(format!("  {}", setting.name)),
+                Cell::from(value),
+            ]));
+        }
+
+        self.table_state.select(Some(selected_row));
+
+        let table = Table::new(rows, [Constraint::Length(34), Constraint::Fill(1)])
+            .row_highlight_style(Style::default().fg(accent_color).on_black().bold());
+
+        f.render_stateful_widget(table, table_rect, &mut self.table_state);
+
+        if let Some(input_mgr) = &mut self.input_mgr {
+            input_mgr.render(f, bottom_rect);
+        } else {
+            f.render_widget(self.bottom_line(), bottom_rect);
+        }
+
+        if self.showing_changes {
+            self.render_changes(f, rect);
+        }
+    }
+}
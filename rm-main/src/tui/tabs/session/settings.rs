@@ -0,0 +1,443 @@
+use serde_json::{json, Value};
+
+const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const EVERY_DAY: i64 = 0b111_1111;
+const WEEKDAYS: i64 = 0b011_1110;
+const WEEKENDS: i64 = 0b100_0001;
+
+#[derive(Clone, Copy)]
+pub enum Kind {
+    Bool,
+    Int { min: i64, max: i64 },
+    Ratio,
+    // Minutes since midnight.
+    Time,
+    // Bitmask with Sunday being the lowest bit.
+    Days,
+    Choice(&'static [&'static str]),
+    Path,
+}
+
+pub struct Setting {
+    pub section: &'static str,
+    pub key: &'static str,
+    pub name: &'static str,
+    pub kind: Kind,
+}
+
+const fn setting(
+    section: &'static str,
+    key: &'static str,
+    name: &'static str,
+    kind: Kind,
+) -> Setting {
+    Setting {
+        section,
+        key,
+        name,
+        kind,
+    }
+}
+
+const SPEED: Kind = Kind::Int {
+    min: 0,
+    max: i32::MAX as i64,
+};
+const COUNT: Kind = Kind::Int {
+    min: 0,
+    max: i32::MAX as i64,
+};
+const PORT: Kind = Kind::Int { min: 1, max: 65535 };
+
+pub const SETTINGS: &[Setting] = &[
+    setting(
+        "Speed limits",
+        "speed-limit-down-enabled",
+        "Limit download speed",
+        Kind::Bool,
+    ),
+    setting(
+        "Speed limits",
+        "speed-limit-down",
+        "Download limit (KB/s)",
+        SPEED,
+    ),
+    setting(
+        "Speed limits",
+        "speed-limit-up-enabled",
+        "Limit upload speed",
+        Kind::Bool,
+    ),
+    setting(
+        "Speed limits",
+        "speed-limit-up",
+        "Upload limit (KB/s)",
+        SPEED,
+    ),
+    setting(
+        "Alternative speed limits",
+        "alt-speed-enabled",
+        "Enabled",
+        Kind::Bool,
+    ),
+    setting(
+        "Alternative speed limits",
+        "alt-speed-down",
+        "Download limit (KB/s)",
+        SPEED,
+    ),
+    setting(
+        "Alternative speed limits",
+        "alt-speed-up",
+        "Upload limit (KB/s)",
+        SPEED,
+    ),
+    setting(
+        "Alternative speed limits",
+        "alt-speed-time-enabled",
+        "Scheduled",
+        Kind::Bool,
+    ),
+    setting(
+        "Alternative speed limits",
+        "alt-speed-time-begin",
+        "From",
+        Kind::Time,
+    ),
+    setting(
+        "Alternative speed limits",
+        "alt-speed-time-end",
+        "To",
+        Kind::Time,
+    ),
+    setting(
+        "Alternative speed limits",
+        "alt-speed-time-day",
+        "On",
+        Kind::Days,
+    ),
+    setting("Network", "peer-port", "Peer port", PORT),
+    setting(
+        "Network",
+        "peer-port-random-on-start",
+        "Random port on start",
+        Kind::Bool,
+    ),
+    setting(
+        "Network",
+        "port-forwarding-enabled",
+        "Port forwarding (UPnP/NAT-PMP)",
+        Kind::Bool,
+    ),
+    setting(
+        "Network",
+        "encryption",
+        "Encryption",
+        Kind::Choice(&["required", "preferred", "tolerated"]),
+    ),
+    setting("Network", "peer-limit-global", "Peer limit", COUNT),
+    setting(
+        "Network",
+        "peer-limit-per-torrent",
+        "Peer limit per torrent",
+        COUNT,
+    ),
+    setting(
+        "Queue",
+        "download-queue-enabled",
+        "Limit active downloads",
+        Kind::Bool,
+    ),
+    setting("Queue", "download-queue-size", "Active downloads", COUNT),
+    setting(
+        "Queue",
+        "seed-queue-enabled",
+        "Limit active seeds",
+        Kind::Bool,
+    ),
+    setting("Queue", "seed-queue-size", "Active seeds", COUNT),
+    setting(
+        "Queue",
+        "queue-stalled-enabled",
+        "Skip stalled torrents",
+        Kind::Bool,
+    ),
+    setting(
+        "Queue",
+        "queue-stalled-minutes",
+        "Stalled after (minutes)",
+        COUNT,
+    ),
+    setting("Seeding", "seedRatioLimited", "Stop at ratio", Kind::Bool),
+    setting("Seeding", "seedRatioLimit", "Ratio", Kind::Ratio),
+    setting(
+        "Seeding",
+        "idle-seeding-limit-enabled",
+        "Stop when idle",
+        Kind::Bool,
+    ),
+    setting("Seeding", "idle-seeding-limit", "Idle for (minutes)", COUNT),
+    setting(
+        "Downloading",
+        "incomplete-dir-enabled",
+        "Use incomplete directory",
+        Kind::Bool,
+    ),
+    setting(
+        "Downloading",
+        "incomplete-dir",
+        "Incomplete directory",
+        Kind::Path,
+    ),
+    setting("Peer discovery", "dht-enabled", "DHT", Kind::Bool),
+    setting("Peer discovery", "pex-enabled", "PEX", Kind::Bool),
+    setting(
+        "Peer discovery",
+        "lpd-enabled",
+        "Local peer discovery",
+        Kind::Bool,
+    ),
+];
+
+impl Setting {
+    /// Whether it's changed right away instead of being typed in.
+    pub fn is_toggle(&self) -> bool {
+        matches!(self.kind, Kind::Bool | Kind::Choice(_))
+    }
+
+    /// The value after toggling a bool or moving to the next choice.
+    pub fn toggled(&self, value: &Value) -> Option<Value> {
+        match self.kind {
+            Kind::Bool => Some(json!(!value.as_bool()?)),
+            Kind::Choice(choices) => {
+                let current = choices
+                    .iter()
+                    .position(|choice| Some(*choice) == value.as_str());
+                let next = current.map_or(0, |current| (current + 1) % choices.len());
+                Some(json!(choices[next]))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn format(&self, value: &Value) -> String {
+        let formatted = match self.kind {
+            Kind::Bool => value
+                .as_bool()
+                .map(|value| if value { "Yes" } else { "No" }.into()),
+            Kind::Int { .. } => value.as_i64().map(|value| value.to_string()),
+            Kind::Ratio => value.as_f64().map(|value| format!("{value:.2}")),
+            Kind::Time => value
+                .as_i64()
+                .map(|minutes| format!("{:02}:{:02}", minutes / 60, minutes % 60)),
+            Kind::Days => value.as_i64().map(format_days),
+            Kind::Choice(_) | Kind::Path => value.as_str().map(str::to_string),
+        };
+
+        formatted.unwrap_or_else(|| value.to_string())
+    }
+
+    /// Parses what got typed in, with the reason if it's not valid.
+    pub fn parse(&self, text: &str) -> Result<Value, String> {
+        let text = text.trim();
+        match self.kind {
+            Kind::Int { min, max } => match text.parse::<i64>() {
+                Ok(number) if (min..=max).contains(&number) => Ok(json!(number)),
+                _ => Err(format!("a number from {min} to {max}")),
+            },
+            Kind::Ratio => match text.parse::<f64>() {
+                Ok(ratio) if ratio >= 0.0 && ratio.is_finite() => Ok(json!(ratio)),
+                _ => Err("a non-negative number".to_string()),
+            },
+            Kind::Time => parse_time(text)
+                .map(|minutes| json!(minutes))
+                .ok_or_else(|| "a time like 23:30".to_string()),
+            Kind::Days => parse_days(text)
+                .map(|days| json!(days))
+                .ok_or_else(|| "days like Mon,Tue or all, weekdays, weekends".to_string()),
+            Kind::Path if !text.is_empty() => Ok(json!(text)),
+            Kind::Path => Err("a directory".to_string()),
+            Kind::Bool | Kind::Choice(_) => Err("toggled, not typed in".to_string()),
+        }
+    }
+}
+
+fn format_days(days: i64) -> String {
+    match days & EVERY_DAY {
+        EVERY_DAY => "Every day".to_string(),
+        WEEKDAYS => "Weekdays".to_string(),
+        WEEKENDS => "Weekends".to_string(),
+        0 => "Never".to_string(),
+        days => DAYS
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| days & (1 << i) != 0)
+            .map(|(_, day)| *day)
+            .collect::<Vec<_>>()
+            .join(","),
+    }
+}
+
+fn parse_days(text: &str) -> Option<i64> {
+    match text.to_lowercase().as_str() {
+        "all" | "every day" => return Some(EVERY_DAY),
+        "weekdays" => return Some(WEEKDAYS),
+        "weekends" => return Some(WEEKENDS),
+        _ => (),
+    }
+
+    let mut days = 0;
+    for day in text.split(',').map(str::trim) {
+        let i = DAYS
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(day))?;
+        days |= 1 << i;
+    }
+    Some(days)
+}
+
+fn parse_time(text: &str) -> Option<i64> {
+    let (hours, minutes) = text.split_once(':')?;
+    let hours: i64 = hours.parse().ok()?;
+    let minutes: i64 = minutes.parse().ok()?;
+    ((0..24).contains(&hours) && (0..60).contains(&minutes)).then_some(hours * 60 + minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setting(key: &str) -> &'static Setting {
+        SETTINGS.iter().find(|setting| setting.key == key).unwrap()
+    }
+
+    #[test]
+    fn time() {
+        assert_eq!(parse_time("00:00"), Some(0));
+        assert_eq!(parse_time("9:05"), Some(9 * 60 + 5));
+        assert_eq!(parse_time("23:59"), Some(23 * 60 + 59));
+        assert_eq!(parse_time("24:00"), None);
+        assert_eq!(parse_time("12:60"), None);
+        assert_eq!(parse_time("-1:30"), None);
+        assert_eq!(parse_time("12"), None);
+        assert_eq!(parse_time("12:"), None);
+        assert_eq!(parse_time(""), None);
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(parse_days("all"), Some(EVERY_DAY));
+        assert_eq!(parse_days("Weekdays"), Some(WEEKDAYS));
+        assert_eq!(parse_days("weekends"), Some(WEEKENDS));
+        assert_eq!(parse_days("mon, WED"), Some(0b000_1010));
+        assert_eq!(parse_days("Mon,Someday"), None);
+        assert_eq!(parse_days(""), None);
+    }
+
+    #[test]
+    fn days_wrapping_around_the_week() {
+        // Sunday is the lowest bit and Saturday the highest, so they make up a weekend.
+        assert_eq!(parse_days("Sat,Sun"), Some(WEEKENDS));
+        assert_eq!(format_days(WEEKENDS), "Weekends");
+        assert_eq!(parse_days("Fri,Sat,Sun"), Some(0b110_0001));
+        assert_eq!(format_days(0b110_0001), "Sun,Fri,Sat");
+    }
+
+    #[test]
+    fn format_days_names() {
+        assert_eq!(format_days(EVERY_DAY), "Every day");
+        assert_eq!(format_days(WEEKDAYS), "Weekdays");
+        assert_eq!(format_days(0), "Never");
+        assert_eq!(format_days(0b000_1010), "Mon,Wed");
+        // Bits past Saturday aren't days.
+        assert_eq!(format_days(0b1000_0000), "Never");
+        assert_eq!(format_days(0b1111_1111), "Every day");
+    }
+
+    #[test]
+    fn parse() {
+        let port = setting("peer-port");
+        assert_eq!(port.parse(" 51413 "), Ok(json!(51413)));
+        assert!(port.parse("0").is_err());
+        assert!(port.parse("65536").is_err());
+        assert!(port.parse("").is_err());
+
+        let ratio = setting("seedRatioLimit");
+        assert_eq!(ratio.parse("1.5"), Ok(json!(1.5)));
+        assert!(ratio.parse("-1").is_err());
+        assert!(ratio.parse("inf").is_err());
+
+        assert_eq!(
+            setting("alt-speed-time-begin").parse("7:30"),
+            Ok(json!(450))
+        );
+        assert_eq!(
+            setting("alt-speed-time-day").parse("Sat,Sun"),
+            Ok(json!(WEEKENDS))
+        );
+
+        let dir = setting("incomplete-dir");
+        assert_eq!(dir.parse(" /data "), Ok(json!("/data")));
+        assert!(dir.parse("  ").is_err());
+
+        assert!(setting("dht-enabled").parse("yes").is_err());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(setting("dht-enabled").format(&json!(true)), "Yes");
+        assert_eq!(setting("seedRatioLimit").format(&json!(2)), "2.00");
+        assert_eq!(setting("alt-speed-time-end").format(&json!(1020)), "17:00");
+        assert_eq!(setting("alt-speed-time-begin").format(&json!(5)), "00:05");
+        assert_eq!(
+            setting("alt-speed-time-day").format(&json!(WEEKDAYS)),
+            "Weekdays"
+        );
+        assert_eq!(setting("encryption").format(&json!("required")), "required");
+        // Whatever the daemon sent is still shown if it's not the expected type.
+        assert_eq!(setting("peer-port").format(&json!("x")), "\"x\"");
+    }
+
+    #[test]
+    fn toggled() {
+        let dht = setting("dht-enabled");
+        assert_eq!(dht.toggled(&json!(true)), Some(json!(false)));
+        assert_eq!(dht.toggled(&json!("x")), None);
+
+        let encryption = setting("encryption");
+        assert_eq!(
+            encryption.toggled(&json!("required")),
+            Some(json!("preferred"))
+        );
+        assert_eq!(
+            encryption.toggled(&json!("tolerated")),
+            Some(json!("required"))
+        );
+        // An unknown value starts over from the first choice.
+        assert_eq!(encryption.toggled(&json!("other")), Some(json!("required")));
+
+        assert_eq!(setting("peer-port").toggled(&json!(1)), None);
+    }
+
+    #[test]
+    fn every_setting_can_be_written() {
+        use crate::transmission::session::{session_set_args, SessionSettings};
+
+        for setting in SETTINGS {
+            let value = match setting.kind {
+                Kind::Bool => json!(true),
+                Kind::Int { max, .. } => json!(max),
+                Kind::Ratio => json!(1.5),
+                Kind::Time => json!(17 * 60),
+                Kind::Days => json!(WEEKENDS),
+                Kind::Choice(choices) => json!(choices[0]),
+                Kind::Path => json!("/downloads"),
+            };
+            let changes = SessionSettings::from_iter([(setting.key.to_string(), value)]);
+            assert!(session_set_args(&changes).is_ok(), "{}", setting.key);
+        }
+
+        let changes = SessionSettings::from_iter([("peer-port".to_string(), json!("x"))]);
+        assert!(session_set_args(&changes).is_err());
+    }
+}
@@ -2,14 +2,19 @@ use std::sync::Arc;
 
 use ratatui::{
     layout::{Alignment, Rect},
+    style::Stylize,
+    text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
 use rm_config::CONFIG;
-use rm_shared::utils::bytes_to_human_format;
+use rm_shared::{connection_status::ConnectionStatus, utils::bytes_to_human_format};
 use transmission_rpc::types::{FreeSpace, SessionStats};
 
-use crate::tui::components::Component;
+use crate::{
+    transmission::session::{alt_speed_enabled, alt_speed_limits, SessionSettings},
+    tui::components::Component,
+};
 
 use super::table_manager::TableManager;
 
@@ -21,6 +26,11 @@ pub(super) struct BottomStats {
     pub(super) free_space: Option<Arc<FreeSpace>>,
     torrent_count: u16,
     torrent_currently_selected: u16,
+    connection_status: ConnectionStatus,
+    // Why the daemon can't be connected to, kept until it can.
+    connection_error: Option<String>,
+    // Download and upload limits (KB/s) while the alternative speed limits are on.
+    alt_speed: Option<(i64, i64)>,
 }
 
 impl BottomStats {
@@ -32,6 +42,21 @@ impl BottomStats {
         self.stats = Some(stats);
     }
 
+    pub fn set_connection_status(&mut self, status: ConnectionStatus) {
+        if status.is_connected() {
+            self.connection_error = None;
+        }
+        self.connection_status = status;
+    }
+
+    pub fn set_connection_error(&mut self, error: String) {
+        self.connection_error = Some(error);
+    }
+
+    pub fn set_session_settings(&mut self, settings: &SessionSettings) {
+        self.alt_speed = alt_speed_enabled(settings).then(|| alt_speed_limits(settings));
+    }
+
     pub fn set_free_space(&mut self, free_space: Arc<FreeSpace>) {
         self.free_space = Some(free_space);
     }
@@ -45,6 +70,29 @@ impl BottomStats {
 }
 impl Component for BottomStats {
     fn render(&mut self, f: &mut Frame, rect: Rect) {
+        match self.connection_status {
+            ConnectionStatus::Connected => (),
+            ConnectionStatus::Reconnecting => {
+                let text = format!("{} Connecting...", CONFIG.icons.loading);
+                f.render_widget(Paragraph::new(text).alignment(Alignment::Right), rect);
+                return;
+            }
+            ConnectionStatus::Offline { retry_in } => {
+                let text = match &self.connection_error {
+                    Some(error) => format!(
+                        "{} Offline ({error}), reconnecting in {retry_in}s",
+                        CONFIG.icons.failure
+                    ),
+                    None => format!(
+                        "{} Offline, reconnecting in {retry_in}s",
+                        CONFIG.icons.failure
+                    ),
+                };
+                f.render_widget(Paragraph::new(text).red().alignment(Alignment::Right), rect);
+                return;
+            }
+        }
+
         if let Some(stats) = &self.stats {
             let download = bytes_to_human_format(stats.download_speed);
             let upload = bytes_to_human_format(stats.upload_speed);
@@ -69,7 +117,20 @@ impl Component for BottomStats {
                 text = format!("{} {} | {text}", CONFIG.icons.file, self.torrent_count);
             }
 
-            let paragraph = Paragraph::new(text).alignment(Alignment::Right);
+            let mut line = Line::default();
+            if let Some((down, up)) = self.alt_speed {
+                line.push_span(
+                    Span::raw(format!(
+                        "{} {} {down} KB/s {} {up} KB/s",
+                        CONFIG.icons.turtle, CONFIG.icons.download, CONFIG.icons.upload
+                    ))
+                    .fg(CONFIG.general.accent_color),
+                );
+                line.push_span(Span::raw(" | "));
+            }
+            line.push_span(Span::raw(text));
+
+            let paragraph = Paragraph::new(line).alignment(Alignment::Right);
             f.render_widget(paragraph, rect);
         }
     }
@@ -0,0 +1,209 @@
+use std::collections::{BTreeMap, HashSet};
+
+use rm_config::CONFIG;
+use transmission_rpc::types::TorrentStatus;
+
+use super::rustmission_torrent::RustmissionTorrent;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusFacet {
+    Downloading,
+    Seeding,
+    Paused,
+    Checking,
+    Errored,
+    Stalled,
+}
+
+impl StatusFacet {
+    const ALL: [StatusFacet; 6] = [
+        StatusFacet::Downloading,
+        StatusFacet::Seeding,
+        StatusFacet::Paused,
+        StatusFacet::Checking,
+        StatusFacet::Errored,
+        StatusFacet::Stalled,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            StatusFacet::Downloading => "Downloading",
+            StatusFacet::Seeding => "Seeding",
+            StatusFacet::Paused => "Paused",
+            StatusFacet::Checking => "Checking",
+            StatusFacet::Errored => "Errored",
+            StatusFacet::Stalled => "Stalled",
+        }
+    }
+
+    pub fn matches(self, torrent: &RustmissionTorrent) -> bool {
+        match self {
+            StatusFacet::Downloading => matches!(
+                torrent.status(),
+                TorrentStatus::Downloading | TorrentStatus::QueuedToDownload
+            ),
+            StatusFacet::Seeding => matches!(
+                torrent.status(),
+                TorrentStatus::Seeding | TorrentStatus::QueuedToSeed
+            ),
+            StatusFacet::Paused => torrent.status() == TorrentStatus::Stopped,
+            StatusFacet::Checking => matches!(
+                torrent.status(),
+                TorrentStatus::Verifying | TorrentStatus::QueuedToVerify
+            ),
+            StatusFacet::Errored => torrent.error.is_some(),
+            StatusFacet::Stalled => torrent.is_stalled,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FacetGroup {
+    Status,
+    Category,
+    Tracker,
+    Directory,
+}
+
+impl FacetGroup {
+    pub const ALL: [FacetGroup; 4] = [
+        FacetGroup::Status,
+        FacetGroup::Category,
+        FacetGroup::Tracker,
+        FacetGroup::Directory,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            FacetGroup::Status => "Status",
+            FacetGroup::Category => "Categories",
+            FacetGroup::Tracker => "Trackers",
+            FacetGroup::Directory => "Directories",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum Facet {
+    Status(StatusFacet),
+    Category(String),
+    Tracker(String),
+    Directory(String),
+}
+
+impl Facet {
+    pub fn group(&self) -> FacetGroup {
+        match self {
+            Facet::Status(_) => FacetGroup::Status,
+            Facet::Category(_) => FacetGroup::Category,
+            Facet::Tracker(_) => FacetGroup::Tracker,
+            Facet::Directory(_) => FacetGroup::Directory,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Facet::Status(status) => status.name(),
+            Facet::Category(name) | Facet::Tracker(name) | Facet::Directory(name) => name,
+        }
+    }
+
+    fn matches(&self, torrent: &RustmissionTorrent) -> bool {
+        match self {
+            Facet::Status(status) => status.matches(torrent),
+            Facet::Category(name) => torrent
+                .category
+                .as_ref()
+                .is_some_and(|category| category.name() == name),
+            Facet::Tracker(host) => torrent.tracker_hosts.contains(host),
+            Facet::Directory(dir) => torrent.download_dir == *dir,
+        }
+    }
+}
+
+/// Facets picked in the sidebar. A torrent has to match at least one facet
+/// of every group that has any picked.
+#[derive(Default)]
+pub struct Facets {
+    active: HashSet<Facet>,
+}
+
+impl Facets {
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn is_active(&self, facet: &Facet) -> bool {
+        self.active.contains(facet)
+    }
+
+    pub fn toggle(&mut self, facet: Facet) {
+        if !self.active.remove(&facet) {
+            self.active.insert(facet);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    pub fn matches(&self, torrent: &RustmissionTorrent) -> bool {
+        FacetGroup::ALL.iter().all(|group| {
+            let mut picked = self
+                .active
+                .iter()
+                .filter(|facet| facet.group() == *group)
+                .peekable();
+            picked.peek().is_none() || picked.any(|facet| facet.matches(torrent))
+        })
+    }
+
+    /// Every facet worth listing along with how many torrents match it.
+    /// Picked facets are listed even when nothing matches them anymore.
+    pub fn counts(&self, torrents: &[RustmissionTorrent]) -> Vec<(Facet, usize)> {
+        let mut counts = vec![];
+
+        for status in StatusFacet::ALL {
+            let count = torrents.iter().filter(|t| status.matches(t)).count();
+            counts.push((Facet::Status(status), count));
+        }
+
+        let mut categories: BTreeMap<String, usize> = CONFIG
+            .categories
+            .map
+            .keys()
+            .map(|name| (name.clone(), 0))
+            .collect();
+        let mut trackers = BTreeMap::new();
+        let mut directories = BTreeMap::new();
+
+        for torrent in torrents {
+            if let Some(category) = &torrent.category {
+                *categories.entry(category.name().to_string()).or_default() += 1;
+            }
+            for host in &torrent.tracker_hosts {
+                *trackers.entry(host.clone()).or_default() += 1;
+            }
+            *directories.entry(torrent.download_dir.clone()).or_default() += 1;
+        }
+
+        for facet in &self.active {
+            let (map, name) = match facet {
+                Facet::Status(_) => continue,
+                Facet::Category(name) => (&mut categories, name),
+                Facet::Tracker(host) => (&mut trackers, host),
+                Facet::Directory(dir) => (&mut directories, dir),
+            };
+            map.entry(name.clone()).or_default();
+        }
+
+        counts.extend(categories.into_iter().map(|(n, c)| (Facet::Category(n), c)));
+        counts.extend(trackers.into_iter().map(|(n, c)| (Facet::Tracker(n), c)));
+        counts.extend(
+            directories
+                .into_iter()
+                .map(|(n, c)| (Facet::Directory(n), c)),
+        );
+        counts
+    }
+}
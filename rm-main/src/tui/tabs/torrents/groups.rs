@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    prelude::*,
+    widgets::{Cell, Row},
+};
+use rm_config::{main_config::GroupBy, CONFIG};
+use rm_shared::{header::Header, utils::bytes_to_human_format};
+use transmission_rpc::types::TorrentStatus;
+
+use super::rustmission_torrent::RustmissionTorrent;
+
+/// Torrents sharing a category, directory, tracker or status, along with
+/// their totals shown in the group's header row.
+pub struct Group {
+    pub name: String,
+    pub is_collapsed: bool,
+    /// Positions of the torrents among the visible (filtered) ones.
+    pub positions: Vec<usize>,
+    size: i64,
+    download_speed: i64,
+    upload_speed: i64,
+    uploaded: i64,
+    downloaded: u64,
+}
+
+impl Group {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            is_collapsed: false,
+            positions: vec![],
+            size: 0,
+            download_speed: 0,
+            upload_speed: 0,
+            uploaded: 0,
+            downloaded: 0,
+        }
+    }
+
+    fn add(&mut self, position: usize, torrent: &RustmissionTorrent) {
+        self.positions.push(position);
+        self.size += torrent.size_when_done;
+        self.download_speed += torrent.download_speed;
+        self.upload_speed += torrent.upload_speed;
+        self.uploaded += torrent.uploaded_ever;
+        self.downloaded += torrent.downloaded_ever;
+    }
+
+    fn ratio(&self) -> f64 {
+        if self.downloaded == 0 {
+            0.0
+        } else {
+            self.uploaded as f64 / self.downloaded as f64
+        }
+    }
+
+    pub fn to_row(&self, headers: &[Header]) -> Row<'_> {
+        let icon = if self.is_collapsed {
+            &CONFIG.icons.triangle_right
+        } else {
+            &CONFIG.icons.triangle_down
+        };
+        let label = format!("{icon} {} ({})", self.name, self.positions.len());
+        // The label needs a column even if the name isn't displayed.
+        let label_idx = headers
+            .iter()
+            .position(|header| *header == Header::Name)
+            .unwrap_or(0);
+
+        headers
+            .iter()
+            .enumerate()
+            .map(|(idx, header)| match header {
+                _ if idx == label_idx => Cell::from(label.clone()),
+                Header::SizeWhenDone => Cell::from(bytes_to_human_format(self.size)),
+                Header::DownloadRate if self.download_speed != 0 => Cell::from(format!(
+                    "{} {}",
+                    CONFIG.icons.download,
+                    bytes_to_human_format(self.download_speed)
+                )),
+                Header::UploadRate if self.upload_speed != 0 => Cell::from(format!(
+                    "{} {}",
+                    CONFIG.icons.upload,
+                    bytes_to_human_format(self.upload_speed)
+                )),
+                Header::UploadRatio => Cell::from(format!("{:.1}", self.ratio())),
+                _ => Cell::default(),
+            })
+            .collect::<Row>()
+            .style(Style::default().fg(CONFIG.general.accent_color).bold())
+    }
+}
+
+pub enum GroupedRow {
+    Header(usize),
+    /// Position of the torrent among the visible ones.
+    Torrent(usize),
+}
+
+/// Splits the visible torrents into groups, keeping their order within a group.
+pub fn group<'a>(
+    group_by: GroupBy,
+    torrents: impl Iterator<Item = &'a RustmissionTorrent>,
+) -> Vec<Group> {
+    let mut groups: HashMap<(u8, String), Group> = HashMap::new();
+
+    for (position, torrent) in torrents.enumerate() {
+        let key = group_key(group_by, torrent);
+        groups
+            .entry(key.clone())
+            .or_insert_with(|| Group::new(key.1))
+            .add(position, torrent);
+    }
+
+    let mut groups: Vec<_> = groups.into_iter().collect();
+    groups.sort_by(|(x, _), (y, _)| x.cmp(y));
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+/// Groups are ordered by the first element, then by name.
+fn group_key(group_by: GroupBy, torrent: &RustmissionTorrent) -> (u8, String) {
+    let named = |name: Option<&str>, fallback: &str| match name {
+        Some(name) => (0, name.to_string()),
+        None => (1, fallback.to_string()),
+    };
+
+    match group_by {
+        GroupBy::None => (0, String::new()),
+        GroupBy::Category => named(
+            torrent.category.as_ref().map(|category| category.name()),
+            "Uncategorized",
+        ),
+        GroupBy::Directory => (0, torrent.download_dir.clone()),
+        GroupBy::Tracker => named(
+            torrent.tracker_hosts.first().map(String::as_str),
+            "No tracker",
+        ),
+        GroupBy::Status => {
+            let (rank, name) = if torrent.error.is_some() {
+                (0, "Errored")
+            } else {
+                match torrent.status() {
+                    TorrentStatus::Downloading => (1, "Downloading"),
+                    TorrentStatus::Seeding => (2, "Seeding"),
+                    TorrentStatus::QueuedToDownload | TorrentStatus::QueuedToSeed => (3, "Queued"),
+                    TorrentStatus::Verifying | TorrentStatus::QueuedToVerify => (4, "Checking"),
+                    TorrentStatus::Stopped => (5, "Paused"),
+                }
+            };
+            (rank, name.to_string())
+        }
+    }
+}
@@ -1,43 +1,73 @@
 mod bottom_stats;
+mod facets;
+mod groups;
 pub mod popups;
+pub mod query;
 pub mod rustmission_torrent;
+mod sidebar;
 pub mod table_manager;
 pub mod task_manager;
 pub mod tasks;
 
-use std::sync::OnceLock;
+use std::sync::RwLock;
 
-use crate::transmission::TorrentAction;
-use crate::tui::components::{Component, ComponentAction};
+use crate::transmission::{session::QueueMove, TorrentAction};
+use crate::tui::components::{is_left_click, Component, ComponentAction};
 use crate::tui::ctx::CTX;
 
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use intuitils::error_message::ErrorMessage;
+use popups::columns::ColumnsPopup;
+use popups::context_menu::ContextMenuPopup;
 use popups::details::DetailsPopup;
 use popups::stats::StatisticsPopup;
+use popups::views::ViewsPopup;
+use query::Query;
 use ratatui::{
     prelude::*,
     widgets::{Cell, Row, Table},
 };
 
-use rm_config::CONFIG;
+use rm_config::{
+    main_config::{TorrentsTab as TorrentsTabConfig, View},
+    CONFIG,
+};
 use rm_shared::{
     action::{Action, UpdateAction},
     current_window::TorrentWindow,
+    header::Header,
     status_task::StatusTask,
 };
 use rustmission_torrent::RustmissionTorrent;
 use tasks::TorrentSelection;
 use transmission_rpc::types::{Id, SessionGet, TorrentStatus};
 
-use crate::transmission;
-
 use self::bottom_stats::BottomStats;
 use self::popups::files::FilesPopup;
+use self::popups::options::OptionsPopup;
+use self::popups::peers::PeersPopup;
+use self::popups::trackers::TrackersPopup;
 use self::popups::{CurrentPopup, PopupManager};
+use self::sidebar::{Sidebar, SIDEBAR_WIDTH};
 use self::table_manager::TableManager;
 use self::task_manager::TaskManager;
 
-pub static SESSION_GET: OnceLock<SessionGet> = OnceLock::new();
+pub static SESSION_GET: RwLock<Option<SessionGet>> = RwLock::new(None);
+
+/// Headers the torrents table needs fields fetched for, the sorted by one included.
+pub static TABLE_HEADERS: RwLock<Vec<Header>> = RwLock::new(Vec::new());
+
+/// Name of the view the torrents tab is showing, if it isn't the default one.
+pub static ACTIVE_VIEW: RwLock<Option<String>> = RwLock::new(None);
+
+/// Default download directory of the currently connected daemon.
+pub fn session_download_dir() -> Option<String> {
+    SESSION_GET
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|session| session.download_dir.clone())
+}
 
 pub struct TorrentsTab {
     pub current_window: TorrentWindow,
@@ -45,6 +75,11 @@ pub struct TorrentsTab {
     popup_manager: PopupManager,
     task_manager: TaskManager,
     bottom_stats: BottomStats,
+    sidebar: Sidebar,
+    views: Vec<View>,
+    active_view: Option<usize>,
+    /// Headers of views that don't have their own, changed with the column editor.
+    default_headers: Vec<Header>,
 }
 
 impl TorrentsTab {
@@ -52,16 +87,16 @@ impl TorrentsTab {
         let table_manager = TableManager::new();
         let bottom_stats = BottomStats::new();
 
-        tokio::spawn(transmission::fetchers::stats());
-        tokio::spawn(transmission::fetchers::torrents());
-        tokio::spawn(transmission::fetchers::free_space());
-
         Self {
             bottom_stats,
             task_manager: TaskManager::new(),
             table_manager,
             popup_manager: PopupManager::new(),
             current_window: TorrentWindow::General,
+            sidebar: Sidebar::new(),
+            views: CONFIG.torrents_tab.views.clone(),
+            active_view: None,
+            default_headers: CONFIG.torrents_tab.headers.clone(),
         }
     }
 }
@@ -71,6 +106,17 @@ impl Component for TorrentsTab {
         let [torrents_list_rect, stats_rect] =
             Layout::vertical([Constraint::Min(10), Constraint::Length(1)]).areas(rect);
 
+        let torrents_list_rect = if self.sidebar.is_visible {
+            let [sidebar_rect, torrents_list_rect] =
+                Layout::horizontal([Constraint::Length(SIDEBAR_WIDTH), Constraint::Fill(1)])
+                    .areas(torrents_list_rect);
+            self.sidebar
+                .render(f, sidebar_rect, &self.table_manager.facets);
+            torrents_list_rect
+        } else {
+            torrents_list_rect
+        };
+
         self.render_table(f, torrents_list_rect);
         self.bottom_stats.render(f, stats_rect);
         self.task_manager.render(f, stats_rect);
@@ -100,6 +146,14 @@ impl Component for TorrentsTab {
                     self.table_manager.move_to_column_right();
                     CTX.send_action(Action::Render);
                 }
+                A::AppendSortColumnLeft => {
+                    self.table_manager.append_sort_column_left();
+                    CTX.send_action(Action::Render);
+                }
+                A::AppendSortColumnRight => {
+                    self.table_manager.append_sort_column_right();
+                    CTX.send_action(Action::Render);
+                }
                 A::Down | A::Up => {
                     self.table_manager.reverse_sort();
                     CTX.send_action(Action::Render);
@@ -114,13 +168,40 @@ impl Component for TorrentsTab {
             return ComponentAction::Nothing;
         }
 
+        if let A::Click(event) | A::DoubleClick(event) = action {
+            self.handle_click(event, matches!(action, A::DoubleClick(_)));
+            return ComponentAction::Nothing;
+        }
+
+        if self.sidebar.is_focused {
+            match action {
+                _ if action.is_quit() => CTX.send_action(Action::HardQuit),
+                A::ChangeFocus | A::Close => self.sidebar.is_focused = false,
+                A::ToggleSidebar => self.toggle_sidebar(),
+                _ => {
+                    if self
+                        .sidebar
+                        .handle_action(action, &mut self.table_manager.facets)
+                    {
+                        self.apply_facets();
+                    }
+                }
+            }
+            CTX.send_action(Action::Render);
+            return ComponentAction::Nothing;
+        }
+
+        if self.table_manager.is_visual_selecting()
+            && (action.is_soft_quit() || action == A::VisualSelect)
+        {
+            self.table_manager.stop_visual_selection();
+            self.update_selection_task();
+            CTX.send_action(Action::Render);
+            return ComponentAction::Nothing;
+        }
+
         if !self.table_manager.selected_torrents_ids.is_empty() && action.is_soft_quit() {
-            self.table_manager
-                .table
-                .items
-                .iter_mut()
-                .for_each(|t| t.is_selected = false);
-            self.table_manager.selected_torrents_ids.drain(..);
+            self.table_manager.clear_selection();
             self.task_manager.default();
             CTX.send_action(Action::Render);
             return ComponentAction::Nothing;
@@ -142,18 +223,60 @@ impl Component for TorrentsTab {
             A::End => self.select_last(),
             A::ShowStats => self.show_statistics_popup(),
             A::ShowFiles => self.show_files_popup(),
+            A::ShowPeers => self.show_peers_popup(),
+            A::ShowTrackers => self.show_trackers_popup(),
+            A::ShowOptions => self.show_options_popup(),
+            A::Confirm if self.table_manager.is_group_highlighted() => {
+                self.table_manager.toggle_current_group();
+                self.bottom_stats
+                    .update_selected_indicator(&self.table_manager);
+                CTX.send_action(Action::Render);
+            }
             A::Confirm => self.show_details_popup(),
             A::Select => {
-                self.table_manager.select_current_torrent();
-                if !self.table_manager.selected_torrents_ids.is_empty() {
-                    self.task_manager
-                        .select(self.table_manager.selected_torrents_ids.len());
+                if self.table_manager.is_group_highlighted() {
+                    self.table_manager.select_current_group();
                 } else {
-                    self.task_manager.default();
+                    self.table_manager.select_current_torrent();
                 }
+                self.update_selection_task();
+                CTX.send_action(Action::Render);
+            }
+            A::VisualSelect => {
+                self.table_manager.start_visual_selection();
+                self.update_selection_task();
+                CTX.send_action(Action::Render);
+            }
+            A::SelectAll => {
+                self.table_manager.select_all();
+                self.update_selection_task();
+                CTX.send_action(Action::Render);
+            }
+            A::InvertSelection => {
+                self.table_manager.invert_selection();
+                self.update_selection_task();
+                CTX.send_action(Action::Render);
+            }
+            A::ClearSelection => {
+                self.table_manager.clear_selection();
+                self.update_selection_task();
+                CTX.send_action(Action::Render);
+            }
+            A::SelectSameCategory => {
+                self.table_manager.select_same_category();
+                self.update_selection_task();
                 CTX.send_action(Action::Render);
             }
             A::Pause => self.pause_current_torrent(),
+            A::StartNow => self.run_on_selected(TorrentAction::StartNow, StatusTask::new_start_now),
+            A::Verify => self.run_on_selected(TorrentAction::Verify, StatusTask::new_verify),
+            A::Reannounce => {
+                self.run_on_selected(TorrentAction::Reannounce, StatusTask::new_reannounce)
+            }
+            A::QueueMoveTop => self.move_in_queue(QueueMove::Top),
+            A::QueueMoveUp => self.move_in_queue(QueueMove::Up),
+            A::QueueMoveDown => self.move_in_queue(QueueMove::Down),
+            A::QueueMoveBottom => self.move_in_queue(QueueMove::Bottom),
             A::Delete => {
                 if let Some(torrent_selection) = self.get_currently_selected() {
                     self.task_manager.delete_torrents(torrent_selection);
@@ -166,6 +289,7 @@ impl Component for TorrentsTab {
                 }
             }
             A::AddMagnet => self.task_manager.add_magnet(),
+            A::CreateTorrent => self.task_manager.create_torrent(),
             A::Search => self.task_manager.search(
                 &self
                     .table_manager
@@ -175,9 +299,8 @@ impl Component for TorrentsTab {
             ),
             A::MoveTorrent => {
                 if let Some(selection) = self.get_currently_selected() {
-                    if let Some(session_info) = SESSION_GET.get() {
-                        self.task_manager
-                            .move_torrent(selection, session_info.download_dir.clone());
+                    if let Some(download_dir) = session_download_dir() {
+                        self.task_manager.move_torrent(selection, download_dir);
                     }
                 }
             }
@@ -187,6 +310,20 @@ impl Component for TorrentsTab {
                 }
             }
             A::XdgOpen => self.xdg_open_current_torrent(),
+            A::ToggleSidebar => self.toggle_sidebar(),
+            A::CycleGroupBy => {
+                self.table_manager.cycle_group_by();
+                self.bottom_stats
+                    .update_selected_indicator(&self.table_manager);
+                CTX.send_action(Action::Render);
+            }
+            A::CycleView if !self.views.is_empty() => self.cycle_view(),
+            A::CycleView | A::ShowViews => self.show_views_popup(),
+            A::ShowColumns => self.show_columns_popup(),
+            A::ChangeFocus if self.sidebar.is_visible => {
+                self.sidebar.is_focused = true;
+                CTX.send_action(Action::Render);
+            }
             A::MoveToColumnLeft | A::MoveToColumnRight => {
                 self.table_manager.enter_sorting_selection();
                 self.task_manager.sort();
@@ -197,6 +334,12 @@ impl Component for TorrentsTab {
             }
         };
 
+        // Whatever moved the cursor, the range follows it.
+        if self.table_manager.is_visual_selecting() {
+            self.table_manager.update_visual_selection();
+            self.update_selection_task();
+        }
+
         ComponentAction::Nothing
     }
 
@@ -211,6 +354,16 @@ impl Component for TorrentsTab {
                 }
                 self.bottom_stats.set_stats(stats);
             }
+            UpdateAction::ConnectionStatus(status) => {
+                self.bottom_stats.set_connection_status(status);
+            }
+            UpdateAction::ConnectionError(err) => {
+                self.bottom_stats
+                    .set_connection_error(err.source.to_string());
+            }
+            UpdateAction::SessionSettings(settings) => {
+                self.bottom_stats.set_session_settings(&settings);
+            }
             UpdateAction::FreeSpace(free_space) => {
                 self.bottom_stats.set_free_space(free_space);
             }
@@ -222,26 +375,52 @@ impl Component for TorrentsTab {
                     .update_selected_indicator(&self.table_manager);
             }
             UpdateAction::SearchFilterClear => {
-                self.table_manager.filter = None;
+                self.table_manager.clear_filter();
                 self.table_manager.table.state.borrow_mut().select(Some(0));
                 self.table_manager.update_rows_number();
                 self.bottom_stats
                     .update_selected_indicator(&self.table_manager);
             }
+            UpdateAction::SwitchView(view) => self.switch_view(view),
+            UpdateAction::PromptSaveView => {
+                let names = self.views.iter().map(|view| view.name.clone()).collect();
+                self.task_manager.save_view(names);
+            }
+            UpdateAction::SaveView(name) => self.save_view(name),
+            UpdateAction::SetColumns(headers, widths) => {
+                self.table_manager.set_columns(headers, widths);
+                CTX.send_action(Action::Render);
+            }
+            UpdateAction::SaveColumns => self.save_columns(),
             UpdateAction::UpdateTorrents(torrents) => {
                 let torrents = torrents.into_iter().map(RustmissionTorrent::from).collect();
 
                 self.table_manager.set_new_rows(torrents);
-                if self.table_manager.selected_torrents_ids.is_empty()
-                    && self.task_manager.is_selection_task()
-                {
-                    self.task_manager.default()
+                self.sidebar
+                    .update(&self.table_manager.table.items, &self.table_manager.facets);
+                // Keeps the count and size up to date, or hides the task once
+                // the selected torrents are gone.
+                if self.task_manager.is_selection_task() {
+                    self.update_selection_task();
                 }
 
                 self.bottom_stats
                     .update_selected_indicator(&self.table_manager);
             }
-            UpdateAction::UpdateCurrentTorrent(_) => {
+            UpdateAction::SwitchProfile(name) => {
+                self.popup_manager.close_popup();
+                self.current_window = TorrentWindow::General;
+                self.table_manager.clear_selection();
+                self.table_manager.facets.clear();
+                self.table_manager.set_new_rows(vec![]);
+                self.bottom_stats = BottomStats::new();
+                self.task_manager.default();
+                self.task_manager
+                    .handle_update_action(UpdateAction::StatusTaskSetSuccess(
+                        StatusTask::new_profile(name),
+                    ));
+            }
+            UpdateAction::UpdateCurrentTorrent(_) | UpdateAction::UpdateSelectedTorrents(_) => {
                 self.popup_manager.handle_update_action(action)
             }
             UpdateAction::CancelTorrentTask => {
@@ -249,12 +428,7 @@ impl Component for TorrentsTab {
                     return;
                 }
 
-                if !self.table_manager.selected_torrents_ids.is_empty() {
-                    self.task_manager
-                        .select(self.table_manager.selected_torrents_ids.len());
-                } else {
-                    self.task_manager.default();
-                }
+                self.update_selection_task();
                 CTX.send_update_action(UpdateAction::SwitchToNormalMode);
             }
             other => self.task_manager.handle_update_action(other),
@@ -300,16 +474,36 @@ impl TorrentsTab {
             .map(|h| h.header_name())
             .collect::<Vec<_>>();
 
-        let sorted_header_name;
-        if let Some(sort_header) = self.table_manager.sort_header {
+        let sorted_header_names;
+        if self.table_manager.sort_header.is_some() {
             let icon = if self.table_manager.sort_reverse {
                 &CONFIG.icons.sort_descending
             } else {
                 &CONFIG.icons.sort_ascending
             };
 
-            sorted_header_name = format!("{icon} {}", text_headers[sort_header]);
-            text_headers[sort_header] = sorted_header_name.as_str();
+            // Sort columns get numbered once there's more than one of them.
+            let (sort, _) = self.table_manager.current_sort();
+            sorted_header_names = sort
+                .iter()
+                .enumerate()
+                .filter_map(|(priority, header)| {
+                    let idx = self
+                        .table_manager
+                        .headers()
+                        .iter()
+                        .position(|h| h == header)?;
+                    let name = if sort.len() > 1 {
+                        format!("{icon}{} {}", priority + 1, text_headers[idx])
+                    } else {
+                        format!("{icon} {}", text_headers[idx])
+                    };
+                    Some((idx, name))
+                })
+                .collect::<Vec<_>>();
+            for (idx, name) in &sorted_header_names {
+                text_headers[*idx] = name.as_str();
+            }
         }
 
         let mut headers = text_headers
@@ -341,6 +535,74 @@ impl TorrentsTab {
             rect,
             &mut self.table_manager.table.state.borrow_mut(),
         );
+        self.table_manager.table.set_area(rect);
+    }
+
+    fn update_selection_task(&mut self) {
+        if !self.table_manager.selected_torrents_ids.is_empty() {
+            self.task_manager.select(
+                self.table_manager.selected_torrents_ids.len(),
+                self.table_manager.selected_size(),
+                self.table_manager.is_visual_selecting(),
+            );
+        } else {
+            self.task_manager.default();
+        }
+    }
+
+    fn handle_click(&mut self, event: MouseEvent, is_double_click: bool) {
+        let position = Position::new(event.column, event.row);
+
+        if let Some(column) = self
+            .table_manager
+            .table
+            .header_at(&self.table_manager.widths, position)
+        {
+            if is_left_click(event) {
+                self.table_manager.sort_by_column(column);
+                CTX.send_action(Action::Render);
+            }
+            return;
+        }
+
+        let Some(row) = self.table_manager.row_at(position) else {
+            return;
+        };
+        self.sidebar.is_focused = false;
+
+        let highlighted = self.table_manager.table.state.borrow().selected();
+        if event.modifiers.contains(KeyModifiers::SHIFT) {
+            if let Some(highlighted) = highlighted {
+                self.table_manager.select_range(highlighted, row);
+            }
+        }
+        self.table_manager
+            .table
+            .state
+            .borrow_mut()
+            .select(Some(row));
+        if event.modifiers.contains(KeyModifiers::CONTROL) {
+            if self.table_manager.is_group_highlighted() {
+                self.table_manager.select_current_group();
+            } else {
+                self.table_manager.select_current_torrent();
+            }
+        }
+        self.table_manager.update_visual_selection();
+        self.update_selection_task();
+        self.bottom_stats
+            .update_selected_indicator(&self.table_manager);
+        CTX.send_action(Action::Render);
+
+        if event.kind == MouseEventKind::Down(MouseButton::Right)
+            && !self.table_manager.is_group_highlighted()
+        {
+            let popup = ContextMenuPopup::new(position);
+            self.popup_manager
+                .show_popup(CurrentPopup::ContextMenu(popup));
+        } else if is_double_click {
+            self.handle_actions(Action::Confirm);
+        }
     }
 
     fn get_currently_selected(&mut self) -> Option<TorrentSelection> {
@@ -352,6 +614,7 @@ impl TorrentsTab {
                     .into_iter()
                     .map(Id::Id)
                     .collect(),
+                self.table_manager.selected_size(),
             ))
         } else if let Some(t) = self.table_manager.current_torrent() {
             Some(TorrentSelection::Single(
@@ -366,21 +629,67 @@ impl TorrentsTab {
     fn show_files_popup(&mut self) {
         if let Some(highlighted_torrent) = self.table_manager.current_torrent() {
             let popup = FilesPopup::new(highlighted_torrent.id.clone());
-            self.popup_manager.show_popup(CurrentPopup::Files(popup));
+            self.popup_manager
+                .show_popup(CurrentPopup::Files(Box::new(popup)));
 
             let update_action = UpdateAction::ChangeTorrentWindow(TorrentWindow::FileViewer);
             CTX.send_update_action(update_action);
         }
     }
 
+    fn show_peers_popup(&mut self) {
+        if let Some(highlighted_torrent) = self.table_manager.current_torrent() {
+            let popup = PeersPopup::new(highlighted_torrent.id.clone());
+            self.popup_manager
+                .show_popup(CurrentPopup::Peers(Box::new(popup)));
+            CTX.send_action(Action::Render);
+        }
+    }
+
+    fn show_trackers_popup(&mut self) {
+        if let Some(selection) = self.get_currently_selected() {
+            let popup = TrackersPopup::new(selection);
+            self.popup_manager
+                .show_popup(CurrentPopup::Trackers(Box::new(popup)));
+            CTX.send_action(Action::Render);
+        }
+    }
+
+    fn show_options_popup(&mut self) {
+        if let Some(selection) = self.get_currently_selected() {
+            let popup = OptionsPopup::new(selection);
+            self.popup_manager
+                .show_popup(CurrentPopup::Options(Box::new(popup)));
+            CTX.send_action(Action::Render);
+        }
+    }
+
     fn show_details_popup(&mut self) {
         if let Some(highlighted_torrent) = self.table_manager.current_torrent() {
             let popup = DetailsPopup::new(highlighted_torrent.clone());
-            self.popup_manager.show_popup(CurrentPopup::Details(popup));
+            self.popup_manager
+                .show_popup(CurrentPopup::Details(Box::new(popup)));
             CTX.send_action(Action::Render);
         }
     }
 
+    fn show_views_popup(&mut self) {
+        let names = self.views.iter().map(|view| view.name.clone()).collect();
+        let popup = ViewsPopup::new(names, self.active_view);
+        self.popup_manager.show_popup(CurrentPopup::Views(popup));
+        CTX.send_action(Action::Render);
+    }
+
+    fn show_columns_popup(&mut self) {
+        let popup = ColumnsPopup::new(
+            self.table_manager.headers().clone(),
+            self.table_manager.column_widths().clone(),
+        );
+        self.popup_manager.show_popup(CurrentPopup::Columns(popup));
+        CTX.send_update_action(UpdateAction::ChangeTorrentWindow(TorrentWindow::Columns));
+        CTX.send_action(Action::Render);
+    }
+
     fn show_statistics_popup(&mut self) {
         if let Some(stats) = &self.bottom_stats.stats {
             let popup = StatisticsPopup::new(stats.clone());
@@ -465,6 +774,171 @@ impl TorrentsTab {
         }
     }
 
+    fn run_on_selected(
+        &mut self,
+        torrent_action: impl FnOnce(Vec<Id>) -> TorrentAction,
+        status_task: impl FnOnce(String) -> StatusTask,
+    ) {
+        if let Some(selection) = self.get_currently_selected() {
+            CTX.send_torrent_action(torrent_action(selection.ids()));
+            let task = status_task(selection.describe());
+            CTX.send_update_action(UpdateAction::StatusTaskSet(task));
+        }
+    }
+
+    fn toggle_sidebar(&mut self) {
+        self.sidebar.is_visible = !self.sidebar.is_visible;
+        self.sidebar.is_focused = self.sidebar.is_visible;
+        if !self.sidebar.is_visible && !self.table_manager.facets.is_empty() {
+            // Hidden facets would keep filtering without any sign of it.
+            self.table_manager.facets.clear();
+            self.apply_facets();
+        }
+        CTX.send_action(Action::Render);
+    }
+
+    fn apply_facets(&mut self) {
+        self.table_manager.refilter();
+        self.table_manager.table.state.borrow_mut().select(Some(0));
+        self.sidebar
+            .update(&self.table_manager.table.items, &self.table_manager.facets);
+        self.bottom_stats
+            .update_selected_indicator(&self.table_manager);
+    }
+
+    fn cycle_view(&mut self) {
+        let next = match self.active_view {
+            None => Some(0),
+            Some(idx) if idx + 1 < self.views.len() => Some(idx + 1),
+            Some(_) => None,
+        };
+        self.switch_view(next);
+    }
+
+    /// Switches to the view at `idx`, or back to the config defaults on `None`.
+    fn switch_view(&mut self, idx: Option<usize>) {
+        let view = idx.and_then(|idx| self.views.get(idx));
+
+        let headers = view
+            .and_then(|view| view.headers.clone())
+            .unwrap_or_else(|| self.default_headers.clone());
+        let (sort, sort_reverse) = match view.filter(|view| !view.sort.is_empty()) {
+            Some(view) => (view.sort.clone(), view.sort_reverse),
+            None => (
+                CONFIG.torrents_tab.default_sort.clone(),
+                CONFIG.torrents_tab.default_sort_reverse,
+            ),
+        };
+        let filter = view.map(|view| view.filter.clone()).unwrap_or_default();
+        let name = view.map(|view| view.name.clone());
+
+        if let Err(err) = Query::parse(&filter) {
+            let err_msg = ErrorMessage::new(
+                "Invalid view filter",
+                format!(
+                    "Filter of the \"{}\" view can't be parsed, it's used as plain text instead",
+                    name.as_deref().unwrap_or_default()
+                ),
+                Box::new(err),
+            );
+            CTX.send_update_action(UpdateAction::Error(Box::new(err_msg)));
+        }
+
+        self.table_manager.set_headers(headers);
+        self.table_manager.set_default_sort(sort, sort_reverse);
+        if filter.is_empty() {
+            self.table_manager.clear_filter();
+        } else {
+            self.table_manager.set_filter(filter);
+        }
+        self.table_manager.table.state.borrow_mut().select(Some(0));
+        self.bottom_stats
+            .update_selected_indicator(&self.table_manager);
+
+        self.active_view = idx.filter(|_| name.is_some());
+        *ACTIVE_VIEW.write().unwrap() = name.clone();
+
+        let task = StatusTask::new_view(name.unwrap_or_else(|| "default".to_string()));
+        CTX.send_update_action(UpdateAction::StatusTaskSetSuccess(task));
+    }
+
+    fn save_view(&mut self, name: String) {
+        let (sort, sort_reverse) = self.table_manager.current_sort();
+        let view = View {
+            name,
+            filter: self
+                .table_manager
+                .filter
+                .as_ref()
+                .map(|filter| filter.pattern.clone())
+                .unwrap_or_default(),
+            sort,
+            sort_reverse,
+            headers: Some(self.table_manager.headers().clone()),
+        };
+
+        match view.append_to_config(CONFIG.directories.main_path) {
+            Ok(()) => {
+                *ACTIVE_VIEW.write().unwrap() = Some(view.name.clone());
+                self.views.push(view);
+                self.active_view = Some(self.views.len() - 1);
+                CTX.send_update_action(UpdateAction::StatusTaskSuccess);
+            }
+            Err(err) => {
+                let desc = format!(
+                    "Encountered an error while saving the \"{}\" view to {:?}",
+                    view.name, CONFIG.directories.main_path
+                );
+                let err_msg = ErrorMessage::new("Failed to save a view", desc, err.into());
+                CTX.send_update_action(UpdateAction::StatusTaskFailure);
+                CTX.send_update_action(UpdateAction::Error(Box::new(err_msg)));
+            }
+        }
+    }
+
+    /// Writes the columns into the config, to the active view if it has its own.
+    fn save_columns(&mut self) {
+        let headers = self.table_manager.headers().clone();
+        let view = self
+            .active_view
+            .and_then(|idx| self.views.get_mut(idx))
+            .filter(|view| view.headers.is_some());
+        let view_name = view.as_ref().map(|view| view.name.clone());
+
+        let result = TorrentsTabConfig::write_columns(
+            CONFIG.directories.main_path,
+            view_name.as_deref(),
+            &headers,
+            self.table_manager.column_widths(),
+        );
+
+        match result {
+            Ok(()) => {
+                match view {
+                    Some(view) => view.headers = Some(headers),
+                    None => self.default_headers = headers,
+                }
+                let what = view_name.unwrap_or_else(|| "default".to_string());
+                let task = StatusTask::new_save_columns(what);
+                CTX.send_update_action(UpdateAction::StatusTaskSetSuccess(task));
+            }
+            Err(err) => {
+                let desc = format!(
+                    "Encountered an error while saving the columns to {:?}",
+                    CONFIG.directories.main_path
+                );
+                let err_msg = ErrorMessage::new("Failed to save columns", desc, err.into());
+                CTX.send_update_action(UpdateAction::Error(Box::new(err_msg)));
+            }
+        }
+    }
+
+    fn move_in_queue(&mut self, queue_move: QueueMove) {
+        if let Some(selection) = self.get_currently_selected() {
+            CTX.send_torrent_action(TorrentAction::QueueMove(queue_move, selection.ids()));
+        }
+    }
+
     fn xdg_open_current_torrent(&mut self) {
         if let Some(torrent) = self.table_manager.current_torrent() {
             let torrent_location = torrent.torrent_location();
@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    prelude::*,
+    widgets::{Cell, Clear, Paragraph, Row, Table, TableState},
+};
+use rm_config::{
+    keymap::{actions::torrents_tab_columns::TorrentsColumnsAction, GeneralAction},
+    CONFIG,
+};
+use rm_shared::{
+    action::{Action, UpdateAction},
+    header::Header,
+};
+
+use crate::tui::{
+    components::{keybinding_style, popup_block, Component, ComponentAction},
+    ctx::CTX,
+};
+
+/// Lets the user show, hide, reorder and resize the columns of the torrents
+/// table. Every change is applied right away, but only saved on confirm.
+pub struct ColumnsPopup {
+    /// Every header along with whether it's shown, in display order.
+    columns: Vec<(Header, bool)>,
+    widths: HashMap<Header, u16>,
+    initial_headers: Vec<Header>,
+    initial_widths: HashMap<Header, u16>,
+    table_state: TableState,
+}
+
+impl ColumnsPopup {
+    pub fn new(headers: Vec<Header>, widths: HashMap<Header, u16>) -> Self {
+        let columns = headers
+            .iter()
+            .map(|header| (*header, true))
+            .chain(
+                Header::ALL
+                    .into_iter()
+                    .filter(|header| !headers.contains(header))
+                    .map(|header| (header, false)),
+            )
+            .collect();
+
+        Self {
+            columns,
+            widths: widths.clone(),
+            initial_headers: headers,
+            initial_widths: widths,
+            table_state: TableState::default().with_selected(Some(0)),
+        }
+    }
+
+    fn headers(&self) -> Vec<Header> {
+        self.columns
+            .iter()
+            .filter(|(_, is_shown)| *is_shown)
+            .map(|(header, _)| *header)
+            .collect()
+    }
+
+    fn apply(&self) {
+        CTX.send_update_action(UpdateAction::SetColumns(
+            self.headers(),
+            self.widths.clone(),
+        ));
+        CTX.send_action(Action::Render);
+    }
+
+    fn toggle(&mut self, idx: usize) {
+        let shown_count = self.columns.iter().filter(|(_, shown)| *shown).count();
+        let (_, is_shown) = &mut self.columns[idx];
+        // The table needs at least one column.
+        if *is_shown && shown_count == 1 {
+            return;
+        }
+        *is_shown = !*is_shown;
+        self.apply();
+    }
+
+    fn move_column(&mut self, idx: usize, up: bool) {
+        let new_idx = if up {
+            idx.checked_sub(1)
+        } else {
+            Some(idx + 1).filter(|new_idx| *new_idx < self.columns.len())
+        };
+
+        if let Some(new_idx) = new_idx {
+            self.columns.swap(idx, new_idx);
+            self.table_state.select(Some(new_idx));
+            self.apply();
+        }
+    }
+
+    fn resize(&mut self, idx: usize, grow: bool) {
+        let header = self.columns[idx].0;
+        let width = self
+            .widths
+            .get(&header)
+            .copied()
+            .unwrap_or_else(|| default_width(header));
+        let width = if grow {
+            width.saturating_add(1)
+        } else {
+            width.saturating_sub(1).max(1)
+        };
+        self.widths.insert(header, width);
+        self.apply();
+    }
+
+    fn reset_width(&mut self, idx: usize) {
+        if self.widths.remove(&self.columns[idx].0).is_some() {
+            self.apply();
+        }
+    }
+
+    fn hints() -> Vec<Line<'static>> {
+        let general = |action| {
+            CONFIG
+                .keybindings
+                .general
+                .get_keys_for_action_joined(action)
+        };
+        let columns = |action| {
+            CONFIG
+                .keybindings
+                .torrents_tab_columns
+                .get_keys_for_action_joined(action)
+        };
+
+        let lines = [
+            [
+                (general(GeneralAction::Select), " - show/hide | "),
+                (columns(TorrentsColumnsAction::MoveColumnUp), " / "),
+                (
+                    columns(TorrentsColumnsAction::MoveColumnDown),
+                    " - move up/down",
+                ),
+            ],
+            [
+                (columns(TorrentsColumnsAction::NarrowColumn), " / "),
+                (
+                    columns(TorrentsColumnsAction::WidenColumn),
+                    " - narrower/wider | ",
+                ),
+                (
+                    columns(TorrentsColumnsAction::ResetColumnWidth),
+                    " - reset width",
+                ),
+            ],
+            [
+                (general(GeneralAction::Confirm), " - save | "),
+                (general(GeneralAction::Close), " - discard"),
+                (None, ""),
+            ],
+        ];
+
+        lines
+            .into_iter()
+            .map(|line| {
+                let mut spans = vec![];
+                for (keys, desc) in line {
+                    if let Some(keys) = keys {
+                        spans.push(Span::styled(keys, keybinding_style()));
+                        spans.push(Span::raw(desc));
+                    }
+                }
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+fn default_width(header: Header) -> u16 {
+    match header.default_constraint() {
+        Constraint::Length(width) | Constraint::Max(width) | Constraint::Min(width) => width,
+        _ => 10,
+    }
+}
+
+impl Component for ColumnsPopup {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        if action.is_soft_quit() {
+            CTX.send_update_action(UpdateAction::SetColumns(
+                self.initial_headers.clone(),
+                self.initial_widths.clone(),
+            ));
+            return ComponentAction::Quit;
+        }
+
+        let Some(selected) = self.table_state.selected() else {
+            return ComponentAction::Nothing;
+        };
+
+        match action {
+            Action::Up => {
+                self.table_state.select_previous();
+                CTX.send_action(Action::Render);
+            }
+            Action::Down if selected + 1 < self.columns.len() => {
+                self.table_state.select_next();
+                CTX.send_action(Action::Render);
+            }
+            Action::Select => self.toggle(selected),
+            Action::MoveColumnUp => self.move_column(selected, true),
+            Action::MoveColumnDown => self.move_column(selected, false),
+            Action::NarrowColumn => self.resize(selected, false),
+            Action::WidenColumn => self.resize(selected, true),
+            Action::ResetColumnWidth => self.reset_width(selected),
+            Action::Confirm => {
+                CTX.send_update_action(UpdateAction::SaveColumns);
+                return ComponentAction::Quit;
+            }
+            _ => (),
+        }
+
+        ComponentAction::Nothing
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let hints = if CONFIG.general.beginner_mode {
+            Self::hints()
+        } else {
+            vec![]
+        };
+
+        let width = 56;
+        let height = u16::try_from(self.columns.len() + hints.len() + 3).unwrap_or(u16::MAX);
+
+        let [block_rect] = Layout::horizontal([Constraint::Length(width)])
+            .flex(layout::Flex::Center)
+            .areas(rect);
+        let [block_rect] = Layout::vertical([Constraint::Max(height)])
+            .flex(layout::Flex::Center)
+            .areas(block_rect);
+
+        let block = popup_block(" Columns ");
+        let inner_rect = block_rect.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        });
+        let hints_height = u16::try_from(hints.len()).unwrap_or(u16::MAX);
+        let [table_rect, hints_rect] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(hints_height)])
+                .areas(inner_rect);
+
+        let rows = self.columns.iter().map(|(header, is_shown)| {
+            let checkbox = if *is_shown { "[x]" } else { "[ ]" };
+            let width = match self.widths.get(header) {
+                Some(width) => Cell::from(width.to_string()),
+                None => Cell::from(format!("auto ({})", default_width(*header))).dark_gray(),
+            };
+            let row = Row::new([
+                Cell::from(checkbox),
+                Cell::from(format!("{header:?}")),
+                width,
+            ]);
+            if *is_shown {
+                row
+            } else {
+                row.dark_gray()
+            }
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(10),
+            ],
+        )
+        .header(Row::new(["", "Header", "Width"]).bold())
+        .row_highlight_style(
+            Style::default()
+                .fg(CONFIG.general.accent_color)
+                .bg(Color::Black)
+                .bold(),
+        );
+
+        f.render_widget(Clear, block_rect);
+        f.render_widget(block, block_rect);
+        f.render_stateful_widget(table, table_rect, &mut self.table_state);
+        f.render_widget(Paragraph::new(hints), hints_rect);
+    }
+}
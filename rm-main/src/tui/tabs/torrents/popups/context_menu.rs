@@ -0,0 +1,170 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Clear, List, ListState},
+};
+use rm_config::{
+    keymap::{GeneralAction, TorrentsAction},
+    CONFIG,
+};
+use rm_shared::action::Action;
+
+use crate::tui::{
+    components::{is_left_click, keybinding_style, popup_block, Component, ComponentAction},
+    ctx::CTX,
+};
+
+const TORRENT_ACTIONS: [(&str, TorrentsAction); 12] = [
+    ("Pause / resume", TorrentsAction::Pause),
+    ("Start now", TorrentsAction::StartNow),
+    ("Verify", TorrentsAction::Verify),
+    ("Reannounce", TorrentsAction::Reannounce),
+    ("Move", TorrentsAction::MoveTorrent),
+    ("Rename", TorrentsAction::Rename),
+    ("Change category", TorrentsAction::ChangeCategory),
+    ("Files", TorrentsAction::ShowFiles),
+    ("Peers", TorrentsAction::ShowPeers),
+    ("Trackers", TorrentsAction::ShowTrackers),
+    ("Options", TorrentsAction::ShowOptions),
+    ("Delete", TorrentsAction::Delete),
+];
+
+struct Entry {
+    name: &'static str,
+    keys: Option<String>,
+    action: Action,
+}
+
+/// Torrent actions shown where the torrents table was right-clicked.
+pub struct ContextMenuPopup {
+    entries: Vec<Entry>,
+    position: Position,
+    list_state: ListState,
+    area: Rect,
+}
+
+impl ContextMenuPopup {
+    pub fn new(position: Position) -> Self {
+        let details = Entry {
+            name: "Details",
+            keys: CONFIG
+                .keybindings
+                .general
+                .get_keys_for_action_joined(GeneralAction::Confirm),
+            action: Action::Confirm,
+        };
+
+        let entries = std::iter::once(details)
+            .chain(TORRENT_ACTIONS.into_iter().map(|(name, action)| {
+                Entry {
+                    name,
+                    keys: CONFIG
+                        .keybindings
+                        .torrents_tab
+                        .get_keys_for_action_joined(action),
+                    action: action.into(),
+                }
+            }))
+            .collect();
+
+        Self {
+            entries,
+            position,
+            list_state: ListState::default().with_selected(Some(0)),
+            area: Rect::default(),
+        }
+    }
+
+    fn run(&self, idx: usize) -> ComponentAction {
+        if let Some(entry) = self.entries.get(idx) {
+            CTX.send_action(entry.action.clone());
+        }
+        ComponentAction::Quit
+    }
+}
+
+impl Component for ContextMenuPopup {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        if action.is_soft_quit() {
+            return ComponentAction::Quit;
+        }
+
+        match action {
+            Action::Up => {
+                self.list_state.select_previous();
+                CTX.send_action(Action::Render);
+                ComponentAction::Nothing
+            }
+            Action::Down => {
+                self.list_state.select_next();
+                CTX.send_action(Action::Render);
+                ComponentAction::Nothing
+            }
+            Action::Confirm => match self.list_state.selected() {
+                Some(idx) => self.run(idx),
+                None => ComponentAction::Quit,
+            },
+            Action::Click(event) => {
+                let list_area = self.area.inner(Margin::new(1, 1));
+                let position = Position::new(event.column, event.row);
+
+                if is_left_click(event) && list_area.contains(position) {
+                    let idx = self.list_state.offset() + usize::from(position.y - list_area.y);
+                    self.run(idx)
+                } else if self.area.contains(position) {
+                    ComponentAction::Nothing
+                } else {
+                    ComponentAction::Quit
+                }
+            }
+            _ => ComponentAction::Nothing,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let keys_width = self
+            .entries
+            .iter()
+            .filter_map(|entry| entry.keys.as_ref())
+            .map(|keys| keys.chars().count())
+            .max()
+            .unwrap_or(0);
+        let name_width = self
+            .entries
+            .iter()
+            .map(|entry| entry.name.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let width = u16::try_from(name_width + keys_width + 6).unwrap_or(u16::MAX);
+        let height = u16::try_from(self.entries.len() + 2).unwrap_or(u16::MAX);
+
+        // Opens at the click, moved back in if it'd overflow.
+        let x = self.position.x.min(rect.right().saturating_sub(width));
+        let y = self.position.y.min(rect.bottom().saturating_sub(height));
+        self.area = Rect::new(x, y, width, height).intersection(rect);
+
+        let items = self.entries.iter().map(|entry| {
+            let padding = " ".repeat(name_width - entry.name.chars().count() + 2);
+            let keys = entry.keys.clone().unwrap_or_default();
+            Line::from(vec![
+                Span::raw(format!(" {}{padding}", entry.name)),
+                Span::styled(keys, keybinding_style()),
+            ])
+        });
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .fg(CONFIG.general.accent_color)
+                .bg(Color::Black)
+                .bold(),
+        );
+
+        f.render_widget(Clear, self.area);
+        f.render_widget(popup_block(""), self.area);
+        f.render_stateful_widget(
+            list,
+            self.area.inner(Margin::new(1, 1)),
+            &mut self.list_state,
+        );
+    }
+}
@@ -1,25 +1,35 @@
 use intuitils::popup::centered_rect;
 use ratatui::{
     prelude::*,
-    widgets::{block::Title, Block, BorderType, Clear, Paragraph, Wrap},
+    widgets::{
+        block::{Position, Title},
+        Block, BorderType, Clear, Paragraph, Wrap,
+    },
 };
 use rm_config::{keymap::TorrentsAction, CONFIG};
 use rm_shared::{action::Action, utils::bytes_to_human_format};
 use style::Styled;
 
 use crate::tui::{
-    components::{keybinding_style, popup_close_button_highlight, Component, ComponentAction},
+    components::{
+        keybinding_style, popup_close_button_clicked, popup_close_button_highlight, Component,
+        ComponentAction,
+    },
     ctx::CTX,
     tabs::torrents::rustmission_torrent::{CategoryType, RustmissionTorrent},
 };
 
 pub struct DetailsPopup {
     torrent: RustmissionTorrent,
+    block_rect: Rect,
 }
 
 impl DetailsPopup {
     pub fn new(torrent: RustmissionTorrent) -> Self {
-        Self { torrent }
+        Self {
+            torrent,
+            block_rect: Rect::default(),
+        }
     }
 }
 
@@ -28,6 +38,11 @@ impl Component for DetailsPopup {
         match action {
             _ if action.is_soft_quit() => ComponentAction::Quit,
             Action::Confirm => ComponentAction::Quit,
+            Action::Click(event)
+                if popup_close_button_clicked(self.block_rect, Position::Bottom, event) =>
+            {
+                ComponentAction::Quit
+            }
             Action::Delete => {
                 CTX.send_action(Action::Delete);
                 ComponentAction::Quit
@@ -36,6 +51,18 @@ impl Component for DetailsPopup {
                 CTX.send_action(Action::ShowFiles);
                 ComponentAction::Quit
             }
+            Action::ShowPeers => {
+                CTX.send_action(Action::ShowPeers);
+                ComponentAction::Quit
+            }
+            Action::ShowTrackers => {
+                CTX.send_action(Action::ShowTrackers);
+                ComponentAction::Quit
+            }
+            Action::ShowOptions => {
+                CTX.send_action(Action::ShowOptions);
+                ComponentAction::Quit
+            }
             Action::Rename => {
                 CTX.send_action(Action::Rename);
                 ComponentAction::Quit
@@ -56,6 +83,7 @@ impl Component for DetailsPopup {
         let popup_rect = centered_rect(rect, 50, 50);
         let block_rect = popup_rect.inner(Margin::new(1, 1));
         let text_rect = block_rect.inner(Margin::new(3, 2));
+        self.block_rect = block_rect;
 
         let title_style = Style::default().fg(CONFIG.general.accent_color);
         let block = Block::bordered()
@@ -69,7 +97,7 @@ impl Component for DetailsPopup {
 
         let directory_line = Line::from(format!("Directory: {}", self.torrent.download_dir));
 
-        let uploaded_line = Line::from(format!("Total uploaded: {}", self.torrent.uploaded_ever));
+        let uploaded_line = Line::from(format!("Total uploaded: {}", self.torrent.uploaded_ever()));
 
         let peers_line = Line::from(format!("Peers connected: {}", self.torrent.peers_connected));
 
@@ -95,6 +123,39 @@ impl Component for DetailsPopup {
             keybinding_style(),
         ));
 
+        let mut show_peers_line = Line::default();
+        show_peers_line.push_span(Span::raw("Show peers: "));
+        show_peers_line.push_span(Span::styled(
+            CONFIG
+                .keybindings
+                .torrents_tab
+                .get_keys_for_action_joined(TorrentsAction::ShowPeers)
+                .unwrap_or_default(),
+            keybinding_style(),
+        ));
+
+        let mut show_trackers_line = Line::default();
+        show_trackers_line.push_span(Span::raw("Show trackers: "));
+        show_trackers_line.push_span(Span::styled(
+            CONFIG
+                .keybindings
+                .torrents_tab
+                .get_keys_for_action_joined(TorrentsAction::ShowTrackers)
+                .unwrap_or_default(),
+            keybinding_style(),
+        ));
+
+        let mut show_options_line = Line::default();
+        show_options_line.push_span(Span::raw("Edit options: "));
+        show_options_line.push_span(Span::styled(
+            CONFIG
+                .keybindings
+                .torrents_tab
+                .get_keys_for_action_joined(TorrentsAction::ShowOptions)
+                .unwrap_or_default(),
+            keybinding_style(),
+        ));
+
         let mut move_location_line = Line::default();
         move_location_line.push_span(Span::raw("Move location: "));
         move_location_line.push_span(Span::styled(
@@ -172,6 +233,9 @@ impl Component for DetailsPopup {
         lines.push(padding_line);
         lines.push(delete_line);
         lines.push(show_files_line);
+        lines.push(show_peers_line);
+        lines.push(show_trackers_line);
+        lines.push(show_options_line);
         lines.push(rename_line);
         lines.push(move_location_line);
         lines.push(change_category_line);
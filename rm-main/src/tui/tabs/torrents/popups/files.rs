@@ -1,10 +1,11 @@
 use std::{collections::BTreeMap, time::Duration};
 
+use crossterm::event::MouseEvent;
 use intuitils::{error_message::ErrorMessage, popup::popup_rects};
 use ratatui::{
     prelude::*,
     style::Styled,
-    widgets::{Clear, List, ListState, Paragraph},
+    widgets::{block::Position, Clear, List, ListState, Paragraph},
 };
 use rm_config::{
     keymap::{actions::torrents_tab_file_viewer::TorrentsFileViewerAction, GeneralAction},
@@ -18,8 +19,8 @@ use crate::{
     transmission::TorrentAction,
     tui::{
         components::{
-            keybinding_style, popup_block, popup_close_button, popup_close_button_highlight,
-            Component, ComponentAction,
+            keybinding_style, popup_block, popup_close_button, popup_close_button_clicked,
+            popup_close_button_highlight, Component, ComponentAction,
         },
         ctx::CTX,
     },
@@ -56,47 +57,28 @@ impl Component for PriorityPopup {
             Action::Up => {
                 self.list_state.select_previous();
                 CTX.send_action(Action::Render);
-                return ComponentAction::Nothing;
+                ComponentAction::Nothing
             }
             Action::Down => {
                 self.list_state.select_next();
                 CTX.send_action(Action::Render);
-                return ComponentAction::Nothing;
+                ComponentAction::Nothing
             }
             Action::Confirm => {
-                let torrent_id = match self.torrent_id {
-                    Id::Id(id) => id,
-                    Id::Hash(_) => unreachable!(),
-                };
-
                 let args = match self.list_state.selected().unwrap() {
-                    0 => {
-                        let args = TorrentSetArgs::new().priority_low(self.files.clone());
-                        CTX.send_torrent_action(TorrentAction::SetArgs(
-                            Box::new(args),
-                            Some(vec![self.torrent_id.clone()]),
-                        ));
-                    }
-                    1 => {
-                        let args = TorrentSetArgs::new().priority_normal(self.files.clone());
-                        CTX.send_torrent_action(TorrentAction::SetArgs(
-                            Box::new(args),
-                            Some(vec![self.torrent_id.clone()]),
-                        ));
-                    }
-                    2 => {
-                        let args = TorrentSetArgs::new().priority_high(self.files.clone());
-                        CTX.send_torrent_action(TorrentAction::SetArgs(
-                            Box::new(args),
-                            Some(vec![self.torrent_id.clone()]),
-                        ));
-                    }
+                    0 => TorrentSetArgs::new().priority_low(self.files.clone()),
+                    1 => TorrentSetArgs::new().priority_normal(self.files.clone()),
+                    2 => TorrentSetArgs::new().priority_high(self.files.clone()),
                     _ => unreachable!(),
                 };
+                CTX.send_torrent_action(TorrentAction::SetArgs(
+                    Box::new(args),
+                    Some(vec![self.torrent_id.clone()]),
+                ));
                 CTX.send_action(Action::Render);
-                return ComponentAction::Quit;
+                ComponentAction::Quit
             }
-            _ => return ComponentAction::Nothing,
+            _ => ComponentAction::Nothing,
         }
     }
 
@@ -144,6 +126,7 @@ pub struct FilesPopup {
     current_focus: CurrentFocus,
     switched_after_fetched_data: bool,
     torrent_info_task_handle: JoinHandle<()>,
+    block_rect: Rect,
 }
 
 async fn fetch_new_files(torrent_id: Id) {
@@ -154,15 +137,19 @@ async fn fetch_new_files(torrent_id: Id) {
             torrent_tx,
         ));
 
-        match torrent_rx.await.unwrap() {
-            Ok(mut torrents) => {
-                CTX.send_update_action(UpdateAction::UpdateCurrentTorrent(Box::new(
-                    torrents.pop().unwrap(),
-                )));
+        // The sender is dropped if the profile got switched in the meantime.
+        match torrent_rx.await {
+            Ok(Ok(mut torrents)) => {
+                let Some(torrent) = torrents.pop() else {
+                    // The torrent got removed in the meantime.
+                    return;
+                };
+                CTX.send_update_action(UpdateAction::UpdateCurrentTorrent(Box::new(torrent)));
             }
-            Err(err_message) => {
+            Ok(Err(err_message)) => {
                 CTX.send_update_action(UpdateAction::Error(err_message));
             }
+            Err(_) => return,
         };
 
         tokio::time::sleep(Duration::from_secs(6)).await;
@@ -192,6 +179,7 @@ impl FilesPopup {
             torrent_id,
             torrent_info_task_handle,
             priority_popup: None,
+            block_rect: Rect::default(),
         }
     }
 
@@ -202,6 +190,16 @@ impl FilesPopup {
         }
     }
 
+    fn close_button_clicked(&self, event: MouseEvent) -> bool {
+        // The close button moves to the bottom once the files are loaded.
+        let position = if self.torrent.is_some() {
+            Position::Bottom
+        } else {
+            Position::Top
+        };
+        popup_close_button_clicked(self.block_rect, position, event)
+    }
+
     fn selected_ids(&self) -> Vec<usize> {
         self.tree_state
             .selected()
@@ -211,19 +209,34 @@ impl FilesPopup {
     }
 }
 
+// Switching profiles closes the popup without it quitting, so the fetching task
+// would otherwise keep polling the new daemon for this torrent's files.
+impl Drop for FilesPopup {
+    fn drop(&mut self) {
+        self.torrent_info_task_handle.abort();
+    }
+}
+
 impl Component for FilesPopup {
-    #[must_use]
     fn handle_actions(&mut self, action: Action) -> ComponentAction {
         use Action as A;
 
-        match (&mut self.priority_popup, action, self.current_focus) {
-            (Some(priority_popup), action, _) => {
-                if priority_popup.handle_actions(action).is_quit() {
-                    self.priority_popup = None;
-                    CTX.send_action(A::Render);
-                    return ComponentAction::Nothing;
-                }
+        if let Some(priority_popup) = &mut self.priority_popup {
+            if priority_popup.handle_actions(action).is_quit() {
+                self.priority_popup = None;
+                CTX.send_action(A::Render);
+            }
+            return ComponentAction::Nothing;
+        }
+
+        if let A::Click(event) = action {
+            if self.close_button_clicked(event) {
+                self.torrent_info_task_handle.abort();
+                return ComponentAction::Quit;
             }
+        }
+
+        match (&mut self.priority_popup, action, self.current_focus) {
             (_, action, _) if action.is_soft_quit() => {
                 self.torrent_info_task_handle.abort();
                 return ComponentAction::Quit;
@@ -244,15 +257,8 @@ impl Component for FilesPopup {
                 return ComponentAction::Quit;
             }
             (None, A::Select | A::Confirm, CurrentFocus::Files) => {
-                if self.torrent.is_some() {
-                    let mut wanted_ids = self
-                        .torrent
-                        .as_ref()
-                        .unwrap()
-                        .wanted
-                        .as_ref()
-                        .unwrap()
-                        .clone();
+                if let Some(torrent) = &self.torrent {
+                    let mut wanted_ids = torrent.wanted.as_ref().unwrap().clone();
 
                     let selected_ids = self.selected_ids();
 
@@ -264,7 +270,7 @@ impl Component for FilesPopup {
 
                     let mut wanted_in_selection_no = 0;
                     for selected_id in &selected_ids {
-                        if wanted_ids[*selected_id as usize] {
+                        if wanted_ids[*selected_id] {
                             wanted_in_selection_no += 1;
                         } else {
                             wanted_in_selection_no -= 1;
@@ -273,11 +279,11 @@ impl Component for FilesPopup {
 
                     if wanted_in_selection_no > 0 {
                         for selected_id in &selected_ids {
-                            wanted_ids[*selected_id as usize] = false;
+                            wanted_ids[*selected_id] = false;
                         }
                     } else {
                         for selected_id in &selected_ids {
-                            wanted_ids[*selected_id as usize] = true;
+                            wanted_ids[*selected_id] = true;
                         }
                     }
 
@@ -360,6 +366,7 @@ impl Component for FilesPopup {
 
     fn render(&mut self, f: &mut Frame, rect: Rect) {
         let (popup_rect, block_rect, text_rect) = popup_rects(rect, 75, 75);
+        self.block_rect = block_rect;
 
         let highlight_style = Style::default().fg(CONFIG.general.accent_color);
         let bold_highlight_style = highlight_style.on_black().bold();
@@ -438,7 +445,7 @@ impl Component for FilesPopup {
                         .into_right_aligned_line(),
                 )
                 .title_bottom(close_button)
-                .title_bottom(Line::from(keybinding_tip).left_aligned());
+                .title_bottom(keybinding_tip.left_aligned());
 
             let tree_items = self.tree.make_tree();
 
@@ -506,9 +513,9 @@ impl Node {
         for (id, file) in files.iter().enumerate() {
             let path: Vec<String> = file.name.split('/').map(str::to_string).collect();
 
-            let wanted = torrent.wanted.as_ref().unwrap()[id] != false;
+            let wanted = torrent.wanted.as_ref().unwrap()[id];
 
-            let priority = torrent.priorities.as_ref().unwrap()[id].clone();
+            let priority = torrent.priorities.as_ref().unwrap()[id];
 
             let file = TransmissionFile {
                 id,
@@ -543,7 +550,7 @@ impl Node {
     fn get_by_ids(&mut self, ids: &[usize]) -> Vec<&mut TransmissionFile> {
         let mut transmission_files = vec![];
         for file in &mut self.items {
-            if ids.contains(&(file.id as usize)) {
+            if ids.contains(&file.id) {
                 transmission_files.push(file);
             }
         }
@@ -553,7 +560,7 @@ impl Node {
         transmission_files
     }
 
-    fn make_tree(&self) -> Vec<TreeItem<String>> {
+    fn make_tree(&self) -> Vec<TreeItem<'_, String>> {
         let mut tree_items = vec![];
         for transmission_file in &self.items {
             let mut name = Line::default();
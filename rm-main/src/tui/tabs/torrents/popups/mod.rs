@@ -3,7 +3,11 @@ use crate::tui::{
     ctx::CTX,
 };
 
-use self::{files::FilesPopup, stats::StatisticsPopup};
+use self::{
+    columns::ColumnsPopup, context_menu::ContextMenuPopup, files::FilesPopup,
+    options::OptionsPopup, peers::PeersPopup, stats::StatisticsPopup, trackers::TrackersPopup,
+    views::ViewsPopup,
+};
 use details::DetailsPopup;
 use rm_shared::{
     action::{Action, UpdateAction},
@@ -12,9 +16,16 @@ use rm_shared::{
 
 use ratatui::prelude::*;
 
+pub mod columns;
+pub mod context_menu;
 pub mod details;
 pub mod files;
+pub mod options;
+pub mod peers;
 pub mod stats;
+pub mod torrent_preview;
+pub mod trackers;
+pub mod views;
 
 pub struct PopupManager {
     pub current_popup: Option<CurrentPopup>,
@@ -22,8 +33,14 @@ pub struct PopupManager {
 
 pub enum CurrentPopup {
     Stats(StatisticsPopup),
-    Files(FilesPopup),
-    Details(DetailsPopup),
+    Files(Box<FilesPopup>),
+    Peers(Box<PeersPopup>),
+    Trackers(Box<TrackersPopup>),
+    Options(Box<OptionsPopup>),
+    Details(Box<DetailsPopup>),
+    Views(ViewsPopup),
+    Columns(ColumnsPopup),
+    ContextMenu(ContextMenuPopup),
 }
 
 impl PopupManager {
@@ -52,7 +69,13 @@ impl Component for PopupManager {
             let should_close = match current_popup {
                 CurrentPopup::Stats(popup) => popup.handle_actions(action).is_quit(),
                 CurrentPopup::Files(popup) => popup.handle_actions(action).is_quit(),
+                CurrentPopup::Peers(popup) => popup.handle_actions(action).is_quit(),
+                CurrentPopup::Trackers(popup) => popup.handle_actions(action).is_quit(),
+                CurrentPopup::Options(popup) => popup.handle_actions(action).is_quit(),
                 CurrentPopup::Details(popup) => popup.handle_actions(action).is_quit(),
+                CurrentPopup::Views(popup) => popup.handle_actions(action).is_quit(),
+                CurrentPopup::Columns(popup) => popup.handle_actions(action).is_quit(),
+                CurrentPopup::ContextMenu(popup) => popup.handle_actions(action).is_quit(),
             };
 
             if should_close {
@@ -64,8 +87,12 @@ impl Component for PopupManager {
     }
 
     fn handle_update_action(&mut self, action: UpdateAction) {
-        if let Some(CurrentPopup::Files(popup)) = &mut self.current_popup {
-            popup.handle_update_action(action);
+        match &mut self.current_popup {
+            Some(CurrentPopup::Files(popup)) => popup.handle_update_action(action),
+            Some(CurrentPopup::Peers(popup)) => popup.handle_update_action(action),
+            Some(CurrentPopup::Trackers(popup)) => popup.handle_update_action(action),
+            Some(CurrentPopup::Options(popup)) => popup.handle_update_action(action),
+            _ => (),
         }
     }
 
@@ -78,7 +105,13 @@ impl Component for PopupManager {
                 CurrentPopup::Files(popup) => {
                     popup.render(f, rect);
                 }
+                CurrentPopup::Peers(popup) => popup.render(f, rect),
+                CurrentPopup::Trackers(popup) => popup.render(f, rect),
+                CurrentPopup::Options(popup) => popup.render(f, rect),
                 CurrentPopup::Details(popup) => popup.render(f, rect),
+                CurrentPopup::Views(popup) => popup.render(f, rect),
+                CurrentPopup::Columns(popup) => popup.render(f, rect),
+                CurrentPopup::ContextMenu(popup) => popup.render(f, rect),
             }
         }
     }
@@ -0,0 +1,466 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use intuitils::popup::popup_rects;
+use ratatui::{
+    prelude::*,
+    widgets::{block::Position, Clear, List, ListItem, ListState, Paragraph},
+};
+use rm_config::CONFIG;
+use tokio::{sync::oneshot, task::JoinHandle};
+use transmission_rpc::types::{
+    Id, IdleMode, Priority, RatioMode, Torrent, TorrentGetField, TorrentSetArgs,
+};
+
+use crate::{
+    transmission::TorrentAction,
+    tui::{
+        components::{
+            popup_block, popup_close_button_clicked, popup_close_button_highlight, Component,
+            ComponentAction, InputManager,
+        },
+        ctx::CTX,
+        tabs::torrents::tasks::TorrentSelection,
+    },
+};
+use rm_shared::action::{Action, UpdateAction};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TorrentOption {
+    DownloadLimited,
+    DownloadLimit,
+    UploadLimited,
+    UploadLimit,
+    HonorsSessionLimits,
+    SeedRatioMode,
+    SeedRatioLimit,
+    SeedIdleMode,
+    SeedIdleLimit,
+    PeerLimit,
+    BandwidthPriority,
+}
+
+impl TorrentOption {
+    const ALL: [Self; 11] = [
+        Self::DownloadLimited,
+        Self::DownloadLimit,
+        Self::UploadLimited,
+        Self::UploadLimit,
+        Self::HonorsSessionLimits,
+        Self::SeedRatioMode,
+        Self::SeedRatioLimit,
+        Self::SeedIdleMode,
+        Self::SeedIdleLimit,
+        Self::PeerLimit,
+        Self::BandwidthPriority,
+    ];
+
+    const FIELDS: [TorrentGetField; 12] = [
+        TorrentGetField::Id,
+        TorrentGetField::DownloadLimited,
+        TorrentGetField::DownloadLimit,
+        TorrentGetField::UploadLimited,
+        TorrentGetField::UploadLimit,
+        TorrentGetField::HonorsSessionLimits,
+        TorrentGetField::SeedRatioMode,
+        TorrentGetField::SeedRatioLimit,
+        TorrentGetField::SeedIdleMode,
+        TorrentGetField::SeedIdleLimit,
+        TorrentGetField::PeerLimit,
+        TorrentGetField::BandwidthPriority,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::DownloadLimited => "Limit download speed",
+            Self::DownloadLimit => "Download limit (KB/s)",
+            Self::UploadLimited => "Limit upload speed",
+            Self::UploadLimit => "Upload limit (KB/s)",
+            Self::HonorsSessionLimits => "Honor session limits",
+            Self::SeedRatioMode => "Seed ratio mode",
+            Self::SeedRatioLimit => "Seed ratio limit",
+            Self::SeedIdleMode => "Seed idle mode",
+            Self::SeedIdleLimit => "Seed idle limit (minutes)",
+            Self::PeerLimit => "Peer limit",
+            Self::BandwidthPriority => "Bandwidth priority",
+        }
+    }
+
+    const fn is_number(self) -> bool {
+        matches!(
+            self,
+            Self::DownloadLimit
+                | Self::UploadLimit
+                | Self::SeedRatioLimit
+                | Self::SeedIdleLimit
+                | Self::PeerLimit
+        )
+    }
+}
+
+/// Values shared by all of the torrents, `None` if they differ between them.
+#[derive(Default)]
+struct OptionValues {
+    download_limited: Option<bool>,
+    download_limit: Option<u64>,
+    upload_limited: Option<bool>,
+    upload_limit: Option<u64>,
+    honors_session_limits: Option<bool>,
+    seed_ratio_mode: Option<RatioMode>,
+    seed_ratio_limit: Option<f32>,
+    seed_idle_mode: Option<IdleMode>,
+    seed_idle_limit: Option<u64>,
+    peer_limit: Option<u16>,
+    bandwidth_priority: Option<Priority>,
+}
+
+fn common<T: PartialEq>(torrents: &[Torrent], field: impl Fn(&Torrent) -> Option<T>) -> Option<T> {
+    let mut values = torrents.iter().map(field);
+    let first = values.next()??;
+    values
+        .all(|value| value.as_ref() == Some(&first))
+        .then_some(first)
+}
+
+impl OptionValues {
+    fn from_torrents(torrents: &[Torrent]) -> Self {
+        Self {
+            download_limited: common(torrents, |t| t.download_limited),
+            download_limit: common(torrents, |t| t.download_limit),
+            upload_limited: common(torrents, |t| t.upload_limited),
+            upload_limit: common(torrents, |t| t.upload_limit),
+            honors_session_limits: common(torrents, |t| t.honors_session_limits),
+            seed_ratio_mode: common(torrents, |t| t.seed_ratio_mode),
+            seed_ratio_limit: common(torrents, |t| t.seed_ratio_limit),
+            seed_idle_mode: common(torrents, |t| t.seed_idle_mode),
+            seed_idle_limit: common(torrents, |t| t.seed_idle_limit),
+            peer_limit: common(torrents, |t| t.peer_limit),
+            bandwidth_priority: common(torrents, |t| t.bandwidth_priority),
+        }
+    }
+
+    fn value_str(&self, option: TorrentOption) -> String {
+        fn yes_no(value: bool) -> String {
+            if value { "Yes" } else { "No" }.to_string()
+        }
+
+        fn mode(global: bool, single: bool) -> String {
+            match (global, single) {
+                (true, _) => "Global".to_string(),
+                (_, true) => "Single".to_string(),
+                _ => "Unlimited".to_string(),
+            }
+        }
+
+        let value = match option {
+            TorrentOption::DownloadLimited => self.download_limited.map(yes_no),
+            TorrentOption::DownloadLimit => self.download_limit.map(|v| v.to_string()),
+            TorrentOption::UploadLimited => self.upload_limited.map(yes_no),
+            TorrentOption::UploadLimit => self.upload_limit.map(|v| v.to_string()),
+            TorrentOption::HonorsSessionLimits => self.honors_session_limits.map(yes_no),
+            TorrentOption::SeedRatioMode => self
+                .seed_ratio_mode
+                .map(|m| mode(m == RatioMode::Global, m == RatioMode::Single)),
+            TorrentOption::SeedRatioLimit => self.seed_ratio_limit.map(|v| format!("{v:.2}")),
+            TorrentOption::SeedIdleMode => self
+                .seed_idle_mode
+                .map(|m| mode(m == IdleMode::Global, m == IdleMode::Single)),
+            TorrentOption::SeedIdleLimit => self.seed_idle_limit.map(|v| v.to_string()),
+            TorrentOption::PeerLimit => self.peer_limit.map(|v| v.to_string()),
+            TorrentOption::BandwidthPriority => self.bandwidth_priority.map(|p| {
+                match p {
+                    Priority::Low => "Low",
+                    Priority::Normal => "Normal",
+                    Priority::High => "High",
+                }
+                .to_string()
+            }),
+        };
+
+        value.unwrap_or_else(|| "(mixed)".to_string())
+    }
+
+    /// Flips a yes/no option or moves a mode to the next one.
+    fn cycle(&mut self, option: TorrentOption) -> Option<TorrentSetArgs> {
+        let mut args = TorrentSetArgs::default();
+
+        match option {
+            TorrentOption::DownloadLimited => {
+                let new = !self.download_limited.unwrap_or_default();
+                self.download_limited = Some(new);
+                args.download_limited = Some(new);
+            }
+            TorrentOption::UploadLimited => {
+                let new = !self.upload_limited.unwrap_or_default();
+                self.upload_limited = Some(new);
+                args.upload_limited = Some(new);
+            }
+            TorrentOption::HonorsSessionLimits => {
+                let new = !self.honors_session_limits.unwrap_or_default();
+                self.honors_session_limits = Some(new);
+                args.honors_session_limits = Some(new);
+            }
+            TorrentOption::SeedRatioMode => {
+                let new = match self.seed_ratio_mode {
+                    Some(RatioMode::Global) => RatioMode::Single,
+                    Some(RatioMode::Single) => RatioMode::Unlimited,
+                    Some(RatioMode::Unlimited) | None => RatioMode::Global,
+                };
+                self.seed_ratio_mode = Some(new);
+                args.seed_ratio_mode = Some(new);
+            }
+            TorrentOption::SeedIdleMode => {
+                let new = match self.seed_idle_mode {
+                    Some(IdleMode::Global) => IdleMode::Single,
+                    Some(IdleMode::Single) => IdleMode::Unlimited,
+                    Some(IdleMode::Unlimited) | None => IdleMode::Global,
+                };
+                self.seed_idle_mode = Some(new);
+                args.seed_idle_mode = Some(new);
+            }
+            TorrentOption::BandwidthPriority => {
+                let new = match self.bandwidth_priority {
+                    Some(Priority::Low) => Priority::Normal,
+                    Some(Priority::Normal) | None => Priority::High,
+                    Some(Priority::High) => Priority::Low,
+                };
+                self.bandwidth_priority = Some(new);
+                args.bandwidth_priority = Some(new);
+            }
+            _ => return None,
+        }
+
+        Some(args)
+    }
+
+    /// Parses the number typed in for an option, `None` if it's not valid.
+    fn set_number(&mut self, option: TorrentOption, text: &str) -> Option<TorrentSetArgs> {
+        let mut args = TorrentSetArgs::default();
+
+        match option {
+            TorrentOption::DownloadLimit => {
+                let new = text.parse().ok()?;
+                self.download_limit = Some(new as u64);
+                args.download_limit = Some(new);
+            }
+            TorrentOption::UploadLimit => {
+                let new = text.parse().ok()?;
+                self.upload_limit = Some(new as u64);
+                args.upload_limit = Some(new);
+            }
+            TorrentOption::SeedRatioLimit => {
+                let new: f64 = text.parse().ok().filter(|ratio: &f64| *ratio >= 0.0)?;
+                self.seed_ratio_limit = Some(new as f32);
+                args.seed_ratio_limit = Some(new);
+            }
+            TorrentOption::SeedIdleLimit => {
+                let new = text.parse().ok()?;
+                self.seed_idle_limit = Some(u64::from(new));
+                args.seed_idle_limit = Some(new);
+            }
+            TorrentOption::PeerLimit => {
+                let new = text.parse().ok().filter(|limit: &u16| *limit > 0)?;
+                self.peer_limit = Some(new);
+                args.peer_limit = Some(new);
+            }
+            _ => return None,
+        }
+
+        Some(args)
+    }
+}
+
+pub struct OptionsPopup {
+    torrent_ids: Vec<Id>,
+    values: Option<OptionValues>,
+    list_state: ListState,
+    input_mgr: Option<InputManager>,
+    fetch_task_handle: JoinHandle<()>,
+    block_rect: Rect,
+}
+
+async fn fetch_options(torrent_ids: Vec<Id>) {
+    let (torrents_tx, torrents_rx) = oneshot::channel();
+    CTX.send_torrent_action(TorrentAction::GetTorrentFieldsById(
+        torrent_ids,
+        TorrentOption::FIELDS.to_vec(),
+        torrents_tx,
+    ));
+
+    // The sender is dropped if the profile got switched in the meantime.
+    match torrents_rx.await {
+        Ok(Ok(torrents)) => CTX.send_update_action(UpdateAction::UpdateSelectedTorrents(torrents)),
+        Ok(Err(err_message)) => CTX.send_update_action(UpdateAction::Error(err_message)),
+        Err(_) => (),
+    }
+}
+
+impl OptionsPopup {
+    pub fn new(selection: TorrentSelection) -> Self {
+        let torrent_ids = selection.ids();
+        let fetch_task_handle = tokio::task::spawn(fetch_options(torrent_ids.clone()));
+
+        Self {
+            torrent_ids,
+            values: None,
+            list_state: ListState::default().with_selected(Some(0)),
+            input_mgr: None,
+            fetch_task_handle,
+            block_rect: Rect::default(),
+        }
+    }
+
+    fn selected_option(&self) -> TorrentOption {
+        TorrentOption::ALL[self.list_state.selected().unwrap_or_default()]
+    }
+
+    fn apply(&self, args: TorrentSetArgs) {
+        CTX.send_torrent_action(TorrentAction::SetArgs(
+            Box::new(args),
+            Some(self.torrent_ids.clone()),
+        ));
+    }
+
+    fn edit_selected(&mut self) {
+        let option = self.selected_option();
+        let Some(values) = &mut self.values else {
+            return;
+        };
+
+        if option.is_number() {
+            let value = values.value_str(option);
+            let value = if value.starts_with('(') {
+                String::new()
+            } else {
+                value
+            };
+            self.input_mgr = Some(InputManager::new_with_value(
+                format!("{}: ", option.name()),
+                value,
+            ));
+            CTX.send_update_action(UpdateAction::SwitchToInputMode);
+        } else if let Some(args) = values.cycle(option) {
+            self.apply(args);
+        }
+    }
+
+    fn handle_input(&mut self, input: KeyEvent) {
+        let option = self.selected_option();
+        let (Some(input_mgr), Some(values)) = (&mut self.input_mgr, &mut self.values) else {
+            return;
+        };
+
+        match input.code {
+            KeyCode::Enter => {
+                let Some(args) = values.set_number(option, input_mgr.text().trim()) else {
+                    input_mgr.set_prompt(format!("{} (invalid value): ", option.name()));
+                    return;
+                };
+                self.apply(args);
+            }
+            KeyCode::Esc => (),
+            _ => {
+                input_mgr.handle_key(input);
+                return;
+            }
+        }
+
+        self.input_mgr = None;
+        CTX.send_update_action(UpdateAction::SwitchToNormalMode);
+    }
+}
+
+impl Drop for OptionsPopup {
+    fn drop(&mut self) {
+        self.fetch_task_handle.abort();
+        if self.input_mgr.is_some() {
+            CTX.send_update_action(UpdateAction::SwitchToNormalMode);
+        }
+    }
+}
+
+impl Component for OptionsPopup {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        use Action as A;
+
+        if self.input_mgr.is_some() {
+            if let A::Input(input) = action {
+                self.handle_input(input);
+                CTX.send_action(A::Render);
+            }
+            return ComponentAction::Nothing;
+        }
+
+        match action {
+            _ if action.is_soft_quit() => return ComponentAction::Quit,
+            Action::Click(event)
+                if popup_close_button_clicked(self.block_rect, Position::Bottom, event) =>
+            {
+                return ComponentAction::Quit
+            }
+            A::Up => self.list_state.select_previous(),
+            A::Down => self.list_state.select_next(),
+            A::Home => self.list_state.select_first(),
+            A::End => self.list_state.select_last(),
+            A::Confirm | A::Select => self.edit_selected(),
+            _ => return ComponentAction::Nothing,
+        }
+
+        CTX.send_action(Action::Render);
+        ComponentAction::Nothing
+    }
+
+    fn handle_update_action(&mut self, action: UpdateAction) {
+        if let UpdateAction::UpdateSelectedTorrents(torrents) = action {
+            self.values = Some(OptionValues::from_torrents(&torrents));
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let (popup_rect, block_rect, text_rect) = popup_rects(rect, 50, 50);
+        self.block_rect = block_rect;
+
+        let title = if self.torrent_ids.len() > 1 {
+            format!(" Options ({} torrents) ", self.torrent_ids.len())
+        } else {
+            " Options ".to_string()
+        };
+        let block = popup_block(&title).title_bottom(popup_close_button_highlight());
+
+        f.render_widget(Clear, popup_rect);
+
+        let Some(values) = &self.values else {
+            f.render_widget(Paragraph::new("Loading..."), text_rect);
+            f.render_widget(block, block_rect);
+            return;
+        };
+
+        let [list_rect, input_rect] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(text_rect);
+
+        let name_width = TorrentOption::ALL
+            .iter()
+            .map(|option| option.name().len())
+            .max()
+            .unwrap_or_default();
+
+        let items = TorrentOption::ALL.iter().map(|option| {
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{:name_width$}  ", option.name())),
+                Span::raw(values.value_str(*option)).bold(),
+            ]))
+        });
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .fg(CONFIG.general.accent_color)
+                .bg(Color::Black)
+                .bold(),
+        );
+
+        f.render_widget(block, block_rect);
+        f.render_stateful_widget(list, list_rect, &mut self.list_state);
+
+        if let Some(input_mgr) = &mut self.input_mgr {
+            input_mgr.render(f, input_rect);
+        }
+    }
+}
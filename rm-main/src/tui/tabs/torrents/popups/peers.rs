@@ -0,0 +1,318 @@
+use std::{collections::HashMap, time::Duration};
+
+use intuitils::popup::popup_rects;
+use ratatui::{
+    prelude::*,
+    widgets::{block::Position, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+use rm_config::{keymap::GeneralAction, CONFIG};
+use tokio::{sync::oneshot, task::JoinHandle};
+use transmission_rpc::types::{Id, Peer, Torrent, TorrentGetField};
+
+use crate::{
+    transmission::TorrentAction,
+    tui::{
+        components::{
+            keybinding_style, popup_block, popup_close_button_clicked,
+            popup_close_button_highlight, Component, ComponentAction,
+        },
+        ctx::CTX,
+    },
+};
+use rm_shared::{
+    action::{Action, UpdateAction},
+    utils::bytes_to_human_format,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PeersColumn {
+    Address,
+    Client,
+    Flags,
+    Progress,
+    Download,
+    Upload,
+    Encrypted,
+}
+
+impl PeersColumn {
+    const ALL: [Self; 7] = [
+        Self::Address,
+        Self::Client,
+        Self::Flags,
+        Self::Progress,
+        Self::Download,
+        Self::Upload,
+        Self::Encrypted,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Address => "Address",
+            Self::Client => "Client",
+            Self::Flags => "Flags",
+            Self::Progress => "Progress",
+            Self::Download => "Down",
+            Self::Upload => "Up",
+            Self::Encrypted => "Encrypted",
+        }
+    }
+
+    fn width(self) -> Constraint {
+        match self {
+            Self::Address => Constraint::Length(41),
+            Self::Client => Constraint::Min(15),
+            Self::Flags => Constraint::Length(9),
+            Self::Progress => Constraint::Length(8),
+            Self::Download | Self::Upload => Constraint::Length(10),
+            Self::Encrypted => Constraint::Length(9),
+        }
+    }
+
+    fn compare(self, a: &Peer, b: &Peer) -> std::cmp::Ordering {
+        match self {
+            Self::Address => (a.address, a.port).cmp(&(b.address, b.port)),
+            Self::Client => a.client_name.cmp(&b.client_name),
+            Self::Flags => a.flag_str.cmp(&b.flag_str),
+            Self::Progress => a.progress.total_cmp(&b.progress),
+            Self::Download => a.rate_to_client.cmp(&b.rate_to_client),
+            Self::Upload => a.rate_to_peer.cmp(&b.rate_to_peer),
+            Self::Encrypted => a.is_encrypted.cmp(&b.is_encrypted),
+        }
+    }
+
+    fn cell(self, peer: &Peer) -> Cell<'_> {
+        let rate = |rate: u64| match rate {
+            0 => String::default(),
+            rate => bytes_to_human_format(rate as i64),
+        };
+
+        match self {
+            Self::Address => match peer.address {
+                std::net::IpAddr::V4(ip) => Cell::from(format!("{ip}:{}", peer.port)),
+                std::net::IpAddr::V6(ip) => Cell::from(format!("[{ip}]:{}", peer.port)),
+            },
+            Self::Client => Cell::from(peer.client_name.as_str()),
+            Self::Flags => Cell::from(peer.flag_str.as_str()),
+            Self::Progress => Cell::from(format!("{:.1}%", peer.progress * 100.0)),
+            Self::Download => Cell::from(rate(peer.rate_to_client)),
+            Self::Upload => Cell::from(rate(peer.rate_to_peer)),
+            Self::Encrypted => Cell::from(if peer.is_encrypted { "yes" } else { "no" }),
+        }
+    }
+}
+
+pub struct PeersPopup {
+    peers: Option<Vec<Peer>>,
+    table_state: TableState,
+    sort_column: PeersColumn,
+    sort_reverse: bool,
+    fetch_task_handle: JoinHandle<()>,
+    block_rect: Rect,
+}
+
+async fn fetch_peers(torrent_id: Id) {
+    loop {
+        let (torrent_tx, torrent_rx) = oneshot::channel();
+        CTX.send_torrent_action(TorrentAction::GetTorrentFieldsById(
+            vec![torrent_id.clone()],
+            vec![TorrentGetField::Id, TorrentGetField::Peers],
+            torrent_tx,
+        ));
+
+        // The sender is dropped if the profile got switched in the meantime.
+        match torrent_rx.await {
+            Ok(Ok(mut torrents)) => {
+                let Some(torrent) = torrents.pop() else {
+                    // The torrent got removed in the meantime.
+                    return;
+                };
+                CTX.send_update_action(UpdateAction::UpdateCurrentTorrent(Box::new(torrent)));
+            }
+            Ok(Err(err_message)) => {
+                CTX.send_update_action(UpdateAction::Error(err_message));
+            }
+            Err(_) => return,
+        };
+
+        tokio::time::sleep(Duration::from_secs(3)).await;
+    }
+}
+
+impl PeersPopup {
+    pub fn new(torrent_id: Id) -> Self {
+        Self {
+            peers: None,
+            table_state: TableState::default().with_selected(Some(0)),
+            sort_column: PeersColumn::Download,
+            sort_reverse: true,
+            fetch_task_handle: tokio::task::spawn(fetch_peers(torrent_id)),
+            block_rect: Rect::default(),
+        }
+    }
+
+    pub fn update_torrent(&mut self, torrent: &Torrent) {
+        if let Some(peers) = &torrent.peers {
+            self.peers = Some(peers.clone());
+            self.sort();
+        }
+    }
+
+    fn sort(&mut self) {
+        if let Some(peers) = &mut self.peers {
+            peers.sort_by(|a, b| {
+                let ordering = self.sort_column.compare(a, b);
+                if self.sort_reverse {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+    }
+
+    fn move_sort_column(&mut self, by: isize) {
+        let current = PeersColumn::ALL
+            .iter()
+            .position(|column| *column == self.sort_column)
+            .expect("all columns listed");
+        let len = PeersColumn::ALL.len() as isize;
+        self.sort_column = PeersColumn::ALL[(current as isize + by).rem_euclid(len) as usize];
+        self.sort();
+    }
+
+    fn summary(peers: &[Peer]) -> Line<'static> {
+        let mut clients: HashMap<&str, usize> = HashMap::new();
+        for peer in peers {
+            let client = peer
+                .client_name
+                .split_whitespace()
+                .next()
+                .unwrap_or("Unknown");
+            *clients.entry(client).or_default() += 1;
+        }
+
+        let mut clients: Vec<_> = clients.into_iter().collect();
+        clients.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+        let encrypted = peers.iter().filter(|peer| peer.is_encrypted).count();
+        let incoming = peers.iter().filter(|peer| peer.is_incoming).count();
+
+        let clients = clients
+            .iter()
+            .take(5)
+            .map(|(client, count)| format!("{client} {count}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Line::from(format!(
+            " {} peers ({encrypted} encrypted, {incoming} incoming) | {clients} ",
+            peers.len()
+        ))
+    }
+}
+
+impl Drop for PeersPopup {
+    fn drop(&mut self) {
+        self.fetch_task_handle.abort();
+    }
+}
+
+impl Component for PeersPopup {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        use Action as A;
+
+        match action {
+            _ if action.is_soft_quit() => return ComponentAction::Quit,
+            Action::Click(event)
+                if popup_close_button_clicked(self.block_rect, Position::Bottom, event) =>
+            {
+                return ComponentAction::Quit
+            }
+            A::Confirm => return ComponentAction::Quit,
+            A::Up | A::ScrollUpBy(_) => self.table_state.select_previous(),
+            A::Down | A::ScrollDownBy(_) => self.table_state.select_next(),
+            A::Home => self.table_state.select_first(),
+            A::End => self.table_state.select_last(),
+            A::MoveToColumnLeft | A::Left => self.move_sort_column(-1),
+            A::MoveToColumnRight | A::Right => self.move_sort_column(1),
+            A::Select => {
+                self.sort_reverse = !self.sort_reverse;
+                self.sort();
+            }
+            _ => return ComponentAction::Nothing,
+        }
+
+        CTX.send_action(Action::Render);
+        ComponentAction::Nothing
+    }
+
+    fn handle_update_action(&mut self, action: UpdateAction) {
+        if let UpdateAction::UpdateCurrentTorrent(torrent) = action {
+            self.update_torrent(&torrent);
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let (popup_rect, block_rect, text_rect) = popup_rects(rect, 85, 75);
+        self.block_rect = block_rect;
+
+        let highlight_style = Style::default().fg(CONFIG.general.accent_color);
+
+        let block = popup_block(" Peers ").title_bottom(popup_close_button_highlight());
+
+        let Some(peers) = &self.peers else {
+            f.render_widget(Clear, popup_rect);
+            f.render_widget(Paragraph::new("Loading..."), text_rect);
+            f.render_widget(block, block_rect);
+            return;
+        };
+
+        let mut block =
+            block.title_top(Self::summary(peers).style(highlight_style).right_aligned());
+
+        if CONFIG.general.beginner_mode {
+            let mut keys = vec![];
+            for (action, desc) in [
+                (GeneralAction::MoveToColumnLeft, " / "),
+                (GeneralAction::MoveToColumnRight, " - sort by column | "),
+                (GeneralAction::Select, " - reverse order "),
+            ] {
+                if let Some(key) = CONFIG
+                    .keybindings
+                    .general
+                    .get_keys_for_action_joined(action)
+                {
+                    keys.push(Span::styled(key, keybinding_style()));
+                    keys.push(Span::raw(desc));
+                }
+            }
+            block = block.title_bottom(Line::from(keys).left_aligned());
+        }
+
+        let headers = PeersColumn::ALL.iter().map(|column| {
+            if *column == self.sort_column {
+                let icon = if self.sort_reverse {
+                    &CONFIG.icons.sort_descending
+                } else {
+                    &CONFIG.icons.sort_ascending
+                };
+                Cell::from(format!("{icon} {}", column.name())).style(highlight_style)
+            } else {
+                Cell::from(column.name())
+            }
+        });
+
+        let rows = peers
+            .iter()
+            .map(|peer| Row::new(PeersColumn::ALL.iter().map(|column| column.cell(peer))));
+
+        let table = Table::new(rows, PeersColumn::ALL.map(PeersColumn::width))
+            .header(Row::new(headers).bold())
+            .block(block)
+            .row_highlight_style(highlight_style.on_black().bold());
+
+        f.render_widget(Clear, popup_rect);
+        f.render_stateful_widget(table, block_rect, &mut self.table_state);
+    }
+}
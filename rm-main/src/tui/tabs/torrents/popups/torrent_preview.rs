@@ -0,0 +1,284 @@
+use std::collections::BTreeMap;
+
+use intuitils::popup::popup_rects;
+use ratatui::{prelude::*, style::Styled, widgets::Clear};
+use rm_config::{
+    keymap::{actions::torrents_tab_file_viewer::TorrentsFileViewerAction, GeneralAction},
+    CONFIG,
+};
+use transmission_rpc::types::{Priority, TorrentAddArgs};
+use tui_tree_widget::{Tree, TreeItem, TreeState};
+
+use crate::{
+    metainfo::Metainfo,
+    tui::components::{keybinding_style, popup_block},
+};
+use rm_shared::{action::Action, utils::bytes_to_human_format};
+
+/// Lets the user pick wanted files and their priorities of a `.torrent`
+/// that's about to be added.
+pub struct TorrentPreview {
+    metainfo: Metainfo,
+    wanted: Vec<bool>,
+    priorities: Vec<Priority>,
+    tree: Node,
+    tree_state: TreeState<NodeId>,
+}
+
+/// Directories and files are kept apart, as a directory can be named just
+/// like the index of a file next to it.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum NodeId {
+    Directory(String),
+    File(usize),
+}
+
+impl TorrentPreview {
+    pub fn new(metainfo: Metainfo) -> Self {
+        let mut tree = Node::default();
+        for (id, file) in metainfo.files.iter().enumerate() {
+            tree.add_file(id, &file.path);
+        }
+
+        let mut tree_state = TreeState::default();
+        // Open the torrent's root directory right away.
+        tree_state.open(vec![NodeId::Directory(metainfo.name.clone())]);
+
+        Self {
+            wanted: vec![true; metainfo.files.len()],
+            priorities: vec![Priority::Normal; metainfo.files.len()],
+            metainfo,
+            tree,
+            tree_state,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.metainfo.name
+    }
+
+    /// Returns whether the action changed anything worth re-rendering.
+    pub fn handle_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Up => self.tree_state.key_up(),
+            Action::Down => self.tree_state.key_down(),
+            Action::Left => self.tree_state.key_left(),
+            Action::Right => self.tree_state.key_right(),
+            Action::Select => self.toggle_wanted(),
+            Action::ChangeFilePriority => self.cycle_priority(),
+            _ => false,
+        }
+    }
+
+    pub fn apply_to(&self, args: &mut TorrentAddArgs) {
+        let ids_where = |f: &dyn Fn(usize) -> bool| {
+            let ids: Vec<i32> = (0..self.wanted.len())
+                .filter(|&id| f(id))
+                .map(|id| id as i32)
+                .collect();
+            (!ids.is_empty()).then_some(ids)
+        };
+
+        args.files_unwanted = ids_where(&|id| !self.wanted[id]);
+        args.priority_low = ids_where(&|id| self.priorities[id] == Priority::Low);
+        args.priority_high = ids_where(&|id| self.priorities[id] == Priority::High);
+    }
+
+    fn selected_files(&self) -> Vec<usize> {
+        let Some((last, dirs)) = self.tree_state.selected().split_last() else {
+            return vec![];
+        };
+
+        let mut node = &self.tree;
+        for dir in dirs {
+            match dir {
+                NodeId::Directory(name) if node.directories.contains_key(name) => {
+                    node = &node.directories[name];
+                }
+                _ => return vec![],
+            }
+        }
+
+        match last {
+            NodeId::Directory(name) => node
+                .directories
+                .get(name)
+                .map(Node::all_files)
+                .unwrap_or_default(),
+            NodeId::File(id) => vec![*id],
+        }
+    }
+
+    fn toggle_wanted(&mut self) -> bool {
+        let selected = self.selected_files();
+        if selected.is_empty() {
+            return false;
+        }
+
+        let new_wanted = !selected.iter().any(|&id| self.wanted[id]);
+        for id in selected {
+            self.wanted[id] = new_wanted;
+        }
+        true
+    }
+
+    fn cycle_priority(&mut self) -> bool {
+        let selected = self.selected_files();
+        let Some(&first) = selected.first() else {
+            return false;
+        };
+
+        let new_priority = match self.priorities[first] {
+            Priority::Low => Priority::Normal,
+            Priority::Normal => Priority::High,
+            Priority::High => Priority::Low,
+        };
+        for id in selected {
+            self.priorities[id] = new_priority;
+        }
+        true
+    }
+
+    pub fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let (popup_rect, block_rect, _) = popup_rects(rect, 75, 75);
+
+        let highlight_style = Style::default().fg(CONFIG.general.accent_color);
+
+        if self.tree_state.selected().is_empty() {
+            self.tree_state.select_first();
+        }
+
+        let wanted_length: i64 = self
+            .metainfo
+            .files
+            .iter()
+            .zip(&self.wanted)
+            .filter(|(_, wanted)| **wanted)
+            .map(|(file, _)| file.length)
+            .sum();
+
+        let size = format!(
+            " {} / {} ",
+            bytes_to_human_format(wanted_length),
+            bytes_to_human_format(self.metainfo.total_length())
+        );
+
+        let general = &CONFIG.keybindings.general;
+        let file_viewer = &CONFIG.keybindings.torrents_tab_file_viewer;
+        let mut keys = vec![];
+        for (key, desc) in [
+            (
+                general.get_keys_for_action_joined(GeneralAction::Confirm),
+                " - add | ",
+            ),
+            (
+                general.get_keys_for_action_joined(GeneralAction::Select),
+                " - toggle | ",
+            ),
+            (
+                file_viewer
+                    .get_keys_for_action_joined(TorrentsFileViewerAction::ChangeFilePriority),
+                " - change priority | ",
+            ),
+            (
+                general.get_keys_for_action_joined(GeneralAction::Close),
+                " - cancel ",
+            ),
+        ] {
+            if let Some(key) = key {
+                keys.push(Span::styled(key, keybinding_style()));
+                keys.push(Span::raw(desc));
+            }
+        }
+
+        let block = popup_block(" Add torrent ")
+            .title_top(size.set_style(highlight_style).into_right_aligned_line())
+            .title_bottom(Line::from(keys).left_aligned());
+
+        let tree_items = self.tree.make_tree(self);
+
+        f.render_widget(Clear, popup_rect);
+
+        match Tree::new(&tree_items) {
+            Ok(tree_widget) => {
+                let tree_widget = tree_widget
+                    .block(block)
+                    .highlight_style(highlight_style.on_black().bold());
+                f.render_stateful_widget(tree_widget, block_rect, &mut self.tree_state);
+            }
+            Err(err) => {
+                tracing::error!("Cannot build the tree of {}: {err}", self.metainfo.name);
+                f.render_widget(block, block_rect);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Node {
+    files: Vec<(usize, String)>,
+    directories: BTreeMap<String, Node>,
+}
+
+impl Node {
+    fn add_file(&mut self, id: usize, remaining_path: &[String]) {
+        if let Some((first, rest)) = remaining_path.split_first() {
+            if rest.is_empty() {
+                self.files.push((id, first.clone()));
+            } else {
+                self.directories
+                    .entry(first.clone())
+                    .or_default()
+                    .add_file(id, rest);
+            }
+        }
+    }
+
+    fn all_files(&self) -> Vec<usize> {
+        let mut ids: Vec<usize> = self.files.iter().map(|(id, _)| *id).collect();
+        for node in self.directories.values() {
+            ids.extend(node.all_files());
+        }
+        ids
+    }
+
+    fn make_tree(&self, preview: &TorrentPreview) -> Vec<TreeItem<'static, NodeId>> {
+        let mut tree_items = vec![];
+        for (id, name) in &self.files {
+            let mut line = Line::default();
+
+            if preview.wanted[*id] {
+                line.push_span(Span::raw("󰄲 "));
+            } else {
+                line.push_span(Span::raw(" "));
+            }
+
+            line.push_span(Span::raw("| "));
+
+            let priority = match preview.priorities[*id] {
+                Priority::Low => "Low",
+                Priority::Normal => "Normal",
+                Priority::High => "High",
+            };
+            line.push_span(format!("[{priority}] "));
+
+            line.push_span(Span::raw("["));
+            line.push_span(bytes_to_human_format(preview.metainfo.files[*id].length));
+            line.push_span(Span::raw("] "));
+
+            line.push_span(Span::raw(name.clone()));
+
+            tree_items.push(TreeItem::new_leaf(NodeId::File(*id), line));
+        }
+
+        for (key, value) in &self.directories {
+            let id = NodeId::Directory(key.clone());
+            match TreeItem::new(id, key.clone(), value.make_tree(preview)) {
+                Ok(item) => tree_items.push(item),
+                Err(err) => tracing::error!("Cannot show directory {key}: {err}"),
+            }
+        }
+
+        tree_items
+    }
+}
@@ -0,0 +1,365 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use crossterm::event::{KeyCode, KeyEvent};
+use intuitils::popup::popup_rects;
+use ratatui::{
+    prelude::*,
+    widgets::{block::Position, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+use rm_config::{keymap::TorrentsAction, CONFIG};
+use tokio::{sync::oneshot, task::JoinHandle};
+use transmission_rpc::types::{Id, Torrent, TrackerStat};
+
+use crate::{
+    transmission::TorrentAction,
+    tui::{
+        components::{
+            keybinding_style, popup_block, popup_close_button_clicked,
+            popup_close_button_highlight, Component, ComponentAction, InputManager,
+        },
+        ctx::CTX,
+        tabs::torrents::tasks::TorrentSelection,
+    },
+};
+use rm_shared::{
+    action::{Action, UpdateAction},
+    utils::seconds_to_human_format,
+};
+
+enum Edit {
+    Add(InputManager),
+    Replace(String, InputManager),
+    Remove(String, InputManager),
+}
+
+impl Edit {
+    fn input_mgr(&mut self) -> &mut InputManager {
+        match self {
+            Edit::Add(input_mgr) | Edit::Replace(_, input_mgr) | Edit::Remove(_, input_mgr) => {
+                input_mgr
+            }
+        }
+    }
+}
+
+pub struct TrackersPopup {
+    // Edits are applied to all of these, trackers are shown for the first one.
+    torrent_ids: Vec<Id>,
+    trackers: Option<Vec<TrackerStat>>,
+    table_state: TableState,
+    edit: Option<Edit>,
+    fetch_task_handle: JoinHandle<()>,
+    block_rect: Rect,
+}
+
+async fn fetch_trackers(torrent_id: Id) {
+    loop {
+        let (torrent_tx, torrent_rx) = oneshot::channel();
+        CTX.send_torrent_action(TorrentAction::GetTorrentsById(
+            vec![torrent_id.clone()],
+            torrent_tx,
+        ));
+
+        // The sender is dropped if the profile got switched in the meantime.
+        match torrent_rx.await {
+            Ok(Ok(mut torrents)) => {
+                let Some(torrent) = torrents.pop() else {
+                    // The torrent got removed in the meantime.
+                    return;
+                };
+                CTX.send_update_action(UpdateAction::UpdateCurrentTorrent(Box::new(torrent)));
+            }
+            Ok(Err(err_message)) => {
+                CTX.send_update_action(UpdateAction::Error(err_message));
+            }
+            Err(_) => return,
+        };
+
+        tokio::time::sleep(Duration::from_secs(3)).await;
+    }
+}
+
+impl TrackersPopup {
+    pub fn new(selection: TorrentSelection) -> Self {
+        let torrent_ids = selection.ids();
+        let fetch_task_handle = tokio::task::spawn(fetch_trackers(torrent_ids[0].clone()));
+
+        Self {
+            torrent_ids,
+            trackers: None,
+            table_state: TableState::default().with_selected(Some(0)),
+            edit: None,
+            fetch_task_handle,
+            block_rect: Rect::default(),
+        }
+    }
+
+    pub fn update_torrent(&mut self, torrent: &Torrent) {
+        if let Some(trackers) = &torrent.tracker_stats {
+            let mut trackers = trackers.clone();
+            trackers.sort_by_key(|tracker| tracker.tier);
+            self.trackers = Some(trackers);
+        }
+    }
+
+    fn selected_tracker(&self) -> Option<&TrackerStat> {
+        self.trackers
+            .as_ref()
+            .and_then(|trackers| trackers.get(self.table_state.selected()?))
+    }
+
+    fn start_edit(&mut self, edit: Edit) {
+        self.edit = Some(edit);
+        CTX.send_update_action(UpdateAction::SwitchToInputMode);
+    }
+
+    fn handle_edit_input(&mut self, input: KeyEvent) {
+        let edit = self.edit.as_mut().expect("editing");
+
+        if input.code == KeyCode::Esc {
+            self.edit = None;
+            CTX.send_update_action(UpdateAction::SwitchToNormalMode);
+            return;
+        }
+
+        if input.code != KeyCode::Enter {
+            if edit.input_mgr().handle_key(input).is_some() {
+                CTX.send_action(Action::Render);
+            }
+            return;
+        }
+
+        let ids = self.torrent_ids.clone();
+        let action = match edit {
+            Edit::Add(input_mgr) => {
+                let url = input_mgr.text();
+                (!url.is_empty()).then_some(TorrentAction::AddTracker(ids, url))
+            }
+            Edit::Replace(old_url, input_mgr) => {
+                let new_url = input_mgr.text();
+                (!new_url.is_empty() && new_url != *old_url)
+                    .then(|| TorrentAction::ReplaceTracker(ids, old_url.clone(), new_url))
+            }
+            Edit::Remove(url, input_mgr) => {
+                match input_mgr.text().to_lowercase().as_str() {
+                    "y" | "yes" => Some(TorrentAction::RemoveTracker(ids, url.clone())),
+                    "" | "n" | "no" => None,
+                    // Keep asking until it's clear.
+                    _ => return,
+                }
+            }
+        };
+
+        if let Some(action) = action {
+            CTX.send_torrent_action(action);
+        }
+
+        self.edit = None;
+        CTX.send_update_action(UpdateAction::SwitchToNormalMode);
+    }
+
+    fn targets_str(&self) -> String {
+        if self.torrent_ids.len() > 1 {
+            format!(" in {} torrents", self.torrent_ids.len())
+        } else {
+            String::new()
+        }
+    }
+
+    fn tracker_row(tracker: &TrackerStat) -> Row<'_> {
+        let now = Utc::now();
+        let ago = |time: DateTime<Utc>| {
+            let secs = (now - time).num_seconds().max(0);
+            format!("{} ago", seconds_to_human_format(secs))
+        };
+
+        let result = |has_happened: bool, succeeded: bool, result: &str, time| {
+            if !has_happened {
+                return Cell::from("-");
+            }
+            let cell = Cell::from(format!("{result} ({})", ago(time)));
+            if succeeded {
+                cell
+            } else {
+                cell.red()
+            }
+        };
+
+        let count = |count: i64| {
+            if count < 0 {
+                "-".to_string()
+            } else {
+                count.to_string()
+            }
+        };
+
+        let next_announce = if tracker.next_announce_time > now {
+            let secs = (tracker.next_announce_time - now).num_seconds();
+            format!("in {}", seconds_to_human_format(secs))
+        } else {
+            "-".to_string()
+        };
+
+        Row::new([
+            Cell::from(tracker.tier.to_string()),
+            Cell::from(tracker.announce.as_str()),
+            result(
+                tracker.has_announced,
+                tracker.last_announce_succeeded,
+                &tracker.last_announce_result,
+                tracker.last_announce_time,
+            ),
+            result(
+                tracker.has_scraped,
+                tracker.last_scrape_succeeded,
+                &tracker.last_scrape_result,
+                tracker.last_scrape_time,
+            ),
+            Cell::from(count(tracker.seeder_count)),
+            Cell::from(count(tracker.leecher_count)),
+            Cell::from(next_announce),
+        ])
+    }
+}
+
+impl Drop for TrackersPopup {
+    fn drop(&mut self) {
+        self.fetch_task_handle.abort();
+        if self.edit.is_some() {
+            CTX.send_update_action(UpdateAction::SwitchToNormalMode);
+        }
+    }
+}
+
+impl Component for TrackersPopup {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        use Action as A;
+
+        if self.edit.is_some() {
+            if let A::Input(input) = action {
+                self.handle_edit_input(input);
+                CTX.send_action(A::Render);
+            }
+            return ComponentAction::Nothing;
+        }
+
+        match action {
+            _ if action.is_soft_quit() => return ComponentAction::Quit,
+            Action::Click(event)
+                if popup_close_button_clicked(self.block_rect, Position::Bottom, event) =>
+            {
+                return ComponentAction::Quit
+            }
+            A::Confirm => return ComponentAction::Quit,
+            A::Up | A::ScrollUpBy(_) => self.table_state.select_previous(),
+            A::Down | A::ScrollDownBy(_) => self.table_state.select_next(),
+            A::Home => self.table_state.select_first(),
+            A::End => self.table_state.select_last(),
+            A::AddMagnet => {
+                let prompt = format!("Add tracker{}: ", self.targets_str());
+                self.start_edit(Edit::Add(InputManager::new(prompt)));
+            }
+            A::Rename => {
+                if let Some(tracker) = self.selected_tracker() {
+                    let url = tracker.announce.clone();
+                    let prompt = format!("Replace tracker{} with: ", self.targets_str());
+                    let input_mgr = InputManager::new_with_value(prompt, url.clone());
+                    self.start_edit(Edit::Replace(url, input_mgr));
+                }
+            }
+            A::Delete => {
+                if let Some(tracker) = self.selected_tracker() {
+                    let url = tracker.announce.clone();
+                    let prompt = format!("Remove {url}{}? (y/N) ", self.targets_str());
+                    self.start_edit(Edit::Remove(url, InputManager::new(prompt)));
+                }
+            }
+            _ => return ComponentAction::Nothing,
+        }
+
+        CTX.send_action(Action::Render);
+        ComponentAction::Nothing
+    }
+
+    fn handle_update_action(&mut self, action: UpdateAction) {
+        if let UpdateAction::UpdateCurrentTorrent(torrent) = action {
+            self.update_torrent(&torrent);
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let (popup_rect, block_rect, text_rect) = popup_rects(rect, 85, 60);
+        self.block_rect = block_rect;
+
+        let highlight_style = Style::default().fg(CONFIG.general.accent_color);
+
+        let title = if self.torrent_ids.len() > 1 {
+            format!(" Trackers ({} torrents selected) ", self.torrent_ids.len())
+        } else {
+            " Trackers ".to_string()
+        };
+        let block = popup_block(&title).title_bottom(popup_close_button_highlight());
+
+        let Some(trackers) = &self.trackers else {
+            f.render_widget(Clear, popup_rect);
+            f.render_widget(Paragraph::new("Loading..."), text_rect);
+            f.render_widget(block, block_rect);
+            return;
+        };
+
+        let mut keys = vec![];
+        for (action, desc) in [
+            (TorrentsAction::AddMagnet, " - add | "),
+            (TorrentsAction::Rename, " - replace | "),
+            (TorrentsAction::Delete, " - remove "),
+        ] {
+            if let Some(key) = CONFIG
+                .keybindings
+                .torrents_tab
+                .get_keys_for_action_joined(action)
+            {
+                keys.push(Span::styled(key, keybinding_style()));
+                keys.push(Span::raw(desc));
+            }
+        }
+        let block = block.title_bottom(Line::from(keys).left_aligned());
+
+        let inner_rect = block.inner(block_rect);
+        let [table_rect, input_rect] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(inner_rect);
+
+        let headers = Row::new([
+            "Tier",
+            "Announce URL",
+            "Last announce",
+            "Last scrape",
+            "Seeders",
+            "Leechers",
+            "Next announce",
+        ])
+        .bold();
+
+        let widths = [
+            Constraint::Length(4),
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(7),
+            Constraint::Length(8),
+            Constraint::Length(13),
+        ];
+
+        let table = Table::new(trackers.iter().map(Self::tracker_row), widths)
+            .header(headers)
+            .row_highlight_style(highlight_style.on_black().bold());
+
+        f.render_widget(Clear, popup_rect);
+        f.render_widget(block, block_rect);
+        f.render_stateful_widget(table, table_rect, &mut self.table_state);
+
+        if let Some(edit) = &mut self.edit {
+            edit.input_mgr().render(f, input_rect);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Clear, List, ListState},
+};
+
+use rm_config::CONFIG;
+use rm_shared::action::{Action, UpdateAction};
+
+use crate::tui::{
+    components::{popup_block, Component, ComponentAction},
+    ctx::CTX,
+};
+
+const DEFAULT_VIEW: &str = "Default";
+const SAVE_VIEW: &str = "+ Save current as a new view";
+
+pub struct ViewsPopup {
+    names: Vec<String>,
+    active_view: Option<usize>,
+    list_state: ListState,
+}
+
+impl ViewsPopup {
+    pub fn new(names: Vec<String>, active_view: Option<usize>) -> Self {
+        let selected = active_view.map_or(0, |idx| idx + 1);
+
+        Self {
+            names,
+            active_view,
+            list_state: ListState::default().with_selected(Some(selected)),
+        }
+    }
+
+    fn entries(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(DEFAULT_VIEW)
+            .chain(self.names.iter().map(String::as_str))
+            .chain(std::iter::once(SAVE_VIEW))
+    }
+}
+
+impl Component for ViewsPopup {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        if action.is_soft_quit() {
+            return ComponentAction::Quit;
+        }
+
+        match action {
+            Action::Up => {
+                self.list_state.select_previous();
+                CTX.send_action(Action::Render);
+                ComponentAction::Nothing
+            }
+            Action::Down => {
+                self.list_state.select_next();
+                CTX.send_action(Action::Render);
+                ComponentAction::Nothing
+            }
+            Action::Confirm => {
+                match self.list_state.selected() {
+                    Some(0) => CTX.send_update_action(UpdateAction::SwitchView(None)),
+                    Some(idx) if idx <= self.names.len() => {
+                        CTX.send_update_action(UpdateAction::SwitchView(Some(idx - 1)))
+                    }
+                    Some(_) => CTX.send_update_action(UpdateAction::PromptSaveView),
+                    None => (),
+                }
+                ComponentAction::Quit
+            }
+            _ => ComponentAction::Nothing,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let longest_line = self.entries().map(|name| name.chars().count()).max();
+
+        let width = u16::try_from(longest_line.unwrap_or(0) + 6).unwrap_or(u16::MAX);
+        let height = u16::try_from(self.names.len() + 4).unwrap_or(u16::MAX);
+
+        let [block_rect] = Layout::horizontal([Constraint::Length(width)])
+            .flex(layout::Flex::Center)
+            .areas(rect);
+        let [block_rect] = Layout::vertical([Constraint::Length(height)])
+            .flex(layout::Flex::Center)
+            .areas(block_rect);
+
+        let block = popup_block(" Views ");
+
+        let list_rect = block_rect.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        });
+
+        let active_entry = self.active_view.map_or(0, |idx| idx + 1);
+        let save_entry = self.names.len() + 1;
+        let items = self.entries().enumerate().map(|(idx, name)| {
+            let line = Line::from(format!(" {name} "));
+            if idx == active_entry {
+                line.underlined()
+            } else if idx == save_entry {
+                line.dark_gray()
+            } else {
+                line
+            }
+        });
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .fg(CONFIG.general.accent_color)
+                .bg(Color::Black)
+                .bold(),
+        );
+
+        f.render_widget(Clear, block_rect);
+        f.render_widget(block, block_rect);
+        f.render_stateful_widget(list, list_rect, &mut self.list_state);
+    }
+}
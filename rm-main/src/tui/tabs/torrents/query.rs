@@ -0,0 +1,601 @@
+//! Filter queries typed into the torrents tab search bar.
+//!
+//! A query is made of words combined with `AND`, `OR`, `NOT` and parentheses,
+//! with `AND` being implied between words standing next to each other. The
+//! operators are uppercase only, so that titles like `Tom and Jerry` are still
+//! just text. A word is either a field predicate like `status:seeding`,
+//! `size>10G`, `ratio<1`, `cat:linux`, `dir:/mnt/a`, `tracker:example` or
+//! `added<7d`, or a piece of text that's fuzzy matched against the torrent name,
+//! which is also what words with an unknown field like `title:` are. Quoted
+//! words are always text, and quotes can also wrap values with spaces:
+//! `dir:"/mnt/my disk"`.
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use transmission_rpc::types::TorrentStatus;
+
+use super::{facets::StatusFacet, rustmission_torrent::RustmissionTorrent};
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError(String);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn error<T>(message: impl Into<String>) -> Result<T, ParseError> {
+    Err(ParseError(message.into()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cmp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+impl Cmp {
+    fn holds<T: PartialOrd>(self, lhs: T, rhs: T) -> bool {
+        match self {
+            Cmp::Lt => lhs < rhs,
+            Cmp::Le => lhs <= rhs,
+            Cmp::Gt => lhs > rhs,
+            Cmp::Ge => lhs >= rhs,
+            Cmp::Eq => lhs == rhs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Facet(StatusFacet),
+    Queued,
+}
+
+impl Status {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let status = match value {
+            "downloading" | "down" => Status::Facet(StatusFacet::Downloading),
+            "seeding" | "seed" => Status::Facet(StatusFacet::Seeding),
+            "paused" | "stopped" => Status::Facet(StatusFacet::Paused),
+            "checking" | "verifying" => Status::Facet(StatusFacet::Checking),
+            "errored" | "error" => Status::Facet(StatusFacet::Errored),
+            "stalled" => Status::Facet(StatusFacet::Stalled),
+            "queued" => Status::Queued,
+            _ => return error(format!("unknown status '{value}'")),
+        };
+        Ok(status)
+    }
+
+    fn matches(self, torrent: &RustmissionTorrent) -> bool {
+        match self {
+            Status::Facet(facet) => facet.matches(torrent),
+            Status::Queued => matches!(
+                torrent.status(),
+                TorrentStatus::QueuedToVerify
+                    | TorrentStatus::QueuedToDownload
+                    | TorrentStatus::QueuedToSeed
+            ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+    Text(String),
+    Status(Status),
+    Category(String),
+    Directory(String),
+    Tracker(String),
+    Name(String),
+    Size(Cmp, i64),
+    Ratio(Cmp, f64),
+    // In percent.
+    Progress(Cmp, f64),
+    // How long ago, in seconds.
+    Added(Cmp, i64),
+    Active(Cmp, i64),
+}
+
+impl Query {
+    /// Returns `None` for a query with nothing in it.
+    pub fn parse(text: &str) -> Result<Option<Query>, ParseError> {
+        let tokens = tokenize(text)?;
+        if tokens.is_empty() {
+            return Ok(None);
+        }
+
+        let mut parser = Parser { tokens, pos: 0 };
+        let query = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(Some(query)),
+            Some(Token::RParen) => error("unexpected ')'"),
+            Some(token) => error(format!("unexpected '{}'", token.text())),
+        }
+    }
+
+    pub fn matches(&self, torrent: &RustmissionTorrent, now: DateTime<Utc>) -> bool {
+        let contains = |haystack: &str, needle: &str| haystack.to_lowercase().contains(needle);
+        let age = |time: DateTime<Utc>| (now - time).num_seconds();
+
+        match self {
+            Query::And(lhs, rhs) => lhs.matches(torrent, now) && rhs.matches(torrent, now),
+            Query::Or(lhs, rhs) => lhs.matches(torrent, now) || rhs.matches(torrent, now),
+            Query::Not(query) => !query.matches(torrent, now),
+            Query::Text(text) => SkimMatcherV2::default()
+                .fuzzy_match(&torrent.torrent_name, text)
+                .is_some(),
+            Query::Status(status) => status.matches(torrent),
+            Query::Category(name) => torrent
+                .category
+                .as_ref()
+                .is_some_and(|category| contains(category.name(), name)),
+            Query::Directory(dir) => contains(&torrent.download_dir, dir),
+            Query::Tracker(host) => torrent.tracker_hosts.iter().any(|h| contains(h, host)),
+            Query::Name(name) => contains(&torrent.torrent_name, name),
+            Query::Size(cmp, size) => cmp.holds(torrent.size_when_done, *size),
+            Query::Ratio(Cmp::Eq, ratio) => (f64::from(torrent.ratio) - ratio).abs() < 0.005,
+            Query::Ratio(cmp, ratio) => cmp.holds(f64::from(torrent.ratio), *ratio),
+            Query::Progress(cmp, percent) => {
+                cmp.holds(f64::from(torrent.progress) * 100.0, *percent)
+            }
+            Query::Added(cmp, secs) => cmp.holds(age(torrent.added_date), *secs),
+            Query::Active(cmp, secs) => cmp.holds(age(torrent.activity_date), *secs),
+        }
+    }
+
+    /// Indices of the torrent name's chars matched by the free-text parts,
+    /// for highlighting.
+    pub fn highlight_indices(&self, torrent_name: &str) -> Vec<usize> {
+        let mut texts = vec![];
+        self.positive_texts(&mut texts);
+
+        let matcher = SkimMatcherV2::default();
+        let mut indices: Vec<usize> = texts
+            .iter()
+            .filter_map(|text| matcher.fuzzy_indices(torrent_name, text))
+            .flat_map(|(_, indices)| indices)
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+
+    fn positive_texts<'a>(&'a self, texts: &mut Vec<&'a str>) {
+        match self {
+            Query::And(lhs, rhs) | Query::Or(lhs, rhs) => {
+                lhs.positive_texts(texts);
+                rhs.positive_texts(texts);
+            }
+            Query::Text(text) => texts.push(text),
+            _ => (),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    LParen,
+    RParen,
+}
+
+impl Token {
+    fn text(&self) -> &str {
+        match self {
+            Token::Word(word) | Token::Quoted(word) => word,
+            Token::LParen => "(",
+            Token::RParen => ")",
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word == keyword)
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = vec![];
+    let mut chars = text.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            _ if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RParen);
+            }
+            _ => {
+                let quoted = c == '"';
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    chars.next();
+                    if c == '"' {
+                        loop {
+                            match chars.next() {
+                                Some('"') => break,
+                                Some(c) => word.push(c),
+                                None => return error("unclosed quote"),
+                            }
+                        }
+                    } else {
+                        word.push(c);
+                    }
+                }
+                tokens.push(if quoted {
+                    Token::Quoted(word)
+                } else {
+                    Token::Word(word)
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        self.pos += 1;
+        self.tokens.get(self.pos - 1)
+    }
+
+    fn parse_or(&mut self) -> Result<Query, ParseError> {
+        let mut query = self.parse_and()?;
+        while self.peek().is_some_and(|token| token.is_keyword("OR")) {
+            self.next();
+            let rhs = self.parse_and()?;
+            query = Query::Or(Box::new(query), Box::new(rhs));
+        }
+        Ok(query)
+    }
+
+    fn parse_and(&mut self) -> Result<Query, ParseError> {
+        let mut query = self.parse_unary()?;
+        loop {
+            match self.peek() {
+                None | Some(Token::RParen) => break,
+                Some(token) if token.is_keyword("OR") => break,
+                Some(token) if token.is_keyword("AND") => {
+                    self.next();
+                }
+                Some(_) => (),
+            }
+            let rhs = self.parse_unary()?;
+            query = Query::And(Box::new(query), Box::new(rhs));
+        }
+        Ok(query)
+    }
+
+    fn parse_unary(&mut self) -> Result<Query, ParseError> {
+        match self.next() {
+            None => error("unexpected end of the query"),
+            Some(Token::LParen) => {
+                let query = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(query),
+                    _ => error("missing ')'"),
+                }
+            }
+            Some(Token::RParen) => error("unexpected ')'"),
+            Some(token) if token.is_keyword("NOT") => Ok(Query::Not(Box::new(self.parse_unary()?))),
+            Some(token) if token.is_keyword("AND") || token.is_keyword("OR") => {
+                error(format!("unexpected '{}'", token.text()))
+            }
+            Some(Token::Quoted(text)) => Ok(Query::Text(text.clone())),
+            Some(Token::Word(word)) => {
+                let word = word.clone();
+                parse_word(&word)
+            }
+        }
+    }
+}
+
+const OPERATORS: [(&str, Option<Cmp>); 6] = [
+    (">=", Some(Cmp::Ge)),
+    ("<=", Some(Cmp::Le)),
+    (">", Some(Cmp::Gt)),
+    ("<", Some(Cmp::Lt)),
+    ("=", Some(Cmp::Eq)),
+    // Plain match for text fields, equality for the others.
+    (":", None),
+];
+
+fn parse_word(word: &str) -> Result<Query, ParseError> {
+    let field_len = word
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(word.len());
+    let (field, rest) = word.split_at(field_len);
+
+    let Some((cmp, value)) = OPERATORS
+        .iter()
+        .find_map(|(op, cmp)| Some((*cmp, rest.strip_prefix(op)?)))
+    else {
+        return Ok(Query::Text(word.to_string()));
+    };
+
+    let field = field.to_lowercase();
+    let is_text_field = matches!(
+        field.as_str(),
+        "status" | "category" | "cat" | "directory" | "dir" | "tracker" | "name"
+    );
+    let is_number_field = matches!(
+        field.as_str(),
+        "size" | "ratio" | "progress" | "added" | "active"
+    );
+    if !is_text_field && !is_number_field {
+        return Ok(Query::Text(word.to_string()));
+    }
+
+    if value.is_empty() {
+        return error(format!("missing value for '{field}'"));
+    }
+    let text_value = value.to_lowercase();
+
+    if is_text_field && !matches!(cmp, None | Some(Cmp::Eq)) {
+        return error(format!("'{field}' can only be matched with ':'"));
+    }
+    let cmp = cmp.unwrap_or(Cmp::Eq);
+
+    match field.as_str() {
+        "status" => Ok(Query::Status(Status::parse(&text_value)?)),
+        "category" | "cat" => Ok(Query::Category(text_value)),
+        "directory" | "dir" => Ok(Query::Directory(text_value)),
+        "tracker" => Ok(Query::Tracker(text_value)),
+        "name" => Ok(Query::Name(text_value)),
+        "size" => Ok(Query::Size(cmp, parse_size(value)?)),
+        "ratio" => match value.parse::<f64>() {
+            Ok(ratio) if ratio.is_finite() => Ok(Query::Ratio(cmp, ratio)),
+            _ => error(format!("'{value}' isn't a ratio")),
+        },
+        "progress" => match value.trim_end_matches('%').parse::<f64>() {
+            Ok(percent) if percent.is_finite() => Ok(Query::Progress(cmp, percent)),
+            _ => error(format!("'{value}' isn't a percentage")),
+        },
+        "added" => Ok(Query::Added(cmp, parse_duration(value)?)),
+        "active" => Ok(Query::Active(cmp, parse_duration(value)?)),
+        _ => unreachable!(),
+    }
+}
+
+fn split_number(value: &str) -> (&str, &str) {
+    let number_len = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    value.split_at(number_len)
+}
+
+/// Sizes like `700M` or `1.5GB`, counted in 1024s like everywhere else.
+fn parse_size(value: &str) -> Result<i64, ParseError> {
+    let (number, unit) = split_number(value);
+    let multiplier: i64 = match unit.to_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" => 1 << 10,
+        "m" | "mb" => 1 << 20,
+        "g" | "gb" => 1 << 30,
+        "t" | "tb" => 1 << 40,
+        _ => return error(format!("unknown size unit '{unit}'")),
+    };
+
+    match number.parse::<f64>() {
+        Ok(number) => Ok((number * multiplier as f64) as i64),
+        Err(_) => error(format!("'{value}' isn't a size")),
+    }
+}
+
+/// Durations like `30m`, `12h`, `7d` or `2w`.
+fn parse_duration(value: &str) -> Result<i64, ParseError> {
+    let (number, unit) = split_number(value);
+    let multiplier: i64 = match unit.to_lowercase().as_str() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        "" => return error(format!("'{value}' needs a unit: s, m, h, d or w")),
+        _ => return error(format!("unknown time unit '{unit}'")),
+    };
+
+    match number.parse::<f64>() {
+        Ok(number) => Ok((number * multiplier as f64) as i64),
+        Err(_) => error(format!("'{value}' isn't a duration")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::tui::tabs::torrents::rustmission_torrent::CategoryType;
+
+    fn parse(text: &str) -> Query {
+        Query::parse(text).unwrap().unwrap()
+    }
+
+    fn parse_err(text: &str) -> String {
+        Query::parse(text).unwrap_err().to_string()
+    }
+
+    fn text(text: &str) -> Box<Query> {
+        Box::new(Query::Text(text.to_string()))
+    }
+
+    fn torrent(name: &str) -> RustmissionTorrent {
+        RustmissionTorrent::for_tests(name)
+    }
+
+    #[test]
+    fn empty_query() {
+        assert_eq!(Query::parse("").unwrap(), None);
+        assert_eq!(Query::parse("   ").unwrap(), None);
+    }
+
+    #[test]
+    fn free_text() {
+        assert_eq!(parse("debian"), Query::Text("debian".to_string()));
+        assert_eq!(
+            parse("debian netinst"),
+            Query::And(text("debian"), text("netinst"))
+        );
+        assert_eq!(parse("\"size>10G\""), Query::Text("size>10G".to_string()));
+        assert_eq!(parse("foo:bar"), Query::Text("foo:bar".to_string()));
+        assert_eq!(
+            parse("S01E01 title: part"),
+            Query::And(
+                Box::new(Query::And(text("S01E01"), text("title:"))),
+                text("part")
+            )
+        );
+        assert_eq!(
+            parse("Tom and Jerry"),
+            Query::And(
+                Box::new(Query::And(text("Tom"), text("and"))),
+                text("Jerry")
+            )
+        );
+    }
+
+    #[test]
+    fn predicates() {
+        assert_eq!(
+            parse("status:seeding"),
+            Query::Status(Status::Facet(StatusFacet::Seeding))
+        );
+        assert_eq!(parse("size>10G"), Query::Size(Cmp::Gt, 10 << 30));
+        assert_eq!(parse("size<=1.5k"), Query::Size(Cmp::Le, 1536));
+        assert_eq!(parse("ratio<1"), Query::Ratio(Cmp::Lt, 1.0));
+        assert_eq!(parse("cat:Linux"), Query::Category("linux".to_string()));
+        assert_eq!(parse("dir:/mnt/a"), Query::Directory("/mnt/a".to_string()));
+        assert_eq!(parse("added<7d"), Query::Added(Cmp::Lt, 7 * 24 * 60 * 60));
+        assert_eq!(
+            parse("tracker:example"),
+            Query::Tracker("example".to_string())
+        );
+        assert_eq!(parse("progress>=50%"), Query::Progress(Cmp::Ge, 50.0));
+        assert_eq!(
+            parse("dir:\"/mnt/my disk\""),
+            Query::Directory("/mnt/my disk".to_string())
+        );
+    }
+
+    #[test]
+    fn boolean_operators() {
+        assert_eq!(
+            parse("a OR b c"),
+            Query::Or(text("a"), Box::new(Query::And(text("b"), text("c"))))
+        );
+        assert_eq!(
+            parse("(a OR b) AND NOT c"),
+            Query::And(
+                Box::new(Query::Or(text("a"), text("b"))),
+                Box::new(Query::Not(text("c")))
+            )
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse_err("size>"), "missing value for 'size'");
+        assert_eq!(parse_err("size>10X"), "unknown size unit 'X'");
+        assert_eq!(parse_err("status:flying"), "unknown status 'flying'");
+        assert_eq!(parse_err("ratio:lots"), "'lots' isn't a ratio");
+        assert_eq!(parse_err("added<7"), "'7' needs a unit: s, m, h, d or w");
+        assert_eq!(parse_err("cat>linux"), "'cat' can only be matched with ':'");
+        assert_eq!(parse_err("(a or b"), "missing ')'");
+        assert_eq!(parse_err("a)"), "unexpected ')'");
+        assert_eq!(parse_err("a OR"), "unexpected end of the query");
+        assert_eq!(parse_err("AND a"), "unexpected 'AND'");
+        assert_eq!(parse_err("\"a"), "unclosed quote");
+    }
+
+    #[test]
+    fn matching() {
+        let now = Utc::now();
+
+        let mut linux = torrent("Debian netinst");
+        linux.update_status(TorrentStatus::Seeding);
+        linux.size_when_done = 20 << 30;
+        linux.ratio = 0.5;
+        linux.category = Some(CategoryType::Plain("Linux".to_string()));
+        linux.download_dir = "/mnt/a/iso".to_string();
+        linux.tracker_hosts = vec!["tracker.example.org".to_string()];
+        linux.added_date = now - Duration::days(2);
+
+        let mut other = torrent("Some movie");
+        other.update_status(TorrentStatus::Downloading);
+        other.size_when_done = 5 << 30;
+        other.added_date = now - Duration::days(30);
+
+        let query = parse("status:seeding size>10G ratio<1 cat:linux");
+        assert!(query.matches(&linux, now));
+        assert!(!query.matches(&other, now));
+
+        let query = parse("dir:/mnt/a tracker:example added<7d");
+        assert!(query.matches(&linux, now));
+        assert!(!query.matches(&other, now));
+
+        let query = parse("NOT status:seeding OR deb");
+        assert!(query.matches(&linux, now));
+        assert!(query.matches(&other, now));
+
+        assert!(parse("movie").matches(&other, now));
+        assert!(!parse("movie").matches(&linux, now));
+    }
+
+    #[test]
+    fn plain_titles() {
+        let now = Utc::now();
+        let episode = torrent("Show S01E01 title: part one");
+        let cartoon = torrent("Tom and Jerry");
+        let other = torrent("Debian netinst");
+
+        for (title, torrent) in [
+            ("S01E01 title: part", &episode),
+            ("Tom and Jerry", &cartoon),
+        ] {
+            let query = parse(title);
+            assert!(query.matches(torrent, now), "{title}");
+            assert!(!query.matches(&other, now), "{title}");
+        }
+        assert!(parse("tom or jerry").matches(&cartoon, now));
+        assert!(!parse("tom or jerry").matches(&torrent("Jerry Maguire"), now));
+    }
+
+    #[test]
+    fn highlighting() {
+        let query = parse("deb not movie");
+        assert_eq!(query.highlight_indices("debian"), vec![0, 1, 2]);
+        assert!(parse("size>1G").highlight_indices("debian").is_empty());
+    }
+}
@@ -9,31 +9,56 @@ use rm_shared::{
     header::Header,
     utils::{bytes_to_human_format, seconds_to_human_format},
 };
-use transmission_rpc::types::{ErrorType, FileStat, Id, Torrent, TorrentStatus};
+use transmission_rpc::types::{ErrorType, Id, RatioMode, Torrent, TorrentStatus};
+
+use crate::transmission::utils::tracker_host;
 
 #[derive(Clone)]
 pub struct RustmissionTorrent {
     pub torrent_name: String,
     pub size_when_done: i64,
     pub progress: f32,
+    pub recheck_progress: f32,
     pub eta_secs: i64,
     pub download_speed: i64,
-    pub upload_speed: String,
-    pub uploaded_ever: String,
+    pub upload_speed: i64,
+    pub uploaded_ever: i64,
+    pub downloaded_ever: u64,
     pub upload_ratio: String,
+    pub ratio: f32,
     status: TorrentStatus,
     style: Style,
     pub id: Id,
     pub download_dir: String,
-    pub file_stats: Vec<FileStat>,
     pub activity_date: DateTime<Utc>,
     pub added_date: DateTime<Utc>,
     pub peers_connected: i64,
+    pub queue_position: usize,
+    pub seeders: i64,
+    pub leechers: i64,
+    pub hash_string: String,
+    pub done_date: Option<DateTime<Utc>>,
+    pub seconds_seeding: i64,
+    pub seconds_downloading: i64,
+    pub total_size: i64,
+    pub left_until_done: i64,
+    pub labels: Vec<String>,
+    pub is_private: bool,
+    pub ratio_limit: RatioLimit,
+    pub is_stalled: bool,
+    pub tracker_hosts: Vec<String>,
     pub category: Option<CategoryType>,
     pub error: Option<String>,
     pub is_selected: bool,
 }
 
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub enum RatioLimit {
+    Single(f32),
+    Global,
+    Unlimited,
+}
+
 #[derive(Clone)]
 pub enum CategoryType {
     Plain(String),
@@ -50,7 +75,7 @@ impl CategoryType {
 }
 
 impl RustmissionTorrent {
-    pub fn to_row(&self, headers: &[Header]) -> ratatui::widgets::Row {
+    pub fn to_row(&self, headers: &[Header]) -> ratatui::widgets::Row<'_> {
         headers
             .iter()
             .map(|header| self.header_to_cell(*header))
@@ -60,8 +85,16 @@ impl RustmissionTorrent {
     }
 
     pub fn progress(&self) -> String {
+        if self.status == TorrentStatus::Verifying {
+            return format!(
+                "{} {:.2}%",
+                CONFIG.icons.verifying,
+                self.recheck_progress * 100f32
+            );
+        }
+
         match self.progress {
-            done if done == 1f32 => String::default(),
+            1f32 => String::default(),
             percent => format!("{:.2}%", percent * 100f32),
         }
     }
@@ -81,6 +114,33 @@ impl RustmissionTorrent {
         }
     }
 
+    pub fn upload_speed(&self) -> String {
+        match self.upload_speed {
+            0 => String::default(),
+            up => bytes_to_human_format(up),
+        }
+    }
+
+    pub fn uploaded_ever(&self) -> String {
+        bytes_to_human_format(self.uploaded_ever)
+    }
+
+    pub fn status_text(&self) -> &'static str {
+        if self.error.is_some() {
+            return "Error";
+        }
+
+        match self.status {
+            TorrentStatus::Stopped => "Stopped",
+            TorrentStatus::QueuedToVerify => "Queued to verify",
+            TorrentStatus::Verifying => "Verifying",
+            TorrentStatus::QueuedToDownload => "Queued to download",
+            TorrentStatus::Downloading => "Downloading",
+            TorrentStatus::QueuedToSeed => "Queued to seed",
+            TorrentStatus::Seeding => "Seeding",
+        }
+    }
+
     pub fn size_when_done(&self) -> String {
         bytes_to_human_format(self.size_when_done)
     }
@@ -90,7 +150,7 @@ impl RustmissionTorrent {
         highlighted_indices: &Vec<usize>,
         highlight_style: Style,
         headers: &Vec<Header>,
-    ) -> ratatui::widgets::Row {
+    ) -> ratatui::widgets::Row<'_> {
         let mut torrent_name_line = Line::default();
         torrent_name_line.push_span(self.category_icon_span());
 
@@ -187,7 +247,7 @@ impl RustmissionTorrent {
         format!("{}/{}", self.download_dir, self.torrent_name)
     }
 
-    fn category_icon_span(&self) -> Span {
+    fn category_icon_span(&self) -> Span<'_> {
         if let Some(CategoryType::Config(category)) = &self.category {
             Span::styled(
                 format!("{} ", category.icon),
@@ -213,7 +273,7 @@ impl RustmissionTorrent {
         line
     }
 
-    fn header_to_cell(&self, header: Header) -> Cell {
+    fn header_to_cell(&self, header: Header) -> Cell<'_> {
         match header {
             Header::Name => {
                 if let Some(error) = &self.error {
@@ -230,22 +290,8 @@ impl RustmissionTorrent {
                     Cell::from(self.torrent_name.as_str())
                 }
             }
-            Header::SizeWhenDone => Cell::from(self.size_when_done()),
-            Header::Progress => Cell::from(self.progress()),
-            Header::Eta => Cell::from(self.eta_secs()),
             Header::DownloadRate => Cell::from(download_speed_format(&self.download_speed())),
-            Header::UploadRate => Cell::from(upload_speed_format(&self.upload_speed)),
-            Header::DownloadDir => Cell::from(self.download_dir.as_str()),
-            Header::Padding => Cell::from(""),
-            Header::Id => match &self.id {
-                Id::Id(id) => Cell::from(id.to_string()),
-                Id::Hash(hash) => Cell::from(hash.as_str()),
-            },
-            Header::UploadRatio => Cell::from(self.upload_ratio.as_str()),
-            Header::UploadedEver => Cell::from(self.uploaded_ever.as_str()),
-            Header::ActivityDate => time_to_line(self.activity_date).into(),
-            Header::AddedDate => time_to_line(self.added_date).into(),
-            Header::PeersConnected => Cell::from(self.peers_connected.to_string()),
+            Header::UploadRate => Cell::from(upload_speed_format(&self.upload_speed())),
             Header::SmallStatus => {
                 if self.error.is_some() {
                     return Cell::from(CONFIG.icons.failure.as_str());
@@ -253,13 +299,13 @@ impl RustmissionTorrent {
 
                 match self.status() {
                     TorrentStatus::Stopped => Cell::from(CONFIG.icons.pause.as_str()),
-                    TorrentStatus::QueuedToVerify => Cell::from(CONFIG.icons.loading.as_str()),
+                    TorrentStatus::QueuedToVerify => Cell::from(CONFIG.icons.queued.as_str()),
                     TorrentStatus::Verifying => Cell::from(CONFIG.icons.verifying.as_str()),
-                    TorrentStatus::QueuedToDownload => Cell::from(CONFIG.icons.loading.as_str()),
-                    TorrentStatus::QueuedToSeed => Cell::from(CONFIG.icons.loading.as_str()),
+                    TorrentStatus::QueuedToDownload => Cell::from(CONFIG.icons.queued.as_str()),
+                    TorrentStatus::QueuedToSeed => Cell::from(CONFIG.icons.queued.as_str()),
                     TorrentStatus::Downloading => Cell::from(CONFIG.icons.download.as_str()),
                     TorrentStatus::Seeding => {
-                        if !self.upload_speed.is_empty() {
+                        if self.upload_speed != 0 {
                             Cell::from(CONFIG.icons.upload.as_str())
                         } else {
                             Cell::from(CONFIG.icons.success.as_str())
@@ -267,25 +313,76 @@ impl RustmissionTorrent {
                     }
                 }
             }
-            Header::Category => {
-                if let Some(category) = &self.category {
-                    match category {
-                        CategoryType::Plain(name) => Cell::from(name.as_str()),
-                        CategoryType::Config(category) => {
-                            Cell::from(category.name.as_str()).fg(category.color)
-                        }
-                    }
-                } else {
-                    Cell::default()
+            Header::Category => match &self.category {
+                Some(CategoryType::Config(category)) => {
+                    Cell::from(category.name.as_str()).fg(category.color)
                 }
-            }
-            Header::CategoryIcon => {
-                if let Some(CategoryType::Config(category)) = &self.category {
+                _ => Cell::from(self.header_text(header)),
+            },
+            Header::CategoryIcon => match &self.category {
+                Some(CategoryType::Config(category)) => {
                     Cell::from(category.icon.as_str()).fg(category.color)
-                } else {
-                    Cell::default()
                 }
+                _ => Cell::default(),
+            },
+            _ => Cell::from(self.header_text(header)),
+        }
+    }
+
+    /// Text of a column, without the icons and colors the table adds to some
+    /// of them. Also what `rustmission list` prints.
+    pub fn header_text(&self, header: Header) -> String {
+        match header {
+            Header::Name => self.torrent_name.clone(),
+            Header::SizeWhenDone => self.size_when_done(),
+            Header::Progress => self.progress(),
+            Header::Eta => self.eta_secs(),
+            Header::DownloadRate => self.download_speed(),
+            Header::UploadRate => self.upload_speed(),
+            Header::DownloadDir => self.download_dir.clone(),
+            Header::Padding => String::new(),
+            Header::Id => match &self.id {
+                Id::Id(id) => id.to_string(),
+                Id::Hash(hash) => hash.clone(),
+            },
+            Header::UploadRatio => self.upload_ratio.clone(),
+            Header::UploadedEver => self.uploaded_ever(),
+            Header::ActivityDate => time_format(self.activity_date),
+            Header::AddedDate => time_format(self.added_date),
+            Header::PeersConnected => self.peers_connected.to_string(),
+            Header::QueuePosition => (self.queue_position + 1).to_string(),
+            Header::Status | Header::SmallStatus => self.status_text().to_string(),
+            Header::Seeders => peer_count_format(self.seeders),
+            Header::Leechers => peer_count_format(self.leechers),
+            Header::HashString => self.hash_string.clone(),
+            Header::DoneDate => self.done_date.map(time_format).unwrap_or_default(),
+            Header::SecondsSeeding => duration_format(self.seconds_seeding),
+            Header::SecondsDownloading => duration_format(self.seconds_downloading),
+            Header::TotalSize => bytes_to_human_format(self.total_size),
+            Header::DownloadedEver => {
+                bytes_to_human_format(i64::try_from(self.downloaded_ever).unwrap_or(i64::MAX))
             }
+            Header::LeftUntilDone => match self.left_until_done {
+                0 => String::default(),
+                left => bytes_to_human_format(left),
+            },
+            Header::Labels => self.labels.join(", "),
+            Header::TrackerHost => self.tracker_hosts.first().cloned().unwrap_or_default(),
+            Header::Private => if self.is_private { "yes" } else { "" }.to_string(),
+            Header::RatioLimit => match self.ratio_limit {
+                RatioLimit::Single(limit) => format!("{limit:.1}"),
+                RatioLimit::Global => "global".to_string(),
+                RatioLimit::Unlimited => "∞".to_string(),
+            },
+            Header::Category => self
+                .category
+                .as_ref()
+                .map(|category| category.name().to_string())
+                .unwrap_or_default(),
+            Header::CategoryIcon => match &self.category {
+                Some(CategoryType::Config(category)) => category.icon.clone(),
+                _ => String::default(),
+            },
         }
     }
 
@@ -294,18 +391,55 @@ impl RustmissionTorrent {
     }
 
     pub fn update_status(&mut self, new_status: TorrentStatus) {
-        if self.error.is_some() {
-            self.style = Style::default().red();
-        } else if new_status == TorrentStatus::Stopped {
-            self.style = Style::default().dark_gray().italic();
-        } else {
-            self.style = Style::default();
-        }
-
+        self.style = status_style(new_status, self.error.is_some());
         self.status = new_status;
     }
 }
 
+#[cfg(test)]
+impl RustmissionTorrent {
+    /// A stopped, empty torrent that doesn't need the config to be built.
+    pub fn for_tests(name: &str) -> Self {
+        Self {
+            torrent_name: name.to_string(),
+            size_when_done: 0,
+            progress: 0.0,
+            recheck_progress: 0.0,
+            eta_secs: -1,
+            download_speed: 0,
+            upload_speed: 0,
+            uploaded_ever: 0,
+            downloaded_ever: 0,
+            upload_ratio: String::new(),
+            ratio: 0.0,
+            status: TorrentStatus::Stopped,
+            style: Style::default(),
+            id: Id::Id(1),
+            download_dir: String::new(),
+            activity_date: DateTime::UNIX_EPOCH,
+            added_date: DateTime::UNIX_EPOCH,
+            peers_connected: 0,
+            queue_position: 0,
+            seeders: -1,
+            leechers: -1,
+            hash_string: String::new(),
+            done_date: None,
+            seconds_seeding: 0,
+            seconds_downloading: 0,
+            total_size: 0,
+            left_until_done: 0,
+            labels: vec![],
+            is_private: false,
+            ratio_limit: RatioLimit::Global,
+            is_stalled: false,
+            tracker_hosts: vec![],
+            category: None,
+            error: None,
+            is_selected: false,
+        }
+    }
+}
+
 impl From<Torrent> for RustmissionTorrent {
     fn from(t: Torrent) -> Self {
         let id = t.id().expect("id requested");
@@ -316,33 +450,32 @@ impl From<Torrent> for RustmissionTorrent {
 
         let progress = t.percent_done.expect("field requested");
 
-        let eta_secs = t.eta.expect("field requested");
+        let recheck_progress = t.recheck_progress.expect("field requested");
+
+        // Fields only some headers need aren't always requested.
+        let eta_secs = t.eta.unwrap_or(-1);
 
         let download_speed = t.rate_download.expect("field requested");
 
-        let upload_speed = match t.rate_upload.expect("field requested") {
-            0 => String::default(),
-            upload => bytes_to_human_format(upload),
-        };
+        let upload_speed = t.rate_upload.expect("field requested");
 
         let status = t.status.expect("field requested");
 
         let download_dir = t.download_dir.clone().expect("field requested");
 
-        let file_stats = t.file_stats.expect("field requested");
+        let uploaded_ever = t.uploaded_ever.expect("field requested");
 
-        let uploaded_ever = bytes_to_human_format(t.uploaded_ever.expect("field requested"));
+        let downloaded_ever = t.downloaded_ever.expect("field requested");
 
-        let upload_ratio = {
-            let raw = t.upload_ratio.expect("field requested");
-            format!("{:.1}", raw)
-        };
+        let ratio = t.upload_ratio.expect("field requested");
+
+        let upload_ratio = format!("{:.1}", ratio);
 
         let activity_date = t.activity_date.expect("field requested");
 
         let added_date = t.added_date.expect("field requested");
 
-        let peers_connected = t.peers_connected.expect("field requested");
+        let peers_connected = t.peers_connected.unwrap_or_default();
 
         let error = {
             if t.error.expect("field requested") != ErrorType::Ok {
@@ -352,18 +485,60 @@ impl From<Torrent> for RustmissionTorrent {
             }
         };
 
-        let style = {
-            if error.is_some() {
-                Style::default().red()
-            } else {
-                match status {
-                    TorrentStatus::Stopped => Style::default().dark_gray().italic(),
-                    _ => Style::default(),
+        let style = status_style(status, error.is_some());
+
+        let queue_position = t.queue_position.unwrap_or_default();
+
+        let tracker_stats = t.tracker_stats.unwrap_or_default();
+        let seeders = tracker_stats
+            .iter()
+            .map(|stat| stat.seeder_count)
+            .max()
+            .unwrap_or(-1);
+        let leechers = tracker_stats
+            .iter()
+            .map(|stat| stat.leecher_count)
+            .max()
+            .unwrap_or(-1);
+
+        let hash_string = t.hash_string.unwrap_or_default();
+
+        // Transmission reports torrents that haven't finished yet as done at 0.
+        let done_date = t.done_date.filter(|date| date.timestamp() > 0);
+
+        let seconds_seeding = t.seconds_seeding.unwrap_or_default();
+
+        let seconds_downloading = t
+            .seconds_downloading
+            .map_or(0, |seconds| i64::try_from(seconds).unwrap_or(i64::MAX));
+
+        let total_size = t.total_size.unwrap_or_default();
+
+        let left_until_done = t.left_until_done.unwrap_or_default();
+
+        let is_private = t.is_private.unwrap_or_default();
+
+        let ratio_limit = match t.seed_ratio_mode {
+            Some(RatioMode::Single) => RatioLimit::Single(t.seed_ratio_limit.unwrap_or_default()),
+            Some(RatioMode::Unlimited) => RatioLimit::Unlimited,
+            Some(RatioMode::Global) | None => RatioLimit::Global,
+        };
+
+        let is_stalled = t.is_stalled.expect("field requested");
+
+        // Kept in announce order, so the first one is the primary tracker.
+        let mut tracker_hosts: Vec<String> = vec![];
+        for tracker in t.trackers.expect("field requested") {
+            if let Some(host) = tracker_host(&tracker.announce) {
+                if !tracker_hosts.contains(&host) {
+                    tracker_hosts.push(host);
                 }
             }
-        };
+        }
 
-        let category = if let Some(category) = t.labels.unwrap().first() {
+        let labels = t.labels.expect("field requested");
+
+        let category = if let Some(category) = labels.first() {
             match CONFIG.categories.map.get(category) {
                 Some(category) => Some(CategoryType::Config(category.clone())),
                 None => Some(CategoryType::Plain(category.to_string())),
@@ -376,6 +551,7 @@ impl From<Torrent> for RustmissionTorrent {
             torrent_name,
             size_when_done,
             progress,
+            recheck_progress,
             eta_secs,
             download_speed,
             upload_speed,
@@ -383,12 +559,27 @@ impl From<Torrent> for RustmissionTorrent {
             style,
             id,
             download_dir,
-            file_stats,
             uploaded_ever,
+            downloaded_ever,
             upload_ratio,
+            ratio,
             activity_date,
             added_date,
             peers_connected,
+            queue_position,
+            seeders,
+            leechers,
+            hash_string,
+            done_date,
+            seconds_seeding,
+            seconds_downloading,
+            total_size,
+            left_until_done,
+            labels,
+            is_private,
+            ratio_limit,
+            is_stalled,
+            tracker_hosts,
             category,
             error,
             is_selected: false,
@@ -396,12 +587,41 @@ impl From<Torrent> for RustmissionTorrent {
     }
 }
 
-fn time_to_line<'a>(time: DateTime<Utc>) -> Line<'a> {
+fn status_style(status: TorrentStatus, has_error: bool) -> Style {
+    if has_error {
+        return Style::default().red();
+    }
+
+    match status {
+        TorrentStatus::Stopped => Style::default().dark_gray().italic(),
+        TorrentStatus::QueuedToVerify
+        | TorrentStatus::QueuedToDownload
+        | TorrentStatus::QueuedToSeed => Style::default().dark_gray(),
+        _ => Style::default(),
+    }
+}
+
+fn time_format(time: DateTime<Utc>) -> String {
     let today = chrono::Local::now();
     if time.year() == today.year() && time.month() == today.month() && time.day() == today.day() {
-        Line::from(time.format("Today %H:%M").to_string())
+        time.format("Today %H:%M").to_string()
     } else {
-        Line::from(time.format("%y|%m|%d %H:%M").to_string())
+        time.format("%y|%m|%d %H:%M").to_string()
+    }
+}
+
+fn duration_format(seconds: i64) -> String {
+    match seconds {
+        0 => String::default(),
+        seconds => seconds_to_human_format(seconds),
+    }
+}
+
+/// Trackers report -1 when they don't know.
+fn peer_count_format(count: i64) -> String {
+    match count {
+        -1 => String::default(),
+        count => count.to_string(),
     }
 }
 
@@ -0,0 +1,120 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Cell, Row, Table, TableState},
+};
+use rm_config::CONFIG;
+use rm_shared::action::Action;
+
+use super::{
+    facets::{Facet, Facets},
+    rustmission_torrent::RustmissionTorrent,
+};
+
+pub const SIDEBAR_WIDTH: u16 = 30;
+
+pub struct Sidebar {
+    pub is_visible: bool,
+    pub is_focused: bool,
+    entries: Vec<(Facet, usize)>,
+    selected: usize,
+    table_state: TableState,
+}
+
+impl Sidebar {
+    pub fn new() -> Self {
+        Self {
+            is_visible: false,
+            is_focused: false,
+            entries: vec![],
+            selected: 0,
+            table_state: TableState::default(),
+        }
+    }
+
+    pub fn update(&mut self, torrents: &[RustmissionTorrent], facets: &Facets) {
+        self.entries = facets.counts(torrents);
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+
+    fn move_selection(&mut self, by: isize) {
+        let last = self.entries.len() as isize - 1;
+        self.selected = (self.selected as isize + by).clamp(0, last.max(0)) as usize;
+    }
+
+    /// Returns whether the picked facets have changed.
+    pub fn handle_action(&mut self, action: Action, facets: &mut Facets) -> bool {
+        use Action as A;
+
+        match action {
+            A::Up => self.move_selection(-1),
+            A::Down => self.move_selection(1),
+            A::ScrollUpBy(amount) => self.move_selection(-isize::from(amount)),
+            A::ScrollDownBy(amount) => self.move_selection(isize::from(amount)),
+            A::ScrollUpPage => self.move_selection(-10),
+            A::ScrollDownPage => self.move_selection(10),
+            A::Home => self.selected = 0,
+            A::End => self.selected = self.entries.len().saturating_sub(1),
+            A::Select | A::Confirm => {
+                if let Some((facet, _)) = self.entries.get(self.selected) {
+                    facets.toggle(facet.clone());
+                    return true;
+                }
+            }
+            A::Delete if !facets.is_empty() => {
+                facets.clear();
+                return true;
+            }
+            _ => (),
+        }
+
+        false
+    }
+
+    pub fn render(&mut self, f: &mut Frame, rect: Rect, facets: &Facets) {
+        let accent_color = CONFIG.general.accent_color;
+
+        let mut block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title(" Filters ");
+        if self.is_focused {
+            block = block.border_style(Style::default().fg(accent_color));
+        }
+
+        let mut rows = vec![];
+        let mut selected_row = 0;
+        let mut last_group = None;
+
+        for (i, (facet, count)) in self.entries.iter().enumerate() {
+            if last_group != Some(facet.group()) {
+                rows.push(Row::new([Cell::from(facet.group().name())
+                    .bold()
+                    .underlined()]));
+                last_group = Some(facet.group());
+            }
+
+            if i == self.selected {
+                selected_row = rows.len();
+            }
+
+            let is_active = facets.is_active(facet);
+            let row = Row::new([
+                Cell::from(format!(
+                    "{} {}",
+                    if is_active { "[x]" } else { "[ ]" },
+                    facet.name()
+                )),
+                Cell::from(Line::from(count.to_string()).right_aligned()),
+            ]);
+            rows.push(if is_active { row.fg(accent_color) } else { row });
+        }
+
+        self.table_state
+            .select(self.is_focused.then_some(selected_row));
+
+        let table = Table::new(rows, [Constraint::Fill(1), Constraint::Length(5)])
+            .block(block)
+            .row_highlight_style(Style::default().fg(accent_color).on_black().bold());
+
+        f.render_stateful_widget(table, rect, &mut self.table_state);
+    }
+}
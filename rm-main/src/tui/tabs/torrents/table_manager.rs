@@ -1,23 +1,48 @@
-use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use chrono::Utc;
 use ratatui::{prelude::*, widgets::Row};
-use rm_config::CONFIG;
+use rm_config::{main_config::GroupBy, CONFIG};
 use rm_shared::header::Header;
-use std::{cmp::Ordering, collections::HashMap};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 use transmission_rpc::types::Id;
 
 use crate::tui::components::GenericTable;
 
-use super::rustmission_torrent::RustmissionTorrent;
+use super::{
+    facets::Facets,
+    groups::{self, Group, GroupedRow},
+    query::Query,
+    rustmission_torrent::RustmissionTorrent,
+    TABLE_HEADERS,
+};
 
 pub struct TableManager {
     pub table: GenericTable<RustmissionTorrent>,
     pub widths: Vec<Constraint>,
     pub filter: Option<Filter>,
+    pub facets: Facets,
     pub torrents_displaying_no: u16,
     pub sort_header: Option<usize>,
+    /// Headers sorted by before the one at `sort_header`.
+    pub pinned_sort: Vec<Header>,
     pub sort_reverse: bool,
     pub sorting_is_being_selected: bool,
     pub selected_torrents_ids: Vec<i64>,
+    /// Torrent the visual range started at, while one's being selected.
+    visual_anchor: Option<i64>,
+    /// Selection from before the visual range started.
+    visual_base: Vec<i64>,
+    pub group_by: GroupBy,
+    groups: Vec<Group>,
+    grouped_rows: Vec<GroupedRow>,
+    // Remembered for the whole session, across regrouping.
+    collapsed_groups: HashSet<(GroupBy, String)>,
+    headers: Vec<Header>,
+    column_widths: HashMap<Header, u16>,
+    default_sort: Vec<Header>,
+    default_sort_reverse: bool,
 }
 
 pub struct Filter {
@@ -29,17 +54,97 @@ pub struct Filter {
 impl TableManager {
     pub fn new() -> Self {
         let table = GenericTable::new(vec![]);
-        let widths = Self::default_widths(&CONFIG.torrents_tab.headers);
+        let headers = CONFIG.torrents_tab.headers.clone();
+        let column_widths = CONFIG.torrents_tab.column_widths.clone();
+        let widths = Self::default_widths(&headers, &column_widths);
 
-        Self {
+        let table_manager = Self {
             table,
             widths,
             filter: None,
+            facets: Facets::default(),
             torrents_displaying_no: 0,
             sort_header: None,
+            pinned_sort: vec![],
             sort_reverse: false,
             sorting_is_being_selected: false,
             selected_torrents_ids: vec![],
+            visual_anchor: None,
+            visual_base: vec![],
+            group_by: CONFIG.torrents_tab.group_by,
+            groups: vec![],
+            grouped_rows: vec![],
+            collapsed_groups: HashSet::new(),
+            headers,
+            column_widths,
+            default_sort: CONFIG.torrents_tab.default_sort.clone(),
+            default_sort_reverse: CONFIG.torrents_tab.default_sort_reverse,
+        };
+        table_manager.publish_headers();
+        table_manager
+    }
+
+    /// Lets the fetcher know which fields the table needs. Headers it's sorted
+    /// by can be hidden, e.g. pinned ones after the columns got changed.
+    fn publish_headers(&self) {
+        let mut headers = self.headers.clone();
+        headers.extend(&self.default_sort);
+        headers.extend(self.current_sort().0);
+        *TABLE_HEADERS.write().unwrap() = headers;
+    }
+
+    pub fn set_headers(&mut self, headers: Vec<Header>) {
+        self.headers = headers;
+        self.widths = self.header_widths(&self.table.items);
+        // Sort header is an index into the old headers.
+        self.sort_header = None;
+        self.pinned_sort.clear();
+        self.sorting_is_being_selected = false;
+        self.publish_headers();
+    }
+
+    /// Changes the columns while keeping the current sort wherever possible.
+    pub fn set_columns(&mut self, headers: Vec<Header>, column_widths: HashMap<Header, u16>) {
+        let sort_header = self.sort_header.map(|idx| self.headers[idx]);
+        self.headers = headers;
+        self.column_widths = column_widths;
+        self.widths = self.header_widths(&self.table.items);
+
+        if let Some(sort_header) = sort_header {
+            self.sort_header = self.headers.iter().position(|h| *h == sort_header);
+            if self.sort_header.is_none() {
+                self.pinned_sort.clear();
+                self.sorting_is_being_selected = false;
+                self.sort();
+                self.refilter();
+            }
+        }
+        self.publish_headers();
+    }
+
+    pub fn column_widths(&self) -> &HashMap<Header, u16> {
+        &self.column_widths
+    }
+
+    /// Sort used when no column is picked with sorting selection.
+    pub fn set_default_sort(&mut self, headers: Vec<Header>, reverse: bool) {
+        self.default_sort = headers;
+        self.default_sort_reverse = reverse;
+        self.sort();
+        self.refilter();
+    }
+
+    /// Headers and direction the items are currently sorted by.
+    pub fn current_sort(&self) -> (Vec<Header>, bool) {
+        match self.sort_header {
+            Some(idx) => {
+                let mut headers = self.pinned_sort.clone();
+                if !headers.contains(&self.headers[idx]) {
+                    headers.push(self.headers[idx]);
+                }
+                (headers, self.sort_reverse)
+            }
+            None => (self.default_sort.clone(), self.default_sort_reverse),
         }
     }
 
@@ -59,6 +164,7 @@ impl TableManager {
     pub fn leave_sorting(&mut self) {
         self.sorting_is_being_selected = false;
         self.sort_header = None;
+        self.pinned_sort.clear();
         self.sort();
     }
 
@@ -94,90 +200,62 @@ impl TableManager {
         }
     }
 
-    pub fn sort(&mut self) {
-        let sort_by = self
-            .sort_header
-            .map(|idx| CONFIG.torrents_tab.headers[idx])
-            .unwrap_or(CONFIG.torrents_tab.default_sort);
-
-        match sort_by {
-            Header::Id => todo!(),
-            Header::Name => self.table.items.sort_by(|x, y| {
-                x.torrent_name
-                    .to_lowercase()
-                    .cmp(&y.torrent_name.to_lowercase())
-            }),
-            Header::SizeWhenDone => self
-                .table
-                .items
-                .sort_by(|x, y| x.size_when_done.cmp(&y.size_when_done)),
-            Header::Progress => self.table.items.sort_unstable_by(|x, y| {
-                x.progress
-                    .partial_cmp(&y.progress)
-                    .unwrap_or(Ordering::Equal)
-            }),
-            Header::Eta => self.table.items.sort_by(|x, y| x.eta_secs.cmp(&y.eta_secs)),
-            Header::DownloadRate => self
-                .table
-                .items
-                .sort_by(|x, y| x.download_speed.cmp(&y.download_speed)),
-            Header::UploadRate => self
-                .table
-                .items
-                .sort_by(|x, y| x.upload_speed.cmp(&y.upload_speed)),
-            Header::DownloadDir => self
-                .table
-                .items
-                .sort_by(|x, y| x.download_dir.cmp(&y.download_dir)),
-            Header::Padding => (),
-            Header::UploadRatio => self
-                .table
-                .items
-                .sort_by(|x, y| x.upload_ratio.cmp(&y.upload_ratio)),
-            Header::UploadedEver => self
-                .table
-                .items
-                .sort_by(|x, y| x.uploaded_ever.cmp(&y.uploaded_ever)),
-            Header::ActivityDate => self
-                .table
-                .items
-                .sort_by(|x, y| x.activity_date.cmp(&y.activity_date)),
-            Header::AddedDate => self
-                .table
-                .items
-                .sort_by(|x, y| x.added_date.cmp(&y.added_date)),
-            Header::PeersConnected => self
-                .table
-                .items
-                .sort_by(|x, y| x.peers_connected.cmp(&y.peers_connected)),
-            Header::SmallStatus => (),
-            Header::Category => self.table.items.sort_by(|x, y| {
-                x.category
-                    .as_ref()
-                    .map(|cat| {
-                        cat.name().cmp(
-                            y.category
-                                .as_ref()
-                                .map(|cat| cat.name())
-                                .unwrap_or_default(),
-                        )
-                    })
-                    .unwrap_or(Ordering::Less)
-            }),
-            Header::CategoryIcon => (),
-        }
-        if self.sort_reverse
-            || (self.sort_header.is_none() && CONFIG.torrents_tab.default_sort_reverse)
-        {
-            self.table.items.reverse();
+    /// Keeps sorting by the selected column and moves on to pick the next one.
+    pub fn append_sort_column_left(&mut self) {
+        self.pin_sort_column();
+        self.move_to_column_left();
+    }
+
+    pub fn append_sort_column_right(&mut self) {
+        self.pin_sort_column();
+        self.move_to_column_right();
+    }
+
+    fn pin_sort_column(&mut self) {
+        if let Some(idx) = self.sort_header {
+            let header = self.headers[idx];
+            if !self.pinned_sort.contains(&header) {
+                self.pinned_sort.push(header);
+            }
+        }
+    }
+
+    /// Sorts by the column at `idx`, or reverses the sort if it's sorted by it already.
+    pub fn sort_by_column(&mut self, idx: usize) {
+        if self.sort_header == Some(idx) && self.pinned_sort.is_empty() {
+            self.sort_reverse = !self.sort_reverse;
+        } else {
+            self.sort_header = Some(idx);
+            self.sort_reverse = false;
+            self.pinned_sort.clear();
         }
+        self.sort();
+        self.refilter();
+    }
+
+    pub fn sort(&mut self) {
+        self.publish_headers();
+        let (sort_by, reverse) = self.current_sort();
+
+        self.table.items.sort_by(|x, y| {
+            let ordering = sort_by.iter().fold(Ordering::Equal, |ordering, header| {
+                ordering.then_with(|| compare(*header, x, y))
+            });
+            if reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
     }
 
     pub fn update_rows_number(&mut self) {
-        if let Some(filter) = &self.filter {
+        if self.group_by != GroupBy::None {
+            self.table.overwrite_len(self.grouped_rows.len());
+        } else if let Some(filter) = &self.filter {
             self.table.overwrite_len(filter.indexes.len());
         } else {
-            self.table.items.len();
+            self.table.overwrite_len(self.table.items.len());
         }
     }
 
@@ -204,49 +282,316 @@ impl TableManager {
         }
     }
 
-    pub fn rows(&self) -> Vec<Row<'_>> {
-        if let Some(filter) = &self.filter {
-            let highlight_style = Style::default().fg(CONFIG.general.accent_color);
-            let headers = &CONFIG.torrents_tab.headers;
-            let mut rows = vec![];
-            for (i, which_torrent) in filter.indexes.iter().enumerate() {
-                let row = self.table.items[*which_torrent as usize].to_row_with_higlighted_indices(
-                    &filter.highlight_indices[i],
-                    highlight_style,
-                    headers,
-                );
-                rows.push(row);
+    /// Selects every torrent displayed between the table rows `from` and `to`.
+    pub fn select_range(&mut self, from: usize, to: usize) {
+        for row in from.min(to)..=from.max(to) {
+            if let Some(item) = self.item_at(row) {
+                self.set_selected(item, true);
             }
+        }
+    }
 
-            self.table.overwrite_len(rows.len());
-            rows
-        } else {
-            self.table
-                .items
+    /// Selects every torrent displayed.
+    pub fn select_all(&mut self) {
+        for item in self.displayed_items() {
+            self.set_selected(item, true);
+        }
+    }
+
+    /// Flips the selection of every torrent displayed. Torrents hidden by the
+    /// filter keep theirs.
+    pub fn invert_selection(&mut self) {
+        for item in self.displayed_items() {
+            let is_selected = !self.table.items[item].is_selected;
+            self.set_selected(item, is_selected);
+        }
+    }
+
+    /// Selects every torrent displayed that's in the same category as the
+    /// highlighted one.
+    pub fn select_same_category(&mut self) {
+        let category = |torrent: &RustmissionTorrent| {
+            torrent
+                .category
+                .as_ref()
+                .map(|category| category.name().to_string())
+        };
+        let Some(current) = self.current_torrent().map(|torrent| category(torrent)) else {
+            return;
+        };
+
+        for item in self.displayed_items() {
+            if category(&self.table.items[item]) == current {
+                self.set_selected(item, true);
+            }
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.stop_visual_selection();
+        for torrent in &mut self.table.items {
+            torrent.is_selected = false;
+        }
+        self.selected_torrents_ids.clear();
+    }
+
+    /// Starts selecting every torrent between the highlighted one and
+    /// wherever the cursor moves to, on top of the current selection. On a
+    /// group header, the range starts at the group's first torrent.
+    pub fn start_visual_selection(&mut self) {
+        let Some(cursor) = self.table.state.borrow().selected() else {
+            return;
+        };
+        let Some(item) = (cursor..self.table.get_len()).find_map(|row| self.item_at(row)) else {
+            return;
+        };
+        let Id::Id(id) = self.table.items[item].id else {
+            unreachable!();
+        };
+
+        self.visual_anchor = Some(id);
+        self.visual_base = self.selected_torrents_ids.clone();
+        self.update_visual_selection();
+    }
+
+    /// Leaves the visual range selected.
+    pub fn stop_visual_selection(&mut self) {
+        self.visual_anchor = None;
+        self.visual_base.clear();
+    }
+
+    pub fn is_visual_selecting(&self) -> bool {
+        self.visual_anchor.is_some()
+    }
+
+    /// Reselects the visual range after the cursor or the rows moved.
+    pub fn update_visual_selection(&mut self) {
+        let Some(anchor) = self.visual_anchor else {
+            return;
+        };
+        let Some(cursor) = self.table.state.borrow().selected() else {
+            return;
+        };
+        let anchor_row = (0..self.table.get_len()).find(|row| {
+            self.item_at(*row)
+                .is_some_and(|item| matches!(self.table.items[item].id, Id::Id(id) if id == anchor))
+        });
+        // The anchor got filtered out or removed.
+        let Some(anchor_row) = anchor_row else {
+            self.stop_visual_selection();
+            return;
+        };
+
+        let base: HashSet<i64> = self.visual_base.iter().copied().collect();
+        self.selected_torrents_ids.clear();
+        for torrent in &mut self.table.items {
+            let Id::Id(id) = torrent.id else {
+                unreachable!();
+            };
+            torrent.is_selected = base.contains(&id);
+            if torrent.is_selected {
+                self.selected_torrents_ids.push(id);
+            }
+        }
+        self.select_range(anchor_row, cursor);
+    }
+
+    /// Combined size of the selected torrents.
+    pub fn selected_size(&self) -> i64 {
+        self.table
+            .items
+            .iter()
+            .filter(|torrent| torrent.is_selected)
+            .map(|torrent| torrent.size_when_done)
+            .sum()
+    }
+
+    fn set_selected(&mut self, item: usize, is_selected: bool) {
+        let torrent = &mut self.table.items[item];
+        let Id::Id(id) = torrent.id else {
+            unreachable!();
+        };
+
+        torrent.is_selected = is_selected;
+        let position = self.selected_torrents_ids.iter().position(|&x| x == id);
+        match (is_selected, position) {
+            (true, None) => self.selected_torrents_ids.push(id),
+            (false, Some(position)) => {
+                self.selected_torrents_ids.remove(position);
+            }
+            _ => (),
+        }
+    }
+
+    /// Index into the items of every torrent displayed, skipping group headers.
+    fn displayed_items(&self) -> Vec<usize> {
+        (0..self.table.get_len())
+            .filter_map(|row| self.item_at(row))
+            .collect()
+    }
+
+    /// Table row displayed at `position`.
+    pub fn row_at(&self, position: Position) -> Option<usize> {
+        self.table.row_at(position, |row| match self.item_at(row) {
+            Some(item) if self.table.items[item].error.is_some() => 2,
+            _ => 1,
+        })
+    }
+
+    pub fn rows(&self) -> Vec<Row<'_>> {
+        let highlight_style = Style::default().fg(CONFIG.general.accent_color);
+
+        let rows: Vec<_> = if self.group_by != GroupBy::None {
+            self.grouped_rows
                 .iter()
-                .map(|t| t.to_row(&CONFIG.torrents_tab.headers))
+                .map(|row| match row {
+                    GroupedRow::Header(idx) => self.groups[*idx].to_row(&self.headers),
+                    GroupedRow::Torrent(position) => self.torrent_row(*position, highlight_style),
+                })
                 .collect()
+        } else {
+            (0..self.visible_len())
+                .map(|position| self.torrent_row(position, highlight_style))
+                .collect()
+        };
+
+        self.table.overwrite_len(rows.len());
+        rows
+    }
+
+    fn torrent_row(&self, position: usize, highlight_style: Style) -> Row<'_> {
+        if let Some(filter) = &self.filter {
+            self.table.items[filter.indexes[position] as usize].to_row_with_higlighted_indices(
+                &filter.highlight_indices[position],
+                highlight_style,
+                &self.headers,
+            )
+        } else {
+            self.table.items[position].to_row(&self.headers)
         }
     }
 
-    pub fn headers(&self) -> &Vec<Header> {
-        &CONFIG.torrents_tab.headers
+    /// How many torrents made it through the filter.
+    fn visible_len(&self) -> usize {
+        match &self.filter {
+            Some(filter) => filter.indexes.len(),
+            None => self.table.items.len(),
+        }
     }
 
-    pub fn current_torrent(&mut self) -> Option<&mut RustmissionTorrent> {
+    /// Index into the items of the torrent at `position` among the visible ones.
+    fn visible_item(&self, position: usize) -> Option<usize> {
+        match &self.filter {
+            Some(filter) => filter.indexes.get(position).map(|idx| *idx as usize),
+            None => (position < self.table.items.len()).then_some(position),
+        }
+    }
+
+    /// Index into the items of the torrent displayed at table row `row`.
+    fn item_at(&self, row: usize) -> Option<usize> {
+        if self.group_by == GroupBy::None {
+            return self.visible_item(row);
+        }
+
+        match self.grouped_rows.get(row)? {
+            GroupedRow::Header(_) => None,
+            GroupedRow::Torrent(position) => self.visible_item(*position),
+        }
+    }
+
+    fn current_group(&self) -> Option<usize> {
         let selected_idx = self.table.state.borrow().selected()?;
+        match self.grouped_rows.get(selected_idx)? {
+            GroupedRow::Header(idx) => Some(*idx),
+            GroupedRow::Torrent(_) => None,
+        }
+    }
 
-        if let Some(filter) = &self.filter {
-            if filter.indexes.is_empty() {
-                None
-            } else {
-                self.table
-                    .items
-                    .get_mut(filter.indexes[selected_idx] as usize)
+    pub fn is_group_highlighted(&self) -> bool {
+        self.group_by != GroupBy::None && self.current_group().is_some()
+    }
+
+    pub fn cycle_group_by(&mut self) {
+        self.group_by = self.group_by.next();
+        self.regroup();
+        self.update_rows_number();
+        self.table.state.borrow_mut().select(Some(0));
+    }
+
+    pub fn toggle_current_group(&mut self) {
+        let Some(idx) = self.current_group() else {
+            return;
+        };
+
+        let key = (self.group_by, self.groups[idx].name.clone());
+        if !self.collapsed_groups.remove(&key) {
+            self.collapsed_groups.insert(key);
+        }
+
+        self.regroup();
+        self.update_rows_number();
+        let header_row = self
+            .grouped_rows
+            .iter()
+            .position(|row| matches!(row, GroupedRow::Header(header) if *header == idx));
+        self.table.state.borrow_mut().select(header_row);
+    }
+
+    /// Selects every torrent of the highlighted group, or unselects them if
+    /// they're all selected already.
+    pub fn select_current_group(&mut self) {
+        let Some(idx) = self.current_group() else {
+            return;
+        };
+
+        let items: Vec<usize> = self.groups[idx]
+            .positions
+            .iter()
+            .filter_map(|position| self.visible_item(*position))
+            .collect();
+        let is_selected = !items.iter().all(|item| self.table.items[*item].is_selected);
+
+        for item in items {
+            self.set_selected(item, is_selected);
+        }
+    }
+
+    fn regroup(&mut self) {
+        self.grouped_rows.clear();
+
+        if self.group_by == GroupBy::None {
+            self.groups.clear();
+            return;
+        }
+
+        let visible = (0..self.visible_len())
+            .filter_map(|position| self.visible_item(position))
+            .map(|item| &self.table.items[item]);
+        let mut groups = groups::group(self.group_by, visible);
+
+        for (idx, group) in groups.iter_mut().enumerate() {
+            group.is_collapsed = self
+                .collapsed_groups
+                .contains(&(self.group_by, group.name.clone()));
+
+            self.grouped_rows.push(GroupedRow::Header(idx));
+            if !group.is_collapsed {
+                self.grouped_rows
+                    .extend(group.positions.iter().map(|p| GroupedRow::Torrent(*p)));
             }
-        } else {
-            self.table.items.get_mut(selected_idx)
         }
+
+        self.groups = groups;
+    }
+
+    pub fn headers(&self) -> &Vec<Header> {
+        &self.headers
+    }
+
+    pub fn current_torrent(&mut self) -> Option<&mut RustmissionTorrent> {
+        let selected_idx = self.table.state.borrow().selected()?;
+        let idx = self.item_at(selected_idx)?;
+        self.table.items.get_mut(idx)
     }
 
     pub fn set_new_rows(&mut self, mut rows: Vec<RustmissionTorrent>) {
@@ -274,8 +619,10 @@ impl TableManager {
 
         self.table.set_items(rows);
         self.widths = self.header_widths(&self.table.items);
-        self.update_rows_number();
         self.sort();
+        // Sorting reorders the items, so the filtered indexes have to follow.
+        self.refilter();
+        self.update_visual_selection();
 
         let mut state = self.table.state.borrow_mut();
         if state.selected().is_none() && !self.table.items.is_empty() {
@@ -283,31 +630,90 @@ impl TableManager {
         }
     }
 
-    pub fn set_filter(&mut self, filter: String) {
-        let matcher = SkimMatcherV2::default();
+    pub fn set_filter(&mut self, pattern: String) {
+        self.filter = Some(Filter {
+            pattern,
+            indexes: vec![],
+            highlight_indices: vec![],
+        });
+        self.refilter();
+    }
+
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+        self.refilter();
+    }
+
+    /// Applies the name pattern and the sidebar facets to the current items.
+    pub fn refilter(&mut self) {
+        let pattern = self
+            .filter
+            .take()
+            .map(|filter| filter.pattern)
+            .unwrap_or_default();
+
+        if pattern.is_empty() && self.facets.is_empty() {
+            self.regroup();
+            self.update_rows_number();
+            self.clamp_selection();
+            return;
+        }
+
+        // The search bar doesn't let invalid queries through, but in case one
+        // slips by it's still worth something as a plain fuzzy pattern.
+        let query = Query::parse(&pattern).unwrap_or_else(|_| Some(Query::Text(pattern.clone())));
+        let now = Utc::now();
+
         let mut indexes: Vec<u16> = vec![];
         let mut highlight_indices = vec![];
         for (i, torrent) in self.table.items.iter().enumerate() {
-            if let Some((_, indices)) = matcher.fuzzy_indices(&torrent.torrent_name, &filter) {
-                indexes.push(i as u16);
-                highlight_indices.push(indices);
+            if !self.facets.matches(torrent) {
+                continue;
+            }
+
+            match &query {
+                None => {
+                    indexes.push(i as u16);
+                    highlight_indices.push(vec![]);
+                }
+                Some(query) if query.matches(torrent, now) => {
+                    indexes.push(i as u16);
+                    highlight_indices.push(query.highlight_indices(&torrent.torrent_name));
+                }
+                Some(_) => (),
             }
         }
 
-        let filter = Filter {
-            pattern: filter,
+        self.filter = Some(Filter {
+            pattern,
             indexes,
             highlight_indices,
-        };
+        });
+        self.regroup();
+        self.update_rows_number();
+        self.clamp_selection();
+    }
 
-        self.filter = Some(filter);
+    fn clamp_selection(&mut self) {
+        let len = self.table.get_len();
+        let mut state = self.table.state.borrow_mut();
+        if let Some(selected) = state.selected() {
+            if selected >= len {
+                state.select(Some(len.saturating_sub(1)));
+            }
+        }
     }
 
-    fn default_widths(headers: &Vec<Header>) -> Vec<Constraint> {
+    fn default_widths(
+        headers: &Vec<Header>,
+        column_widths: &HashMap<Header, u16>,
+    ) -> Vec<Constraint> {
         let mut constraints = vec![];
 
         for header in headers {
-            if *header == Header::Category {
+            if let Some(width) = column_widths.get(header) {
+                constraints.push(Constraint::Length(*width))
+            } else if *header == Header::Category {
                 constraints.push(Constraint::Length(u16::from(
                     CONFIG.categories.max_name_len,
                 )))
@@ -323,16 +729,21 @@ impl TableManager {
     }
 
     fn header_widths(&self, rows: &[RustmissionTorrent]) -> Vec<Constraint> {
-        let headers = &CONFIG.torrents_tab.headers;
+        let headers = &self.headers;
 
         if !CONFIG.general.auto_hide {
-            return Self::default_widths(headers);
+            return Self::default_widths(headers, &self.column_widths);
         }
 
+        let default_constraint = |header: &Header| match self.column_widths.get(header) {
+            Some(width) => Constraint::Length(*width),
+            None => header.default_constraint(),
+        };
+
         let mut map = HashMap::new();
 
         for header in headers {
-            map.insert(header, header.default_constraint());
+            map.insert(header, default_constraint(header));
         }
 
         let hidable_headers = [
@@ -350,20 +761,20 @@ impl TableManager {
         for row in rows {
             if !row.download_speed().is_empty() {
                 map.entry(&Header::DownloadRate)
-                    .and_modify(|c| *c = Header::DownloadRate.default_constraint());
+                    .and_modify(|c| *c = default_constraint(&Header::DownloadRate));
             }
-            if !row.upload_speed.is_empty() {
+            if !row.upload_speed().is_empty() {
                 map.entry(&Header::UploadRate)
-                    .and_modify(|c| *c = Header::UploadRate.default_constraint());
+                    .and_modify(|c| *c = default_constraint(&Header::UploadRate));
             }
             if !row.progress().is_empty() {
                 map.entry(&Header::Progress)
-                    .and_modify(|c| *c = Header::Progress.default_constraint());
+                    .and_modify(|c| *c = default_constraint(&Header::Progress));
             }
 
             if !row.eta_secs().is_empty() {
                 map.entry(&Header::Eta)
-                    .and_modify(|c| *c = Header::Eta.default_constraint());
+                    .and_modify(|c| *c = default_constraint(&Header::Eta));
             }
         }
 
@@ -376,3 +787,51 @@ impl TableManager {
         constraints
     }
 }
+
+fn compare(header: Header, x: &RustmissionTorrent, y: &RustmissionTorrent) -> Ordering {
+    let status = |t: &RustmissionTorrent| (t.error.is_some(), t.status() as u8);
+    let category = |t: &RustmissionTorrent| t.category.as_ref().map(|cat| cat.name().to_string());
+
+    match header {
+        Header::Id => match (&x.id, &y.id) {
+            (Id::Id(x), Id::Id(y)) => x.cmp(y),
+            _ => Ordering::Equal,
+        },
+        Header::Name => x
+            .torrent_name
+            .to_lowercase()
+            .cmp(&y.torrent_name.to_lowercase()),
+        Header::SizeWhenDone => x.size_when_done.cmp(&y.size_when_done),
+        Header::Progress => x.progress.total_cmp(&y.progress),
+        Header::Eta => x.eta_secs.cmp(&y.eta_secs),
+        Header::DownloadRate => x.download_speed.cmp(&y.download_speed),
+        Header::UploadRate => x.upload_speed.cmp(&y.upload_speed),
+        Header::DownloadDir => x.download_dir.cmp(&y.download_dir),
+        Header::Padding => Ordering::Equal,
+        Header::UploadRatio => x.ratio.total_cmp(&y.ratio),
+        Header::UploadedEver => x.uploaded_ever.cmp(&y.uploaded_ever),
+        Header::ActivityDate => x.activity_date.cmp(&y.activity_date),
+        Header::AddedDate => x.added_date.cmp(&y.added_date),
+        Header::PeersConnected => x.peers_connected.cmp(&y.peers_connected),
+        Header::SmallStatus | Header::Status => status(x).cmp(&status(y)),
+        // Icons would only group torrents, names also order them.
+        Header::Category | Header::CategoryIcon => category(x).cmp(&category(y)),
+        Header::QueuePosition => x.queue_position.cmp(&y.queue_position),
+        Header::Seeders => x.seeders.cmp(&y.seeders),
+        Header::Leechers => x.leechers.cmp(&y.leechers),
+        Header::HashString => x.hash_string.cmp(&y.hash_string),
+        Header::DoneDate => x.done_date.cmp(&y.done_date),
+        Header::SecondsSeeding => x.seconds_seeding.cmp(&y.seconds_seeding),
+        Header::SecondsDownloading => x.seconds_downloading.cmp(&y.seconds_downloading),
+        Header::TotalSize => x.total_size.cmp(&y.total_size),
+        Header::DownloadedEver => x.downloaded_ever.cmp(&y.downloaded_ever),
+        Header::LeftUntilDone => x.left_until_done.cmp(&y.left_until_done),
+        Header::Labels => x.labels.cmp(&y.labels),
+        Header::TrackerHost => x.tracker_hosts.first().cmp(&y.tracker_hosts.first()),
+        Header::Private => x.is_private.cmp(&y.is_private),
+        Header::RatioLimit => x
+            .ratio_limit
+            .partial_cmp(&y.ratio_limit)
+            .unwrap_or(Ordering::Equal),
+    }
+}
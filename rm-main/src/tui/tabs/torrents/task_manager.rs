@@ -36,6 +36,7 @@ impl TaskManager {
 
 pub enum CurrentTask {
     AddMagnet(tasks::AddMagnet),
+    CreateTorrent(tasks::CreateTorrent),
     Delete(tasks::Delete),
     Filter(tasks::Filter),
     Move(tasks::Move),
@@ -45,6 +46,7 @@ pub enum CurrentTask {
     Sort(tasks::Sort),
     Selection(tasks::Selection),
     Rename(tasks::Rename),
+    SaveView(tasks::SaveView),
 }
 
 impl CurrentTask {
@@ -56,7 +58,6 @@ impl CurrentTask {
 }
 
 impl Component for TaskManager {
-    #[must_use]
     fn handle_actions(&mut self, action: Action) -> ComponentAction {
         match &mut self.current_task {
             CurrentTask::AddMagnet(magnet_bar) => {
@@ -64,6 +65,11 @@ impl Component for TaskManager {
                     self.cancel_task()
                 }
             }
+            CurrentTask::CreateTorrent(create_bar) => {
+                if create_bar.handle_actions(action).is_quit() {
+                    self.cancel_task()
+                }
+            }
             CurrentTask::Delete(delete_bar) => {
                 if delete_bar.handle_actions(action).is_quit() {
                     self.cancel_task()
@@ -94,6 +100,11 @@ impl Component for TaskManager {
                     self.cancel_task()
                 }
             }
+            CurrentTask::SaveView(save_view_bar) => {
+                if save_view_bar.handle_actions(action).is_quit() {
+                    self.cancel_task()
+                }
+            }
             CurrentTask::Default(_) => (),
             CurrentTask::Sort(_) => (),
             CurrentTask::Selection(_) => (),
@@ -123,6 +134,7 @@ impl Component for TaskManager {
     fn render(&mut self, f: &mut Frame, rect: Rect) {
         match &mut self.current_task {
             CurrentTask::AddMagnet(magnet_bar) => magnet_bar.render(f, rect),
+            CurrentTask::CreateTorrent(create_bar) => create_bar.render(f, rect),
             CurrentTask::Delete(delete_bar) => delete_bar.render(f, rect),
             CurrentTask::Move(move_bar) => move_bar.render(f, rect),
             CurrentTask::Filter(filter_bar) => filter_bar.render(f, rect),
@@ -132,6 +144,7 @@ impl Component for TaskManager {
             CurrentTask::Sort(sort_bar) => sort_bar.render(f, rect),
             CurrentTask::Selection(selection_bar) => selection_bar.render(f, rect),
             CurrentTask::Rename(rename_bar) => rename_bar.render(f, rect),
+            CurrentTask::SaveView(save_view_bar) => save_view_bar.render(f, rect),
         }
     }
 
@@ -146,6 +159,11 @@ impl TaskManager {
         CTX.send_update_action(UpdateAction::SwitchToInputMode);
     }
 
+    pub fn create_torrent(&mut self) {
+        self.current_task = CurrentTask::CreateTorrent(tasks::CreateTorrent::new());
+        CTX.send_update_action(UpdateAction::SwitchToInputMode);
+    }
+
     pub fn search(&mut self, current_pattern: &Option<String>) {
         self.current_task = CurrentTask::Filter(tasks::Filter::new(current_pattern));
         CTX.send_update_action(UpdateAction::SwitchToInputMode);
@@ -156,6 +174,11 @@ impl TaskManager {
         CTX.send_update_action(UpdateAction::SwitchToInputMode);
     }
 
+    pub fn save_view(&mut self, taken_names: Vec<String>) {
+        self.current_task = CurrentTask::SaveView(tasks::SaveView::new(taken_names));
+        CTX.send_update_action(UpdateAction::SwitchToInputMode);
+    }
+
     pub fn delete_torrents(&mut self, selection: TorrentSelection) {
         self.current_task = CurrentTask::Delete(tasks::Delete::new(selection));
         CTX.send_update_action(UpdateAction::SwitchToInputMode);
@@ -175,8 +198,8 @@ impl TaskManager {
         self.current_task = CurrentTask::Default(tasks::Default::new());
     }
 
-    pub fn select(&mut self, amount: usize) {
-        self.current_task = CurrentTask::Selection(tasks::Selection::new(amount));
+    pub fn select(&mut self, amount: usize, size: i64, is_visual: bool) {
+        self.current_task = CurrentTask::Selection(tasks::Selection::new(amount, size, is_visual));
     }
 
     pub fn sort(&mut self) {
@@ -191,7 +214,7 @@ impl TaskManager {
     }
 
     fn pending_task(&mut self, task: StatusTask) {
-        if matches!(self.current_task, CurrentTask::Status(_)) {
+        if self.is_status_task_in_progress() {
             return;
         }
 
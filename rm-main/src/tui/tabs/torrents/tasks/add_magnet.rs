@@ -1,17 +1,25 @@
+use std::path::Path;
+
+use base64::Engine;
 use crossterm::event::{KeyCode, KeyEvent};
+use intuitils::error_message::ErrorMessage;
 use ratatui::prelude::*;
 use rm_config::CONFIG;
+use transmission_rpc::types::TorrentAddArgs;
 
 use crate::{
+    metainfo::Metainfo,
     transmission::TorrentAction,
     tui::{
+        app_key_event::AppKeyEvent,
         components::{Component, ComponentAction, InputManager},
         ctx::CTX,
-        tabs::torrents::SESSION_GET,
+        tabs::torrents::{popups::torrent_preview::TorrentPreview, session_download_dir},
     },
 };
 use rm_shared::{
     action::{Action, UpdateAction},
+    current_window::{TorrentWindow, Window},
     status_task::StatusTask,
 };
 
@@ -19,6 +27,7 @@ pub struct AddMagnet {
     input_magnet_mgr: InputManager,
     input_category_mgr: InputManager,
     input_location_mgr: InputManager,
+    torrent_file: Option<Box<TorrentFile>>,
     stage: Stage,
 }
 
@@ -26,9 +35,15 @@ enum Stage {
     Magnet,
     Category,
     Location,
+    Preview,
+}
+
+struct TorrentFile {
+    content: Vec<u8>,
+    preview: TorrentPreview,
 }
 
-const MAGNET_PROMPT: &str = "Add magnet URI: ";
+const MAGNET_PROMPT: &str = "Add magnet URI or .torrent path: ";
 const CATEGORY_PROMPT: &str = "Category (empty for default): ";
 const LOCATION_PROMPT: &str = "Directory: ";
 
@@ -40,8 +55,9 @@ impl AddMagnet {
                 .autocompletions(CONFIG.categories.map.keys().cloned().collect()),
             input_location_mgr: InputManager::new_with_value(
                 LOCATION_PROMPT.to_string(),
-                SESSION_GET.get().unwrap().download_dir.clone(),
+                session_download_dir().unwrap_or_default(),
             ),
+            torrent_file: None,
             stage: Stage::Magnet,
         }
     }
@@ -62,11 +78,23 @@ impl AddMagnet {
             Stage::Magnet => self.handle_magnet_input(input),
             Stage::Category => self.handle_category_input(input),
             Stage::Location => self.handle_location_input(input),
+            Stage::Preview => self.handle_preview_input(input),
         }
     }
 
     fn handle_magnet_input(&mut self, input: KeyEvent) -> ComponentAction {
         if input.code == KeyCode::Enter {
+            let text = self.input_magnet_mgr.text();
+            if is_local_path(&text) {
+                match read_torrent_file(Path::new(&text)) {
+                    Ok(torrent_file) => self.torrent_file = Some(torrent_file),
+                    Err(err) => {
+                        CTX.send_update_action(UpdateAction::Error(err));
+                        return ComponentAction::Quit;
+                    }
+                }
+            }
+
             if CONFIG.categories.is_empty() {
                 self.stage = Stage::Location;
             } else {
@@ -98,14 +126,11 @@ impl AddMagnet {
             {
                 self.input_location_mgr = InputManager::new_with_value(
                     LOCATION_PROMPT.to_string(),
-                    category.default_dir.clone().unwrap_or_else(|| {
-                        SESSION_GET
-                            .get()
-                            .as_ref()
-                            .expect("session_get was already initialized")
-                            .download_dir
-                            .clone()
-                    }),
+                    category
+                        .default_dir
+                        .clone()
+                        .or_else(session_download_dir)
+                        .unwrap_or_default(),
                 );
                 self.stage = Stage::Location;
                 CTX.send_action(Action::Render);
@@ -132,7 +157,11 @@ impl AddMagnet {
     }
 
     fn handle_location_input(&mut self, input: KeyEvent) -> ComponentAction {
-        if input.code == KeyCode::Enter {
+        if input.code == KeyCode::Enter && self.torrent_file.is_some() {
+            self.stage = Stage::Preview;
+            CTX.send_action(Action::Render);
+            ComponentAction::Nothing
+        } else if input.code == KeyCode::Enter {
             let category = if self.input_category_mgr.text().is_empty() {
                 None
             } else {
@@ -159,10 +188,71 @@ impl AddMagnet {
             ComponentAction::Nothing
         }
     }
+
+    fn handle_preview_input(&mut self, input: KeyEvent) -> ComponentAction {
+        let torrent_file = self.torrent_file.as_mut().expect("set before preview");
+
+        // The preview works just like the file viewer, so it shares its keys.
+        let Some(action) =
+            AppKeyEvent::from(input).to_action(Window::Torrents(TorrentWindow::FileViewer))
+        else {
+            return ComponentAction::Nothing;
+        };
+
+        if action == Action::Confirm {
+            let category = self.input_category_mgr.text();
+
+            let mut args = TorrentAddArgs {
+                metainfo: Some(
+                    base64::engine::general_purpose::STANDARD.encode(&torrent_file.content),
+                ),
+                download_dir: Some(self.input_location_mgr.text()),
+                labels: (!category.is_empty()).then(|| vec![category]),
+                ..Default::default()
+            };
+            torrent_file.preview.apply_to(&mut args);
+
+            let name = torrent_file.preview.name().to_string();
+            CTX.send_torrent_action(TorrentAction::AddMetainfo(name.clone(), Box::new(args)));
+
+            let task = StatusTask::new_add(name);
+            CTX.send_update_action(UpdateAction::StatusTaskSet(task));
+
+            ComponentAction::Quit
+        } else if action.is_soft_quit() {
+            ComponentAction::Quit
+        } else {
+            if torrent_file.preview.handle_action(action) {
+                CTX.send_action(Action::Render);
+            }
+            ComponentAction::Nothing
+        }
+    }
+}
+
+fn is_local_path(input: &str) -> bool {
+    !["magnet:", "http:", "https:", "www"]
+        .iter()
+        .any(|prefix| input.starts_with(prefix))
+        && Path::new(input).is_file()
+}
+
+fn read_torrent_file(path: &Path) -> Result<Box<TorrentFile>, Box<ErrorMessage>> {
+    let error = |err: Box<dyn std::error::Error + Send + Sync>| {
+        let desc = format!("Failed to read \"{}\" as a .torrent file", path.display());
+        Box::new(ErrorMessage::new("Invalid torrent file", desc, err))
+    };
+
+    let content = std::fs::read(path).map_err(|err| error(Box::new(err)))?;
+    let metainfo = Metainfo::from_bytes(&content).map_err(|err| error(Box::new(err)))?;
+
+    Ok(Box::new(TorrentFile {
+        content,
+        preview: TorrentPreview::new(metainfo),
+    }))
 }
 
 impl Component for AddMagnet {
-    #[must_use]
     fn handle_actions(&mut self, action: Action) -> ComponentAction {
         match action {
             Action::Input(input) => self.handle_input(input),
@@ -175,6 +265,12 @@ impl Component for AddMagnet {
             Stage::Magnet => self.input_magnet_mgr.render(f, rect),
             Stage::Category => self.input_category_mgr.render(f, rect),
             Stage::Location => self.input_location_mgr.render(f, rect),
+            Stage::Preview => {
+                self.input_location_mgr.render(f, rect);
+                if let Some(torrent_file) = &mut self.torrent_file {
+                    torrent_file.preview.render(f, f.area());
+                }
+            }
         }
     }
 }
@@ -11,7 +11,7 @@ use crate::{
     tui::{
         components::{Component, ComponentAction, InputManager},
         ctx::CTX,
-        tabs::torrents::SESSION_GET,
+        tabs::torrents::session_download_dir,
     },
 };
 
@@ -110,15 +110,13 @@ impl ChangeCategory {
             let category = self.category_input_mgr.text();
 
             if let Some(config_category) = CONFIG.categories.map.get(&category) {
-                self.set_stage_directory(config_category.default_dir.clone().unwrap_or_else(
-                    || {
-                        SESSION_GET
-                            .get()
-                            .expect("session_get was not initialized yet")
-                            .download_dir
-                            .clone()
-                    },
-                ));
+                self.set_stage_directory(
+                    config_category
+                        .default_dir
+                        .clone()
+                        .or_else(session_download_dir)
+                        .unwrap_or_default(),
+                );
                 CTX.send_action(Action::Render);
                 return ComponentAction::Nothing;
             } else {
@@ -144,7 +142,6 @@ impl ChangeCategory {
 }
 
 impl Component for ChangeCategory {
-    #[must_use]
     fn handle_actions(&mut self, action: Action) -> ComponentAction {
         match action {
             Action::Input(input) => self.handle_input(input),
@@ -0,0 +1,194 @@
+use std::path::Path;
+
+use base64::Engine;
+use crossterm::event::{KeyCode, KeyEvent};
+use intuitils::error_message::ErrorMessage;
+use ratatui::prelude::*;
+use transmission_rpc::types::TorrentAddArgs;
+
+use crate::{
+    metainfo::{create_torrent, CreateOptions},
+    transmission::TorrentAction,
+    tui::{
+        components::{Component, ComponentAction, InputManager},
+        ctx::CTX,
+    },
+};
+use rm_shared::{
+    action::{Action, UpdateAction},
+    status_task::StatusTask,
+};
+
+pub struct CreateTorrent {
+    input_path_mgr: InputManager,
+    input_trackers_mgr: InputManager,
+    input_comment_mgr: InputManager,
+    input_private_mgr: InputManager,
+    input_add_mgr: InputManager,
+    stage: Stage,
+}
+
+enum Stage {
+    Path,
+    Trackers,
+    Comment,
+    Private,
+    Add,
+}
+
+const PATH_PROMPT: &str = "Create torrent from: ";
+const TRACKERS_PROMPT: &str = "Trackers (separated by spaces): ";
+const COMMENT_PROMPT: &str = "Comment: ";
+const PRIVATE_PROMPT: &str = "Private? (y/N) ";
+const ADD_PROMPT: &str = "Add to Transmission and seed? (Y/n) ";
+
+impl CreateTorrent {
+    pub fn new() -> Self {
+        Self {
+            input_path_mgr: InputManager::new(PATH_PROMPT.to_string()),
+            input_trackers_mgr: InputManager::new(TRACKERS_PROMPT.to_string()),
+            input_comment_mgr: InputManager::new(COMMENT_PROMPT.to_string()),
+            input_private_mgr: InputManager::new(PRIVATE_PROMPT.to_string()),
+            input_add_mgr: InputManager::new(ADD_PROMPT.to_string()),
+            stage: Stage::Path,
+        }
+    }
+
+    fn current_input_mgr(&mut self) -> &mut InputManager {
+        match self.stage {
+            Stage::Path => &mut self.input_path_mgr,
+            Stage::Trackers => &mut self.input_trackers_mgr,
+            Stage::Comment => &mut self.input_comment_mgr,
+            Stage::Private => &mut self.input_private_mgr,
+            Stage::Add => &mut self.input_add_mgr,
+        }
+    }
+
+    fn handle_input(&mut self, input: KeyEvent) -> ComponentAction {
+        if input.code == KeyCode::Esc {
+            return ComponentAction::Quit;
+        }
+
+        if input.code != KeyCode::Enter {
+            if self.current_input_mgr().handle_key(input).is_some() {
+                CTX.send_action(Action::Render);
+            }
+            return ComponentAction::Nothing;
+        }
+
+        match self.stage {
+            Stage::Path => {
+                if Path::new(&self.input_path_mgr.text()).exists() {
+                    self.stage = Stage::Trackers;
+                } else {
+                    self.input_path_mgr.set_prompt(format!(
+                        "Create torrent from ({} not found): ",
+                        self.input_path_mgr.text()
+                    ));
+                }
+            }
+            Stage::Trackers => self.stage = Stage::Comment,
+            Stage::Comment => self.stage = Stage::Private,
+            Stage::Private => {
+                if parse_yes_no(&self.input_private_mgr.text(), false).is_some() {
+                    self.stage = Stage::Add;
+                }
+            }
+            Stage::Add => {
+                if let Some(add) = parse_yes_no(&self.input_add_mgr.text(), true) {
+                    self.create(add);
+                    return ComponentAction::Quit;
+                }
+            }
+        }
+
+        CTX.send_action(Action::Render);
+        ComponentAction::Nothing
+    }
+
+    fn create(&self, add: bool) {
+        let path = match std::path::absolute(self.input_path_mgr.text()) {
+            Ok(path) => path,
+            Err(err) => {
+                send_error(&self.input_path_mgr.text(), Box::new(err));
+                return;
+            }
+        };
+
+        let comment = self.input_comment_mgr.text();
+        let options = CreateOptions {
+            trackers: self
+                .input_trackers_mgr
+                .text()
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
+            comment: (!comment.is_empty()).then_some(comment),
+            private: parse_yes_no(&self.input_private_mgr.text(), false).unwrap_or_default(),
+            piece_length: None,
+        };
+
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        CTX.send_update_action(UpdateAction::StatusTaskSet(StatusTask::new_create(&name)));
+
+        tokio::task::spawn(async move {
+            let output = path.with_file_name(format!("{name}.torrent"));
+
+            let source = path.clone();
+            let created = tokio::task::spawn_blocking(move || create_torrent(&source, &options))
+                .await
+                .expect("hashing doesn't panic")
+                .and_then(|torrent| {
+                    std::fs::write(&output, &torrent)?;
+                    Ok(torrent)
+                });
+
+            match created {
+                Ok(torrent) if add => {
+                    // Seeding starts right away, as the data is already where it should be.
+                    let args = TorrentAddArgs {
+                        metainfo: Some(base64::engine::general_purpose::STANDARD.encode(torrent)),
+                        download_dir: path.parent().map(|parent| parent.display().to_string()),
+                        ..Default::default()
+                    };
+                    CTX.send_torrent_action(TorrentAction::AddMetainfo(name, Box::new(args)));
+                }
+                Ok(_) => CTX.send_update_action(UpdateAction::StatusTaskSuccess),
+                Err(err) => send_error(&path.display().to_string(), err.into()),
+            }
+        });
+    }
+}
+
+fn parse_yes_no(text: &str, default: bool) -> Option<bool> {
+    match text.to_lowercase().as_str() {
+        "" => Some(default),
+        "y" | "yes" => Some(true),
+        "n" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+fn send_error(path: &str, err: Box<dyn std::error::Error + Send + Sync>) {
+    let desc = format!("Failed to create a torrent from \"{path}\"");
+    tracing::error!("{desc}: {err}");
+    let err_message = ErrorMessage::new("Failed to create a torrent", desc, err);
+    CTX.send_update_action(UpdateAction::Error(Box::new(err_message)));
+    CTX.send_update_action(UpdateAction::StatusTaskFailure);
+}
+
+impl Component for CreateTorrent {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        match action {
+            Action::Input(input) => self.handle_input(input),
+            _ => ComponentAction::Nothing,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        self.current_input_mgr().render(f, rect)
+    }
+}
@@ -17,7 +17,7 @@ pub struct Delete {
 
 impl Delete {
     pub fn new(to_delete: TorrentSelection) -> Self {
-        let prompt = String::from("Delete selected with files? (Y/n) ");
+        let prompt = format!("Delete {} with files? (Y/n) ", to_delete.describe());
 
         Self {
             delete_with_files: false,
@@ -35,11 +35,7 @@ impl Delete {
             ))
         }
 
-        let task = match &self.torrents_to_delete {
-            TorrentSelection::Single(_, name) => StatusTask::new_del(name.clone()),
-            TorrentSelection::Many(ids) => StatusTask::new_del(ids.len().to_string()),
-        };
-
+        let task = StatusTask::new_del(self.torrents_to_delete.describe());
         CTX.send_update_action(UpdateAction::StatusTaskSet(task));
     }
 }
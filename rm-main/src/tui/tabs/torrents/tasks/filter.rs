@@ -1,22 +1,37 @@
 use crossterm::event::KeyCode;
 use ratatui::prelude::*;
 
+use rm_config::CONFIG;
 use rm_shared::action::{Action, UpdateAction};
 
 use crate::tui::{
     components::{Component, ComponentAction, InputManager},
     ctx::CTX,
+    tabs::torrents::query::{ParseError, Query},
 };
 
 pub struct Filter {
     input: InputManager,
+    // Invalid queries aren't applied, the last valid one stays in effect.
+    error: Option<ParseError>,
 }
 
 impl Filter {
     pub fn new(current_pattern: &Option<String>) -> Self {
         let pattern = current_pattern.as_ref().cloned().unwrap_or_default();
         let input = InputManager::new_with_value("Search: ".to_string(), pattern);
-        Self { input }
+        Self { input, error: None }
+    }
+
+    fn apply(&mut self) {
+        let text = self.input.text();
+        match Query::parse(&text) {
+            Ok(_) => {
+                self.error = None;
+                CTX.send_update_action(UpdateAction::SearchFilterApply(text));
+            }
+            Err(err) => self.error = Some(err),
+        }
     }
 }
 
@@ -24,13 +39,16 @@ impl Component for Filter {
     fn handle_actions(&mut self, action: Action) -> ComponentAction {
         match action {
             Action::Input(input) => {
-                if matches!(input.code, KeyCode::Enter | KeyCode::Esc) {
+                if input.code == KeyCode::Enter && self.error.is_some() {
+                    ComponentAction::Nothing
+                } else if matches!(input.code, KeyCode::Enter | KeyCode::Esc) {
                     if self.input.text().is_empty() {
                         CTX.send_update_action(UpdateAction::SearchFilterClear);
                     }
                     ComponentAction::Quit
                 } else if self.input.handle_key(input).is_some() {
-                    CTX.send_update_action(UpdateAction::SearchFilterApply(self.input.text()));
+                    self.apply();
+                    CTX.send_action(Action::Render);
                     ComponentAction::Nothing
                 } else {
                     ComponentAction::Nothing
@@ -41,6 +59,19 @@ impl Component for Filter {
     }
 
     fn render(&mut self, f: &mut Frame, rect: Rect) {
-        self.input.render(f, rect);
+        let Some(error) = &self.error else {
+            self.input.render(f, rect);
+            return;
+        };
+
+        let error = format!(" {} {error} ", CONFIG.icons.failure);
+        let [input_rect, error_rect] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(error.chars().count() as u16),
+        ])
+        .areas(rect);
+
+        self.input.render(f, input_rect);
+        f.render_widget(Line::from(error).red(), error_rect);
     }
 }
@@ -1,21 +1,26 @@
 mod add_magnet;
 mod change_category;
+mod create_torrent;
 mod default;
 mod delete_torrent;
 mod filter;
 mod move_torrent;
 mod rename;
+mod save_view;
 mod selection;
 mod sort;
 mod status;
 
 pub use add_magnet::AddMagnet;
 pub use change_category::ChangeCategory;
+pub use create_torrent::CreateTorrent;
 pub use default::Default;
 pub use delete_torrent::Delete;
 pub use filter::Filter;
 pub use move_torrent::Move;
 pub use rename::Rename;
+use rm_shared::utils::bytes_to_human_format;
+pub use save_view::SaveView;
 pub use selection::Selection;
 pub use sort::Sort;
 pub use status::{CurrentTaskState, Status};
@@ -23,14 +28,34 @@ use transmission_rpc::types::Id;
 
 pub enum TorrentSelection {
     Single(Id, String),
-    Many(Vec<Id>),
+    /// Selected torrents along with their combined size.
+    Many(Vec<Id>, i64),
 }
 
 impl TorrentSelection {
     pub fn ids(&self) -> Vec<Id> {
         match self {
             TorrentSelection::Single(id, _) => vec![id.clone()],
-            TorrentSelection::Many(ids) => ids.clone(),
+            TorrentSelection::Many(ids, _) => ids.clone(),
+        }
+    }
+
+    /// Name of the torrent, or how many there are, for status messages.
+    pub fn describe(&self) -> String {
+        match self {
+            TorrentSelection::Single(_, name) => name.clone(),
+            TorrentSelection::Many(ids, size) => {
+                let torrents = if ids.len() == 1 {
+                    "torrent"
+                } else {
+                    "torrents"
+                };
+                format!(
+                    "{} {torrents} ({})",
+                    ids.len(),
+                    bytes_to_human_format(*size)
+                )
+            }
         }
     }
 }
@@ -0,0 +1,86 @@
+use crossterm::event::KeyCode;
+use ratatui::prelude::*;
+
+use rm_config::CONFIG;
+use rm_shared::{
+    action::{Action, UpdateAction},
+    status_task::StatusTask,
+};
+
+use crate::tui::{
+    components::{Component, ComponentAction, InputManager},
+    ctx::CTX,
+};
+
+pub struct SaveView {
+    input: InputManager,
+    taken_names: Vec<String>,
+}
+
+impl SaveView {
+    pub fn new(taken_names: Vec<String>) -> Self {
+        Self {
+            input: InputManager::new("View name: ".to_string()),
+            taken_names,
+        }
+    }
+
+    fn is_name_taken(&self) -> bool {
+        self.taken_names.contains(&self.input.text())
+    }
+
+    fn save(&self) {
+        let name = self.input.text();
+
+        if name.is_empty() {
+            return;
+        }
+
+        CTX.send_update_action(UpdateAction::StatusTaskSet(StatusTask::new_save_view(
+            name.clone(),
+        )));
+        CTX.send_update_action(UpdateAction::SaveView(name));
+    }
+}
+
+impl Component for SaveView {
+    fn handle_actions(&mut self, action: Action) -> ComponentAction {
+        match action {
+            Action::Input(input) => {
+                if input.code == KeyCode::Esc {
+                    return ComponentAction::Quit;
+                } else if input.code == KeyCode::Enter {
+                    if self.is_name_taken() {
+                        return ComponentAction::Nothing;
+                    }
+                    self.save();
+                    return ComponentAction::Quit;
+                }
+
+                if self.input.handle_key(input).is_some() {
+                    CTX.send_action(Action::Render);
+                }
+
+                ComponentAction::Nothing
+            }
+            _ => ComponentAction::Nothing,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        if !self.is_name_taken() {
+            self.input.render(f, rect);
+            return;
+        }
+
+        let error = format!(" {} name already taken ", CONFIG.icons.failure);
+        let [input_rect, error_rect] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(error.chars().count() as u16),
+        ])
+        .areas(rect);
+
+        self.input.render(f, input_rect);
+        f.render_widget(Line::from(error).red(), error_rect);
+    }
+}
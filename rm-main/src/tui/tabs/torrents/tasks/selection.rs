@@ -1,15 +1,25 @@
 use crate::tui::components::{keybinding_style, Component};
-use rm_config::{keymap::GeneralAction, CONFIG};
+use rm_config::{
+    keymap::{GeneralAction, TorrentsAction},
+    CONFIG,
+};
+use rm_shared::utils::bytes_to_human_format;
 
 use ratatui::{prelude::*, text::Span};
 
 pub struct Selection {
     selection_amount: usize,
+    selection_size: i64,
+    is_visual: bool,
 }
 
 impl Selection {
-    pub const fn new(selection_amount: usize) -> Self {
-        Self { selection_amount }
+    pub const fn new(selection_amount: usize, selection_size: i64, is_visual: bool) -> Self {
+        Self {
+            selection_amount,
+            selection_size,
+            is_visual,
+        }
     }
 }
 
@@ -18,21 +28,39 @@ impl Component for Selection {
         let mut line = Line::default();
         let mut line_is_empty = true;
 
-        if let Some(keys) = CONFIG
-            .keybindings
-            .general
-            .get_keys_for_action_joined(GeneralAction::Close)
-        {
+        if self.is_visual {
+            line.push_span(Span::raw("VISUAL ").fg(CONFIG.general.accent_color).bold());
+        }
+
+        let keys = if self.is_visual {
+            CONFIG
+                .keybindings
+                .torrents_tab
+                .get_keys_for_action_joined(TorrentsAction::VisualSelect)
+                .map(|keys| (keys, " - end range"))
+        } else {
+            CONFIG
+                .keybindings
+                .general
+                .get_keys_for_action_joined(GeneralAction::Close)
+                .map(|keys| (keys, " - clear selection"))
+        };
+
+        if let Some((keys, desc)) = keys {
             line_is_empty = false;
             line.push_span(Span::styled(keys, keybinding_style()));
-            line.push_span(Span::raw(" - clear selection"));
+            line.push_span(Span::raw(desc));
         }
 
         if !line_is_empty {
             line.push_span(Span::raw(" | "));
         }
 
-        line.push_span(format!("{} selected", self.selection_amount));
+        line.push_span(format!(
+            "{} selected ({})",
+            self.selection_amount,
+            bytes_to_human_format(self.selection_size)
+        ));
 
         f.render_widget(line, rect);
     }
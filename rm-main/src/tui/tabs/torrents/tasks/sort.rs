@@ -48,10 +48,23 @@ impl Component for Sort {
             if !line_is_empty {
                 line.push_span(Span::raw(" | "));
             }
+            line_is_empty = false;
             line.push_span(Span::styled(keys, keybinding_style()));
             line.push_span(Span::raw(" - reverse"));
         }
 
+        if let Some(keys) = CONFIG
+            .keybindings
+            .general
+            .get_keys_for_action_joined(GeneralAction::AppendSortColumnRight)
+        {
+            if !line_is_empty {
+                line.push_span(Span::raw(" | "));
+            }
+            line.push_span(Span::styled(keys, keybinding_style()));
+            line.push_span(Span::raw(" - sort by next column too"));
+        }
+
         f.render_widget(line, rect);
     }
 }
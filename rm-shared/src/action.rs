@@ -1,11 +1,15 @@
-use std::{error::Error, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, MouseEvent};
 use intuitils::error_message::ErrorMessage;
 use magnetease::{MagneteaseError, MagneteaseResult};
+use serde_json::{Map, Value};
 use transmission_rpc::types::{FreeSpace, SessionGet, SessionStats, Torrent};
 
-use crate::{current_window::TorrentWindow, status_task::StatusTask};
+use crate::{
+    connection_status::ConnectionStatus, current_window::TorrentWindow, header::Header,
+    status_task::StatusTask,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Action {
@@ -32,20 +36,56 @@ pub enum Action {
     ChangeTab(u8),
     XdgOpen,
     Input(KeyEvent),
+    Click(MouseEvent),
+    DoubleClick(MouseEvent),
     MoveToColumnLeft,
     MoveToColumnRight,
+    AppendSortColumnLeft,
+    AppendSortColumnRight,
+    ShowProfiles,
+    ToggleAltSpeed,
     // Torrents Tab
     ShowStats,
     ShowFiles,
+    ShowPeers,
+    ShowTrackers,
+    ShowOptions,
+    ToggleSidebar,
+    CycleView,
+    ShowViews,
+    ShowColumns,
+    CycleGroupBy,
+    VisualSelect,
+    SelectAll,
+    InvertSelection,
+    ClearSelection,
+    SelectSameCategory,
     Pause,
+    StartNow,
+    Verify,
+    Reannounce,
+    QueueMoveTop,
+    QueueMoveUp,
+    QueueMoveDown,
+    QueueMoveBottom,
     Delete,
     AddMagnet,
+    CreateTorrent,
     MoveTorrent,
     ChangeCategory,
     ChangeFilePriority,
     Rename,
+    // Columns Popup
+    MoveColumnUp,
+    MoveColumnDown,
+    WidenColumn,
+    NarrowColumn,
+    ResetColumnWidth,
     // Search Tab
     ShowProvidersInfo,
+    // Session Tab
+    ApplySessionChanges,
+    DiscardSessionChanges,
 }
 
 pub enum UpdateAction {
@@ -54,6 +94,11 @@ pub enum UpdateAction {
     SwitchToNormalMode,
     Error(Box<ErrorMessage>),
     UnrecoverableError(Box<color_eyre::eyre::Report>),
+    SwitchProfile(String),
+    ConnectionStatus(ConnectionStatus),
+    /// The daemon can't be connected to until the user steps in, e.g. due
+    /// to wrong credentials.
+    ConnectionError(Box<ErrorMessage>),
     // Torrents Tab
     ChangeTorrentWindow(TorrentWindow),
     SessionStats(Arc<SessionStats>),
@@ -61,14 +106,22 @@ pub enum UpdateAction {
     FreeSpace(Arc<FreeSpace>),
     UpdateTorrents(Vec<Torrent>),
     UpdateCurrentTorrent(Box<Torrent>),
+    UpdateSelectedTorrents(Vec<Torrent>),
     SearchFilterApply(String),
     SearchFilterClear,
+    SwitchView(Option<usize>),
+    PromptSaveView,
+    SaveView(String),
+    SetColumns(Vec<Header>, HashMap<Header, u16>),
+    SaveColumns,
     CancelTorrentTask,
     // Search Tab
     SearchStarted,
     ProviderResult(MagneteaseResult),
     ProviderError(MagneteaseError),
     SearchFinished,
+    // Session Tab
+    SessionSettings(Arc<Map<String, Value>>),
     // Task Manager's Status Task
     StatusTaskClear,
     StatusTaskSuccess,
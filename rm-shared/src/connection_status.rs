@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionStatus {
+    Connected,
+    // Trying to (re)establish the connection right now
+    #[default]
+    Reconnecting,
+    // Connection was lost, next attempt will be made in given seconds
+    Offline {
+        retry_in: u64,
+    },
+}
+
+impl ConnectionStatus {
+    pub fn is_connected(&self) -> bool {
+        *self == Self::Connected
+    }
+}
@@ -2,15 +2,22 @@
 pub enum Window {
     Torrents(TorrentWindow),
     Search(SearchWindow),
+    Session(SessionWindow),
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum TorrentWindow {
     General,
     FileViewer,
+    Columns,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum SearchWindow {
     General,
 }
+
+#[derive(Debug, Clone, Copy)]
+pub enum SessionWindow {
+    General,
+}
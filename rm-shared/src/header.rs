@@ -1,7 +1,7 @@
 use ratatui::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Serialize, Deserialize, Hash, PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Header {
     Id,
     Name,
@@ -20,9 +20,59 @@ pub enum Header {
     SmallStatus,
     Category,
     CategoryIcon,
+    QueuePosition,
+    Status,
+    Seeders,
+    Leechers,
+    HashString,
+    DoneDate,
+    SecondsSeeding,
+    SecondsDownloading,
+    TotalSize,
+    DownloadedEver,
+    LeftUntilDone,
+    Labels,
+    TrackerHost,
+    Private,
+    RatioLimit,
 }
 
 impl Header {
+    pub const ALL: [Header; 32] = [
+        Self::Id,
+        Self::Name,
+        Self::SizeWhenDone,
+        Self::Progress,
+        Self::Eta,
+        Self::DownloadRate,
+        Self::UploadRate,
+        Self::DownloadDir,
+        Self::Padding,
+        Self::UploadRatio,
+        Self::UploadedEver,
+        Self::ActivityDate,
+        Self::AddedDate,
+        Self::PeersConnected,
+        Self::SmallStatus,
+        Self::Category,
+        Self::CategoryIcon,
+        Self::QueuePosition,
+        Self::Status,
+        Self::Seeders,
+        Self::Leechers,
+        Self::HashString,
+        Self::DoneDate,
+        Self::SecondsSeeding,
+        Self::SecondsDownloading,
+        Self::TotalSize,
+        Self::DownloadedEver,
+        Self::LeftUntilDone,
+        Self::Labels,
+        Self::TrackerHost,
+        Self::Private,
+        Self::RatioLimit,
+    ];
+
     pub fn default_constraint(&self) -> Constraint {
         match self {
             Self::Name => Constraint::Max(70),
@@ -42,6 +92,21 @@ impl Header {
             Self::SmallStatus => Constraint::Length(1),
             Self::Category => Constraint::Max(15),
             Self::CategoryIcon => Constraint::Length(5),
+            Self::QueuePosition => Constraint::Length(5),
+            Self::Status => Constraint::Length(18),
+            Self::Seeders => Constraint::Length(6),
+            Self::Leechers => Constraint::Length(7),
+            Self::HashString => Constraint::Max(40),
+            Self::DoneDate => Constraint::Length(14),
+            Self::SecondsSeeding => Constraint::Length(12),
+            Self::SecondsDownloading => Constraint::Length(12),
+            Self::TotalSize => Constraint::Length(12),
+            Self::DownloadedEver => Constraint::Length(12),
+            Self::LeftUntilDone => Constraint::Length(12),
+            Self::Labels => Constraint::Max(30),
+            Self::TrackerHost => Constraint::Max(30),
+            Self::Private => Constraint::Length(7),
+            Self::RatioLimit => Constraint::Length(11),
         }
     }
 
@@ -64,6 +129,21 @@ impl Header {
             Self::SmallStatus => "",
             Self::Category => "Category",
             Self::CategoryIcon => "",
+            Self::QueuePosition => "Queue",
+            Self::Status => "Status",
+            Self::Seeders => "Seeds",
+            Self::Leechers => "Leeches",
+            Self::HashString => "Hash",
+            Self::DoneDate => "Done",
+            Self::SecondsSeeding => "Seeding",
+            Self::SecondsDownloading => "Downloading",
+            Self::TotalSize => "Total size",
+            Self::DownloadedEver => "Down Ever",
+            Self::LeftUntilDone => "Left",
+            Self::Labels => "Labels",
+            Self::TrackerHost => "Tracker",
+            Self::Private => "Private",
+            Self::RatioLimit => "Ratio limit",
         }
     }
 }
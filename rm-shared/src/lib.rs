@@ -1,4 +1,5 @@
 pub mod action;
+pub mod connection_status;
 pub mod current_window;
 pub mod header;
 pub mod status_task;
@@ -8,11 +8,19 @@ pub struct StatusTask {
 #[derive(Clone, Copy)]
 enum TaskType {
     Add,
+    Create,
     Delete,
     Rename,
     Move,
     Open,
+    StartNow,
+    Verify,
+    Reannounce,
     ChangeCategory,
+    SwitchProfile,
+    SwitchView,
+    SaveView,
+    SaveColumns,
 }
 
 impl StatusTask {
@@ -23,6 +31,13 @@ impl StatusTask {
         }
     }
 
+    pub fn new_create(what: impl Into<String>) -> Self {
+        StatusTask {
+            task_type: TaskType::Create,
+            what: what.into(),
+        }
+    }
+
     pub fn new_rename(what: impl Into<String>) -> Self {
         StatusTask {
             task_type: TaskType::Rename,
@@ -44,6 +59,27 @@ impl StatusTask {
         }
     }
 
+    pub fn new_start_now(what: impl Into<String>) -> Self {
+        StatusTask {
+            task_type: TaskType::StartNow,
+            what: what.into(),
+        }
+    }
+
+    pub fn new_verify(what: impl Into<String>) -> Self {
+        StatusTask {
+            task_type: TaskType::Verify,
+            what: what.into(),
+        }
+    }
+
+    pub fn new_reannounce(what: impl Into<String>) -> Self {
+        StatusTask {
+            task_type: TaskType::Reannounce,
+            what: what.into(),
+        }
+    }
+
     pub fn new_category(what: impl Into<String>) -> Self {
         StatusTask {
             task_type: TaskType::ChangeCategory,
@@ -51,6 +87,34 @@ impl StatusTask {
         }
     }
 
+    pub fn new_profile(what: impl Into<String>) -> Self {
+        StatusTask {
+            task_type: TaskType::SwitchProfile,
+            what: what.into(),
+        }
+    }
+
+    pub fn new_view(what: impl Into<String>) -> Self {
+        StatusTask {
+            task_type: TaskType::SwitchView,
+            what: what.into(),
+        }
+    }
+
+    pub fn new_save_view(what: impl Into<String>) -> Self {
+        StatusTask {
+            task_type: TaskType::SaveView,
+            what: what.into(),
+        }
+    }
+
+    pub fn new_save_columns(what: impl Into<String>) -> Self {
+        StatusTask {
+            task_type: TaskType::SaveColumns,
+            what: what.into(),
+        }
+    }
+
     pub fn new_open(what: impl Into<String>) -> Self {
         StatusTask {
             task_type: TaskType::Open,
@@ -63,9 +127,13 @@ impl StatusTask {
 
         match self.task_type {
             TaskType::Add => format!(" Added {truncated}"),
+            TaskType::Create => format!(" Created {truncated}"),
             TaskType::Delete => format!(" Deleted {truncated}"),
             TaskType::Move => format!(" Moved {truncated}"),
             TaskType::Open => format!(" Opened {truncated}"),
+            TaskType::StartNow => format!(" Started {truncated}"),
+            TaskType::Verify => format!(" Verification of {truncated} queued"),
+            TaskType::Reannounce => format!(" Reannounced {truncated}"),
             TaskType::ChangeCategory => {
                 if truncated.is_empty() {
                     " Categories cleared!".to_string()
@@ -74,6 +142,10 @@ impl StatusTask {
                 }
             }
             TaskType::Rename => format!("Renamed {truncated}"),
+            TaskType::SwitchProfile => format!(" Switched to {truncated}"),
+            TaskType::SwitchView => format!(" Switched to {truncated} view"),
+            TaskType::SaveView => format!(" Saved {truncated} view"),
+            TaskType::SaveColumns => format!(" Saved {truncated} columns"),
         }
     }
 
@@ -82,11 +154,19 @@ impl StatusTask {
 
         match self.task_type {
             TaskType::Add => format!(" Error adding {truncated}"),
+            TaskType::Create => format!(" Error creating {truncated}"),
             TaskType::Delete => format!(" Error deleting {truncated}"),
             TaskType::Move => format!(" Error moving to {truncated}"),
             TaskType::Open => format!(" Error opening {truncated}"),
+            TaskType::StartNow => format!(" Error starting {truncated}"),
+            TaskType::Verify => format!(" Error verifying {truncated}"),
+            TaskType::Reannounce => format!(" Error reannouncing {truncated}"),
             TaskType::ChangeCategory => format!(" Error changing category to {truncated}"),
             TaskType::Rename => format!(" Error renaming {truncated}"),
+            TaskType::SwitchProfile => format!(" Error switching to {truncated}"),
+            TaskType::SwitchView => format!(" Error switching to {truncated} view"),
+            TaskType::SaveView => format!(" Error saving {truncated} view"),
+            TaskType::SaveColumns => format!(" Error saving {truncated} columns"),
         }
     }
 
@@ -95,11 +175,19 @@ impl StatusTask {
 
         match self.task_type {
             TaskType::Add => format!(" Adding {truncated}"),
+            TaskType::Create => format!(" Creating {truncated}"),
             TaskType::Delete => format!(" Deleting {truncated}"),
             TaskType::Move => format!(" Moving {truncated}"),
             TaskType::Open => format!(" Opening {truncated}"),
+            TaskType::StartNow => format!(" Starting {truncated}"),
+            TaskType::Verify => format!(" Requesting verification of {truncated}"),
+            TaskType::Reannounce => format!(" Reannouncing {truncated}"),
             TaskType::ChangeCategory => format!(" Changing category to {truncated}"),
             TaskType::Rename => format!(" Renaming {truncated}"),
+            TaskType::SwitchProfile => format!(" Switching to {truncated}"),
+            TaskType::SwitchView => format!(" Switching to {truncated} view"),
+            TaskType::SaveView => format!(" Saving {truncated} view"),
+            TaskType::SaveColumns => format!(" Saving {truncated} columns"),
         }
     }
 }